@@ -0,0 +1,55 @@
+//! Compares `x_get` (deserializing from the borrowed `&Value`) with the previous clone-then-`from_value` approach.
+//!
+//! Run with `cargo bench --bench x_get`.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use value_ext::JsonValueExt;
+
+const ITERATIONS: u32 = 2_000;
+
+#[derive(Deserialize)]
+#[allow(dead_code)] // only deserialized
+struct Item {
+	id: u64,
+	name: String,
+	tags: Vec<String>,
+	score: f64,
+}
+
+fn main() {
+	let items: Vec<Value> = (0..1_000)
+		.map(|id| json!({"id": id, "name": format!("item-{id}"), "tags": ["a", "b", "c"], "score": 0.5, "meta": {"big": [1, 2, 3, 4, 5, 6, 7, 8]}}))
+		.collect();
+	let value = json!({"data": {"items": items}});
+
+	let borrowed = bench(|| {
+		let items: Vec<Item> = value.x_get("/data/items").unwrap();
+		black_box(items);
+	});
+	let cloned = bench(|| {
+		let items: Vec<Item> = serde_json::from_value(value.pointer("/data/items").unwrap().clone()).unwrap();
+		black_box(items);
+	});
+
+	println!("x_get (borrowed)        {:>10.1?} / iter", borrowed / ITERATIONS);
+	println!("clone + from_value      {:>10.1?} / iter", cloned / ITERATIONS);
+	println!(
+		"speedup                 {:>10.2}x",
+		cloned.as_secs_f64() / borrowed.as_secs_f64()
+	);
+}
+
+fn bench(mut f: impl FnMut()) -> Duration {
+	// warm up
+	for _ in 0..ITERATIONS / 10 {
+		f();
+	}
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		f();
+	}
+	start.elapsed()
+}
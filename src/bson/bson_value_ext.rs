@@ -0,0 +1,460 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use bson::oid::ObjectId;
+use bson::{Bson, DateTime, Document};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// (feature `bson`) Extension trait for `bson::Bson` and `bson::Document`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/items/0/name`),
+/// so `JsonPath::dot(..)` also works.
+///
+/// # Provided Methods
+///
+/// - **`x_new_document`**: Returns an empty document.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_get_object_id`**: Returns an `ObjectId` (from an ObjectId or a hex string) using either a direct name or a pointer path.
+/// - **`x_get_datetime`**: Returns a `bson::DateTime` (from a DateTime or an RFC 3339 string) using either a direct name or a pointer path.
+/// - **`x_take`**: Takes a value using a specified name or pointer path, replacing it with `Null`.
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing documents (or arrays) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each document and property name.
+pub trait BsonValueExt {
+	fn x_new_document() -> Self;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Returns the `ObjectId` at the given name or pointer path (an ObjectId, or its 24 char hex string).
+	fn x_get_object_id(&self, name_or_pointer: &str) -> Result<ObjectId>;
+
+	/// Returns the `bson::DateTime` at the given name or pointer path (a DateTime, or an RFC 3339 string).
+	fn x_get_datetime(&self, name_or_pointer: &str) -> Result<DateTime>;
+
+	/// Takes the value at the specified name or pointer path and replaces it with `Null`.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - Missing documents are created along the way (or arrays, when the next path part is an index).
+	/// - Array indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_document, property_name) -> WalkControl` (or `bool`).
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Document, &str) -> R,
+		R: Into<WalkControl>;
+}
+
+impl BsonValueExt for Bson {
+	fn x_new_document() -> Self {
+		Bson::Document(Document::new())
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(Node::Bson(self), name_or_pointer)?;
+		from_bson_with_context(name_or_pointer, value.clone())
+	}
+
+	fn x_get_object_id(&self, name_or_pointer: &str) -> Result<ObjectId> {
+		as_object_id(name_or_pointer, get_value(Node::Bson(self), name_or_pointer)?)
+	}
+
+	fn x_get_datetime(&self, name_or_pointer: &str) -> Result<DateTime> {
+		as_datetime(name_or_pointer, get_value(Node::Bson(self), name_or_pointer)?)
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		// checked first, as the error needs to borrow self
+		get_value(Node::Bson(self), name_or_pointer)?;
+		let value = get_value_mut(self, name_or_pointer)
+			.map(std::mem::take)
+			.unwrap_or_default();
+
+		from_bson_with_context(name_or_pointer, value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = bson::to_bson(&value)?;
+		insert_value(self, name_or_pointer, new_value)
+	}
+
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Document, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		walk(self, callback)
+	}
+}
+
+/// The `Document` methods run on the document moved into a `Bson::Document` (no copy).
+impl BsonValueExt for Document {
+	fn x_new_document() -> Self {
+		Document::new()
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(Node::Document(self), name_or_pointer)?;
+		from_bson_with_context(name_or_pointer, value.clone())
+	}
+
+	fn x_get_object_id(&self, name_or_pointer: &str) -> Result<ObjectId> {
+		as_object_id(name_or_pointer, get_value(Node::Document(self), name_or_pointer)?)
+	}
+
+	fn x_get_datetime(&self, name_or_pointer: &str) -> Result<DateTime> {
+		as_datetime(name_or_pointer, get_value(Node::Document(self), name_or_pointer)?)
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		with_bson(self, |bson| bson.x_take(name_or_pointer))
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		with_bson(self, |bson| bson.x_insert(name_or_pointer, value))
+	}
+
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Document, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		with_bson(self, |bson| walk(bson, callback))
+	}
+}
+
+// region:    --- Support
+
+/// A navigation starting point, as a `Document` is not a `Bson`.
+#[derive(Clone, Copy)]
+enum Node<'a> {
+	Bson(&'a Bson),
+	Document(&'a Document),
+}
+
+impl<'a> Node<'a> {
+	fn child(self, token: &str) -> Option<&'a Bson> {
+		match self {
+			Node::Document(doc) | Node::Bson(Bson::Document(doc)) => doc.get(token),
+			Node::Bson(Bson::Array(arr)) => token.parse::<usize>().ok().and_then(|idx| arr.get(idx)),
+			Node::Bson(_) => None,
+		}
+	}
+
+	fn document(self) -> Option<&'a Document> {
+		match self {
+			Node::Document(doc) | Node::Bson(Bson::Document(doc)) => Some(doc),
+			Node::Bson(_) => None,
+		}
+	}
+}
+
+/// Runs `f` on the document moved into a `Bson::Document`, and moves it back.
+fn with_bson<T>(doc: &mut Document, f: impl FnOnce(&mut Bson) -> T) -> T {
+	let mut bson = Bson::Document(std::mem::take(doc));
+	let res = f(&mut bson);
+	if let Bson::Document(inner) = bson {
+		*doc = inner;
+	}
+	res
+}
+
+fn get_value<'a>(root: Node<'a>, name_or_pointer: &str) -> Result<&'a Bson> {
+	let mut current = root;
+	let mut resolved = String::new();
+	let mut value = None;
+
+	for token in path_tokens(name_or_pointer) {
+		let Some(next) = current.child(&token) else {
+			let suggestions = current
+				.document()
+				.map(|doc| suggest_keys(doc.keys(), &token))
+				.unwrap_or_default();
+			return Err(BsonValueExtError::PropertyNotFound {
+				name: name_or_pointer.to_string(),
+				resolved,
+				missing: token,
+				suggestions,
+			});
+		};
+		resolved.push('/');
+		resolved.push_str(&escape_token(&token));
+		current = Node::Bson(next);
+		value = Some(next);
+	}
+
+	value.ok_or_else(|| BsonValueExtError::PropertyNotFound {
+		name: name_or_pointer.to_string(),
+		resolved,
+		missing: String::new(),
+		suggestions: Vec::new(),
+	})
+}
+
+fn get_value_mut<'a>(value: &'a mut Bson, name_or_pointer: &str) -> Option<&'a mut Bson> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| match current {
+			Bson::Document(doc) => doc.get_mut(token),
+			Bson::Array(arr) => arr.get_mut(token.parse::<usize>().ok()?),
+			_ => None,
+		})
+}
+
+fn insert_value(root: &mut Bson, name_or_pointer: &str, new_value: Bson) -> Result<()> {
+	let parts = path_tokens(name_or_pointer);
+	let last_idx = parts.len() - 1;
+
+	let mut current = root;
+	// the pointer of `current`, for the errors
+	let mut path = String::new();
+
+	// -- Add the eventual missing parents
+	for (i, part) in parts[..last_idx].iter().enumerate() {
+		let next_is_index = is_array_token(&parts[i + 1]);
+		let new_container = || {
+			if next_is_index {
+				Bson::Array(Vec::new())
+			} else {
+				Bson::Document(Document::new())
+			}
+		};
+		current = match current {
+			Bson::Document(doc) => doc.entry(part.to_string()).or_insert_with(new_container),
+			Bson::Array(arr) => {
+				let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_container());
+				}
+				&mut arr[idx]
+			}
+			other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+		};
+		path.push('/');
+		path.push_str(&escape_token(part));
+	}
+
+	// -- Set the value at the last element
+	let last_part = &parts[last_idx];
+	match current {
+		Bson::Document(doc) => {
+			doc.insert(last_part.to_string(), new_value);
+			Ok(())
+		}
+		Bson::Array(arr) => {
+			let idx = array_insert_index(arr, last_part, name_or_pointer, &path)?;
+			if idx == arr.len() {
+				arr.push(new_value);
+			} else {
+				arr[idx] = new_value;
+			}
+			Ok(())
+		}
+		other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+	}
+}
+
+fn walk<F, R>(root: &mut Bson, mut callback: F) -> bool
+where
+	F: FnMut(&mut Document, &str) -> R,
+	R: Into<WalkControl>,
+{
+	let mut queue = VecDeque::new();
+	queue.push_back(root);
+
+	while let Some(current) = queue.pop_front() {
+		match current {
+			Bson::Document(doc) => {
+				let mut skipped_keys = Vec::new();
+				for key in doc.keys().cloned().collect::<Vec<_>>() {
+					match callback(doc, &key).into() {
+						WalkControl::Continue => (),
+						WalkControl::SkipChildren => skipped_keys.push(key),
+						WalkControl::Stop => return false,
+					}
+				}
+
+				for (key, value) in doc.iter_mut() {
+					if matches!(value, Bson::Document(_) | Bson::Array(_)) && !skipped_keys.contains(key) {
+						queue.push_back(value);
+					}
+				}
+			}
+			Bson::Array(arr) => {
+				for value in arr.iter_mut() {
+					if matches!(value, Bson::Document(_) | Bson::Array(_)) {
+						queue.push_back(value);
+					}
+				}
+			}
+			_ => (),
+		}
+	}
+
+	true
+}
+
+fn as_object_id(name_or_pointer: &str, value: &Bson) -> Result<ObjectId> {
+	match value {
+		Bson::ObjectId(oid) => Ok(*oid),
+		Bson::String(s) => ObjectId::parse_str(s).map_err(|_| not_of_type(name_or_pointer, "ObjectId", value)),
+		_ => Err(not_of_type(name_or_pointer, "ObjectId", value)),
+	}
+}
+
+fn as_datetime(name_or_pointer: &str, value: &Bson) -> Result<DateTime> {
+	match value {
+		Bson::DateTime(datetime) => Ok(*datetime),
+		Bson::String(s) => DateTime::parse_rfc3339_str(s).map_err(|_| not_of_type(name_or_pointer, "DateTime", value)),
+		_ => Err(not_of_type(name_or_pointer, "DateTime", value)),
+	}
+}
+
+fn not_of_type(name: &str, not_of_type: &'static str, value: &Bson) -> BsonValueExtError {
+	BsonValueExtError::PropertyValueNotOfType {
+		name: name.to_string(),
+		not_of_type,
+		actual: bson_kind_name(value),
+	}
+}
+
+/// Returns the index to set or append in `arr` (at `path`, for the requested `name`) for the token
+/// (`-` or `arr.len()` means append).
+fn array_insert_index(arr: &[Bson], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		arr.len()
+	} else {
+		token.parse::<usize>().map_err(|_| BsonValueExtError::NotADocument {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > arr.len() {
+		return Err(BsonValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: arr.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Bson, token: &str, name: &str, path: &str) -> BsonValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), bson_kind_name(value));
+	if is_array_token(token) {
+		BsonValueExtError::NotAnArray { name, path, actual }
+	} else {
+		BsonValueExtError::NotADocument { name, path, actual }
+	}
+}
+
+fn from_bson_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: Bson) -> Result<T> {
+	let actual = bson_kind_name(&value);
+	bson::from_bson(value).map_err(|cause| BsonValueExtError::PropertyValueNotDeserializable {
+		name: name_or_pointer.to_string(),
+		not_of_type: std::any::type_name::<T>(),
+		actual,
+		cause: Box::new(cause),
+	})
+}
+
+/// Returns the BSON kind name of the value (e.g., `"string"`, `"document"`), as reported in the errors.
+fn bson_kind_name(value: &Bson) -> &'static str {
+	match value {
+		Bson::Double(_) => "double",
+		Bson::String(_) => "string",
+		Bson::Array(_) => "array",
+		Bson::Document(_) => "document",
+		Bson::Boolean(_) => "boolean",
+		Bson::Null => "null",
+		Bson::Int32(_) => "int32",
+		Bson::Int64(_) => "int64",
+		Bson::ObjectId(_) => "objectId",
+		Bson::DateTime(_) => "date",
+		Bson::Binary(_) => "binary",
+		Bson::Decimal128(_) => "decimal",
+		_ => "other",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, BsonValueExtError>;
+
+/// The `BsonValueExt` error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum BsonValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` is not of type `not_of_type`, but of BSON kind `actual`.
+	PropertyValueNotOfType {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+	},
+
+	/// The value at `name` (of BSON kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: Box<bson::de::Error>,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of BSON kind `actual`) is not a document, while the requested `name` needs it to be one.
+	NotADocument {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of BSON kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	#[from]
+	BsonSer(bson::ser::Error),
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for BsonValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for BsonValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
@@ -0,0 +1,7 @@
+// region:    --- Modules
+
+mod bson_value_ext;
+
+pub use bson_value_ext::*;
+
+// endregion: --- Modules
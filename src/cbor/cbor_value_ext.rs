@@ -0,0 +1,466 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use ciborium::value::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// A CBOR map, as the list of its `(key, value)` entries.
+pub type CborMap = Vec<(Value, Value)>;
+
+/// (feature `cbor`) Extension trait for the CBOR `ciborium::value::Value`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/items/0/name`),
+/// so `JsonPath::dot(..)` also works.
+///
+/// CBOR specific types are handled gracefully:
+/// - Tags (e.g., `Tag(1, epoch)`) are navigated transparently, and stripped when the tagged value does not deserialize as is.
+/// - Byte strings deserialize into `Vec<u8>` (or any sequence of integers), and `x_get_bytes` borrows them.
+/// - A path token matches a map key that is a text equal to it, a byte string with the same bytes, or an integer rendering to it.
+///
+/// # Provided Methods
+///
+/// - **`x_new_map`**: Returns an empty `Value::Map`.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_get_bytes`**: Returns the `&[u8]` of a byte string (or text) using either a direct name or a pointer path.
+/// - **`x_get_tag`**: Returns the tag number and the tagged `&Value` using either a direct name or a pointer path.
+/// - **`x_take`**: Takes a value using a specified name or pointer path, replacing it with `Null`.
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing maps (or arrays) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each map and key.
+pub trait CborValueExt {
+	fn x_new_map() -> Value;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	/// - When the value does not deserialize as is, it is retried with its tags stripped and
+	///   its byte strings as integer arrays.
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Returns the bytes of the byte string (or text) at the given name or pointer path (tags are skipped).
+	fn x_get_bytes(&self, name_or_pointer: &str) -> Result<&[u8]>;
+
+	/// Returns the tag number and the tagged value at the given name or pointer path.
+	fn x_get_tag(&self, name_or_pointer: &str) -> Result<(u64, &Value)>;
+
+	/// Takes the value at the specified name or pointer path and replaces it with `Null`.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - An existing key matching the path token is replaced (even when a byte string or integer key), otherwise a text key is added.
+	/// - Missing maps are created along the way (or arrays, when the next path part is an index).
+	/// - Array indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_map, key) -> WalkControl` (or `bool`),
+	///   where `key` is a `Value`, as CBOR keys are not necessarily text.
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut CborMap, &Value) -> R,
+		R: Into<WalkControl>;
+}
+
+impl CborValueExt for Value {
+	fn x_new_map() -> Value {
+		Value::Map(Vec::new())
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| CborValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	fn x_get_bytes(&self, name_or_pointer: &str) -> Result<&[u8]> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| CborValueExtError::property_not_found(self, name_or_pointer))?;
+
+		match untag(value) {
+			Value::Bytes(bytes) => Ok(bytes),
+			Value::Text(text) => Ok(text.as_bytes()),
+			other => Err(CborValueExtError::PropertyValueNotOfType {
+				name: name_or_pointer.to_string(),
+				not_of_type: "bytes",
+				actual: cbor_kind_name(other),
+			}),
+		}
+	}
+
+	fn x_get_tag(&self, name_or_pointer: &str) -> Result<(u64, &Value)> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| CborValueExtError::property_not_found(self, name_or_pointer))?;
+
+		value.as_tag().ok_or_else(|| CborValueExtError::PropertyValueNotOfType {
+			name: name_or_pointer.to_string(),
+			not_of_type: "tag",
+			actual: cbor_kind_name(value),
+		})
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value = match get_value_mut(self, name_or_pointer) {
+			Some(value) => std::mem::replace(value, Value::Null),
+			None => return Err(CborValueExtError::property_not_found(self, name_or_pointer)),
+		};
+
+		from_value_with_context(name_or_pointer, &value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = Value::serialized(&value)?;
+		let parts = path_tokens(name_or_pointer);
+		let last_idx = parts.len() - 1;
+
+		let mut current = self;
+		// the pointer of `current`, for the errors
+		let mut path = String::new();
+
+		// -- Add the eventual missing parents
+		for (i, part) in parts[..last_idx].iter().enumerate() {
+			let new_container = if is_array_token(&parts[i + 1]) {
+				Value::Array(Vec::new())
+			} else {
+				Value::Map(Vec::new())
+			};
+			current = match untag_mut(current) {
+				Value::Map(map) => {
+					let idx = match entry_index(map, part) {
+						Some(idx) => idx,
+						None => {
+							map.push((Value::Text(part.to_string()), new_container));
+							map.len() - 1
+						}
+					};
+					&mut map[idx].1
+				}
+				Value::Array(arr) => {
+					let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+					if idx == arr.len() {
+						arr.push(new_container);
+					}
+					&mut arr[idx]
+				}
+				other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+			};
+			path.push('/');
+			path.push_str(&escape_token(part));
+		}
+
+		// -- Set the value at the last element
+		let last_part = &parts[last_idx];
+		match untag_mut(current) {
+			Value::Map(map) => {
+				match entry_index(map, last_part) {
+					Some(idx) => map[idx].1 = new_value,
+					None => map.push((Value::Text(last_part.to_string()), new_value)),
+				}
+				Ok(())
+			}
+			Value::Array(arr) => {
+				let idx = array_insert_index(arr, last_part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_value);
+				} else {
+					arr[idx] = new_value;
+				}
+				Ok(())
+			}
+			other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+		}
+	}
+
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut CborMap, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		let mut queue = VecDeque::new();
+		queue.push_back(self);
+
+		while let Some(current) = queue.pop_front() {
+			match untag_mut(current) {
+				Value::Map(map) => {
+					let mut skipped_keys = Vec::new();
+					for key in map.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>() {
+						match callback(map, &key).into() {
+							WalkControl::Continue => (),
+							WalkControl::SkipChildren => skipped_keys.push(key),
+							WalkControl::Stop => return false,
+						}
+					}
+
+					for (key, value) in map.iter_mut() {
+						if is_container(value) && !skipped_keys.contains(key) {
+							queue.push_back(value);
+						}
+					}
+				}
+				Value::Array(arr) => {
+					for value in arr.iter_mut() {
+						if is_container(value) {
+							queue.push_back(value);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		true
+	}
+}
+
+// region:    --- Support
+
+fn untag(mut value: &Value) -> &Value {
+	while let Value::Tag(_, tagged) = value {
+		value = tagged;
+	}
+	value
+}
+
+fn untag_mut(mut value: &mut Value) -> &mut Value {
+	while let Value::Tag(_, tagged) = value {
+		value = tagged;
+	}
+	value
+}
+
+fn is_container(value: &Value) -> bool {
+	matches!(untag(value), Value::Map(_) | Value::Array(_))
+}
+
+/// Returns `true` if the map key matches the path token (text, byte string, or rendered integer key).
+fn key_matches(key: &Value, token: &str) -> bool {
+	match untag(key) {
+		Value::Text(text) => text == token,
+		Value::Bytes(bytes) => bytes.as_slice() == token.as_bytes(),
+		Value::Integer(n) => i128::from(*n).to_string() == token,
+		Value::Bool(b) => b.to_string() == token,
+		_ => false,
+	}
+}
+
+/// Returns the index of the map entry whose key matches the path token, if any.
+fn entry_index(map: &CborMap, token: &str) -> Option<usize> {
+	map.iter().position(|(key, _)| key_matches(key, token))
+}
+
+fn child<'a>(value: &'a Value, token: &str) -> Option<&'a Value> {
+	match untag(value) {
+		Value::Map(map) => entry_index(map, token).map(|idx| &map[idx].1),
+		Value::Array(arr) => token.parse::<usize>().ok().and_then(|idx| arr.get(idx)),
+		_ => None,
+	}
+}
+
+fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| child(current, token))
+}
+
+fn get_value_mut<'a>(value: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| match untag_mut(current) {
+			Value::Map(map) => {
+				let idx = entry_index(map, token)?;
+				Some(&mut map[idx].1)
+			}
+			Value::Array(arr) => arr.get_mut(token.parse::<usize>().ok()?),
+			_ => None,
+		})
+}
+
+/// Returns a copy of the value without tags, and with the byte strings as arrays of integers.
+fn normalized(value: &Value) -> Value {
+	match untag(value) {
+		Value::Bytes(bytes) => Value::Array(bytes.iter().map(|b| Value::Integer((*b).into())).collect()),
+		Value::Array(arr) => Value::Array(arr.iter().map(normalized).collect()),
+		Value::Map(map) => Value::Map(
+			map.iter()
+				.map(|(key, value)| (normalized(key), normalized(value)))
+				.collect(),
+		),
+		other => other.clone(),
+	}
+}
+
+/// Returns the index to set or append in `arr` (at `path`, for the requested `name`) for the token
+/// (`-` or `arr.len()` means append).
+fn array_insert_index(arr: &[Value], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		arr.len()
+	} else {
+		token.parse::<usize>().map_err(|_| CborValueExtError::NotAMap {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > arr.len() {
+		return Err(CborValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: arr.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Value, token: &str, name: &str, path: &str) -> CborValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), cbor_kind_name(value));
+	if is_array_token(token) {
+		CborValueExtError::NotAnArray { name, path, actual }
+	} else {
+		CborValueExtError::NotAMap { name, path, actual }
+	}
+}
+
+fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: &Value) -> Result<T> {
+	value
+		.deserialized()
+		// retry without the CBOR specifics
+		.or_else(|err| normalized(value).deserialized().map_err(|_| err))
+		.map_err(|cause| CborValueExtError::PropertyValueNotDeserializable {
+			name: name_or_pointer.to_string(),
+			not_of_type: std::any::type_name::<T>(),
+			actual: cbor_kind_name(value),
+			cause,
+		})
+}
+
+/// Returns the CBOR kind name of the value (e.g., `"text"`, `"map"`), as reported in the errors.
+fn cbor_kind_name(value: &Value) -> &'static str {
+	match value {
+		Value::Integer(_) => "integer",
+		Value::Bytes(_) => "bytes",
+		Value::Float(_) => "float",
+		Value::Text(_) => "text",
+		Value::Bool(_) => "bool",
+		Value::Null => "null",
+		Value::Tag(_, _) => "tag",
+		Value::Array(_) => "array",
+		Value::Map(_) => "map",
+		_ => "unknown",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, CborValueExtError>;
+
+/// The `CborValueExt` error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum CborValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` is not of type `not_of_type`, but of CBOR kind `actual`.
+	PropertyValueNotOfType {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+	},
+
+	/// The value at `name` (of CBOR kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: ciborium::value::Error,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of CBOR kind `actual`) is not a map, while the requested `name` needs it to be one.
+	NotAMap {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of CBOR kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	#[from]
+	Ciborium(ciborium::value::Error),
+}
+
+impl CborValueExtError {
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		let mut missing = String::new();
+		for token in path_tokens(name_or_pointer) {
+			match child(current, &token) {
+				Some(next) => {
+					resolved.push('/');
+					resolved.push_str(&escape_token(&token));
+					current = next;
+				}
+				None => {
+					missing = token;
+					break;
+				}
+			}
+		}
+
+		let suggestions = match untag(current) {
+			Value::Map(map) if !missing.is_empty() => {
+				let keys: Vec<String> = map
+					.iter()
+					.filter_map(|(key, _)| key.as_text().map(str::to_string))
+					.collect();
+				suggest_keys(&keys, &missing)
+			}
+			_ => Vec::new(),
+		};
+
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for CborValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for CborValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
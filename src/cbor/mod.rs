@@ -0,0 +1,7 @@
+// region:    --- Modules
+
+mod cbor_value_ext;
+
+pub use cbor_value_ext::*;
+
+// endregion: --- Modules
@@ -0,0 +1,146 @@
+use crate::json::hash::stable_hash;
+use crate::json::json_path::to_pointer;
+use crate::json::json_value_ext::{from_value_with_context, get_value, get_value_mut};
+use crate::{JsonValueExt, JsonValueExtError};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+/// The occurrence kept by `JsonValueExt::x_dedup_by` for each distinct key value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupKeep {
+	#[default]
+	First,
+	Last,
+}
+
+/// Appends the `value` to the array at the name or pointer path, which gets created if missing.
+pub(crate) fn push_value(root: &mut Value, name_or_pointer: &str, value: Value) -> Result<()> {
+	array_mut(root, name_or_pointer, true)?.push(value);
+	Ok(())
+}
+
+/// Appends the `values` to the array at the name or pointer path, which gets created if missing.
+pub(crate) fn extend_values(root: &mut Value, name_or_pointer: &str, values: Vec<Value>) -> Result<()> {
+	array_mut(root, name_or_pointer, true)?.extend(values);
+	Ok(())
+}
+
+/// Inserts the `value` at the `index` of the array at the name or pointer path, shifting the elements after it.
+pub(crate) fn insert_at(root: &mut Value, name_or_pointer: &str, index: usize, value: Value) -> Result<()> {
+	let arr = array_mut(root, name_or_pointer, false)?;
+	if index > arr.len() {
+		return Err(index_out_of_bounds(name_or_pointer, index, arr.len()));
+	}
+	arr.insert(index, value);
+	Ok(())
+}
+
+/// Removes and returns the element at the `index` of the array at the name or pointer path, deserialized as `T`.
+/// - The element is deserialized before the removal, so a type error leaves the array unchanged.
+pub(crate) fn remove_at<T: DeserializeOwned>(root: &mut Value, name_or_pointer: &str, index: usize) -> Result<T> {
+	let arr = array_mut(root, name_or_pointer, false)?;
+	if index >= arr.len() {
+		return Err(index_out_of_bounds(name_or_pointer, index, arr.len()));
+	}
+	let value: T = from_value_with_context(name_or_pointer, &arr[index])?;
+	arr.remove(index);
+	Ok(value)
+}
+
+/// Returns the elements of the array at `array_path` grouped by their value at `key_path`
+/// (e.g., `{"fruit": [...], "veggie": [...]}`).
+pub(crate) fn group_by(root: &Value, array_path: &str, key_path: &str) -> Result<Map<String, Value>> {
+	let arr = match get_value(root, array_path) {
+		Some(Value::Array(arr)) => arr,
+		Some(other) => return Err(not_an_array(array_path, other.x_kind().as_str())),
+		None => return Err(JsonValueExtError::property_not_found(root, array_path)),
+	};
+
+	let mut groups = Map::new();
+	for item in arr {
+		let Some(key) = get_value(item, key_path) else {
+			continue;
+		};
+		let key = match key {
+			Value::String(key) => key.clone(),
+			other => other.to_string(),
+		};
+		if let Value::Array(group) = groups.entry(key).or_insert_with(|| Value::Array(Vec::new())) {
+			group.push(item.clone());
+		}
+	}
+	Ok(groups)
+}
+
+/// Removes the elements of the array at `array_path` with the same value at `key_path` as another one, keeping one
+/// occurrence per `keep`, and returns the number of removed elements.
+pub(crate) fn dedup_by(root: &mut Value, array_path: &str, key_path: &str, keep: DedupKeep) -> Result<usize> {
+	let arr = array_mut(root, array_path, false)?;
+
+	// -- Flag the kept elements, from the end for `Last`
+	let mut kept = vec![true; arr.len()];
+	// the already seen keys, by their hash (with the index of the element having them)
+	let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+	let mut indices: Vec<usize> = (0..arr.len()).collect();
+	if keep == DedupKeep::Last {
+		indices.reverse();
+	}
+	for idx in indices {
+		let Some(key) = get_value(&arr[idx], key_path) else {
+			continue;
+		};
+		let same_hash = seen.entry(stable_hash(key)).or_default();
+		if same_hash
+			.iter()
+			.any(|seen_idx| get_value(&arr[*seen_idx], key_path) == Some(key))
+		{
+			kept[idx] = false;
+		} else {
+			same_hash.push(idx);
+		}
+	}
+
+	let len = arr.len();
+	let mut kept = kept.into_iter();
+	arr.retain(|_| kept.next().unwrap_or(true));
+	Ok(len - arr.len())
+}
+
+// region:    --- Support
+
+/// Returns the array at the name or pointer path, created if missing and `create` is `true`.
+pub(crate) fn array_mut<'a>(root: &'a mut Value, name_or_pointer: &str, create: bool) -> Result<&'a mut Vec<Value>> {
+	if create && get_value(root, name_or_pointer).is_none() {
+		root.x_insert(name_or_pointer, Value::Array(Vec::new()))?;
+	}
+	match get_value(root, name_or_pointer) {
+		Some(Value::Array(_)) => (),
+		Some(other) => return Err(not_an_array(name_or_pointer, other.x_kind().as_str())),
+		None => return Err(JsonValueExtError::property_not_found(root, name_or_pointer)),
+	}
+	get_value_mut(root, name_or_pointer)
+		.and_then(Value::as_array_mut)
+		.ok_or_else(|| not_an_array(name_or_pointer, "null"))
+}
+
+fn not_an_array(name_or_pointer: &str, actual: &'static str) -> JsonValueExtError {
+	JsonValueExtError::NotAnArray {
+		name: name_or_pointer.to_string(),
+		path: to_pointer(name_or_pointer),
+		actual,
+	}
+}
+
+fn index_out_of_bounds(name_or_pointer: &str, index: usize, len: usize) -> JsonValueExtError {
+	JsonValueExtError::IndexOutOfBounds {
+		name: name_or_pointer.to_string(),
+		path: to_pointer(name_or_pointer),
+		index,
+		len,
+	}
+}
+
+// endregion: --- Support
@@ -1,5 +1,7 @@
 use crate::JsonValueExtError;
-use serde_json::Value;
+use serde_json::{Map, Number, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 pub trait AsType<'a>: Sized {
 	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError>;
@@ -17,6 +19,99 @@ impl<'a> AsType<'a> for Option<&'a str> {
 	}
 }
 
+impl AsType<'_> for String {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_str()
+			.map(str::to_string)
+			.ok_or(JsonValueExtError::ValueNotOfType("String"))
+	}
+}
+
+impl AsType<'_> for Option<String> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_str().map(str::to_string))
+	}
+}
+
+/// Borrows when the value is a string, and allocates the formatted value when it is a number or a bool.
+impl<'a> AsType<'a> for Cow<'a, str> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		as_cow_str(value).ok_or(JsonValueExtError::ValueNotOfType("Cow<str>"))
+	}
+}
+
+impl<'a> AsType<'a> for Option<Cow<'a, str>> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_cow_str(value))
+	}
+}
+
+/// From a string holding exactly one `char` (Unicode scalar value).
+impl AsType<'_> for char {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_char(value).ok_or(JsonValueExtError::ValueNotOfType("char"))
+	}
+}
+
+impl AsType<'_> for Option<char> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_char(value))
+	}
+}
+
+impl<'a> AsType<'a> for &'a Value {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(value)
+	}
+}
+
+impl<'a> AsType<'a> for &'a Map<String, Value> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		value.as_object().ok_or(JsonValueExtError::ValueNotOfType("Map"))
+	}
+}
+
+impl<'a> AsType<'a> for Option<&'a Map<String, Value>> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_object())
+	}
+}
+
+impl<'a> AsType<'a> for &'a [Value] {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_array()
+			.map(Vec::as_slice)
+			.ok_or(JsonValueExtError::ValueNotOfType("[Value]"))
+	}
+}
+
+impl<'a> AsType<'a> for Option<&'a [Value]> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_array().map(Vec::as_slice))
+	}
+}
+
+/// Lossless access to the number (e.g., to check `is_i64()` / `is_f64()`, or with `arbitrary_precision`).
+impl<'a> AsType<'a> for &'a Number {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		match value {
+			Value::Number(number) => Ok(number),
+			_ => Err(JsonValueExtError::ValueNotOfType("Number")),
+		}
+	}
+}
+
+impl<'a> AsType<'a> for Option<&'a Number> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		match value {
+			Value::Number(number) => Ok(Some(number)),
+			_ => Ok(None),
+		}
+	}
+}
+
 impl AsType<'_> for f64 {
 	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
 		value.as_f64().ok_or(JsonValueExtError::ValueNotOfType("f64"))
@@ -29,6 +124,21 @@ impl AsType<'_> for Option<f64> {
 	}
 }
 
+impl AsType<'_> for f32 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_f64()
+			.and_then(f64_to_f32)
+			.ok_or(JsonValueExtError::ValueNotOfType("f32"))
+	}
+}
+
+impl AsType<'_> for Option<f32> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_f64().and_then(f64_to_f32))
+	}
+}
+
 impl AsType<'_> for i64 {
 	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
 		value.as_i64().ok_or(JsonValueExtError::ValueNotOfType("i64"))
@@ -71,6 +181,224 @@ impl AsType<'_> for Option<u32> {
 	}
 }
 
+impl AsType<'_> for u64 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value.as_u64().ok_or(JsonValueExtError::ValueNotOfType("u64"))
+	}
+}
+
+impl AsType<'_> for Option<u64> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_u64())
+	}
+}
+
+impl AsType<'_> for i16 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_i64()
+			.and_then(|v| i16::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("i16"))
+	}
+}
+
+impl AsType<'_> for Option<i16> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_i64().and_then(|v| i16::try_from(v).ok()))
+	}
+}
+
+impl AsType<'_> for i8 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_i64()
+			.and_then(|v| i8::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("i8"))
+	}
+}
+
+impl AsType<'_> for Option<i8> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_i64().and_then(|v| i8::try_from(v).ok()))
+	}
+}
+
+impl AsType<'_> for isize {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_i64()
+			.and_then(|v| isize::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("isize"))
+	}
+}
+
+impl AsType<'_> for Option<isize> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_i64().and_then(|v| isize::try_from(v).ok()))
+	}
+}
+
+impl AsType<'_> for u16 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_u64()
+			.and_then(|v| u16::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("u16"))
+	}
+}
+
+impl AsType<'_> for Option<u16> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_u64().and_then(|v| u16::try_from(v).ok()))
+	}
+}
+
+impl AsType<'_> for u8 {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_u64()
+			.and_then(|v| u8::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("u8"))
+	}
+}
+
+impl AsType<'_> for Option<u8> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_u64().and_then(|v| u8::try_from(v).ok()))
+	}
+}
+
+impl AsType<'_> for usize {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_u64()
+			.and_then(|v| usize::try_from(v).ok())
+			.ok_or(JsonValueExtError::ValueNotOfType("usize"))
+	}
+}
+
+impl AsType<'_> for Option<usize> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_u64().and_then(|v| usize::try_from(v).ok()))
+	}
+}
+
+/// Each element is extracted with its `AsType` (e.g., `Vec<i64>`, `Vec<f64>`, `Vec<bool>`, `Vec<&str>`).
+/// Fails with `ArrayElementNotOfType` (with the element index) when one element has the wrong type.
+impl<'a, T: AsType<'a>> AsType<'a> for Vec<T> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		let arr = value.as_array().ok_or(JsonValueExtError::ValueNotOfType("Vec"))?;
+		arr.iter()
+			.enumerate()
+			.map(|(index, item)| {
+				T::from_value(item).map_err(|err| match err {
+					JsonValueExtError::ValueNotOfType(not_of_type) => {
+						JsonValueExtError::ArrayElementNotOfType { index, not_of_type }
+					}
+					other => other,
+				})
+			})
+			.collect()
+	}
+}
+
+/// `None` if the value is not an array, or if one element has the wrong type.
+impl<'a, T: AsType<'a>> AsType<'a> for Option<Vec<T>> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(Vec::<T>::from_value(value).ok())
+	}
+}
+
+/// Each element is extracted with its `AsType` (e.g., `[f64; 3]`).
+/// Fails with `ArrayLengthMismatch` when the array length is not exactly `N`,
+/// or with `ArrayElementNotOfType` when one element has the wrong type.
+impl<'a, T: AsType<'a>, const N: usize> AsType<'a> for [T; N] {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		let arr = value.as_array().ok_or(JsonValueExtError::ValueNotOfType("[T; N]"))?;
+		if arr.len() != N {
+			return Err(JsonValueExtError::ArrayLengthMismatch {
+				expected: N,
+				actual: arr.len(),
+			});
+		}
+		let items: Vec<T> = Vec::from_value(value)?;
+		// length checked above
+		items
+			.try_into()
+			.map_err(|_| JsonValueExtError::ValueNotOfType("[T; N]"))
+	}
+}
+
+/// `None` if the value is not an array of length `N`, or if one element has the wrong type.
+impl<'a, T: AsType<'a>, const N: usize> AsType<'a> for Option<[T; N]> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(<[T; N]>::from_value(value).ok())
+	}
+}
+
+// region:    --- Tuples
+
+/// Implements `AsType` for a tuple mapping to a fixed-length JSON array (e.g., `["key", 42]` as `(&str, i64)`).
+macro_rules! impl_as_type_tuple {
+	($len:literal => $($idx:tt $name:ident),+) => {
+		impl<'a, $($name: AsType<'a>),+> AsType<'a> for ($($name,)+) {
+			fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+				let arr = value.as_array().ok_or(JsonValueExtError::ValueNotOfType("tuple"))?;
+				if arr.len() != $len {
+					return Err(JsonValueExtError::ArrayLengthMismatch {
+						expected: $len,
+						actual: arr.len(),
+					});
+				}
+				Ok(($(tuple_element::<$name>(arr, $idx)?,)+))
+			}
+		}
+
+		impl<'a, $($name: AsType<'a>),+> AsType<'a> for Option<($($name,)+)> {
+			fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+				Ok(<($($name,)+)>::from_value(value).ok())
+			}
+		}
+	};
+}
+
+impl_as_type_tuple!(1 => 0 A);
+impl_as_type_tuple!(2 => 0 A, 1 B);
+impl_as_type_tuple!(3 => 0 A, 1 B, 2 C);
+impl_as_type_tuple!(4 => 0 A, 1 B, 2 C, 3 D);
+
+fn tuple_element<'a, T: AsType<'a>>(arr: &'a [Value], index: usize) -> Result<T, JsonValueExtError> {
+	T::from_value(&arr[index]).map_err(|err| match err {
+		JsonValueExtError::ValueNotOfType(not_of_type) => {
+			JsonValueExtError::ArrayElementNotOfType { index, not_of_type }
+		}
+		other => other,
+	})
+}
+
+// endregion: --- Tuples
+
+/// Each property value is extracted with its `AsType` (e.g., `HashMap<&str, &str>`, `HashMap<&str, &Value>`).
+/// Fails with `PropertyValueNotOfType` (with the property name) when one value has the wrong type.
+impl<'a, T: AsType<'a>> AsType<'a> for HashMap<&'a str, T> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		let map = value.as_object().ok_or(JsonValueExtError::ValueNotOfType("HashMap"))?;
+		map.iter()
+			.map(|(key, item)| {
+				let item = T::from_value(item).map_err(|err| err.with_property_context(key, item))?;
+				Ok((key.as_str(), item))
+			})
+			.collect()
+	}
+}
+
+/// `None` if the value is not an object, or if one property value has the wrong type.
+impl<'a, T: AsType<'a>> AsType<'a> for Option<HashMap<&'a str, T>> {
+	fn from_value(value: &'a Value) -> Result<Self, JsonValueExtError> {
+		Ok(HashMap::<&'a str, T>::from_value(value).ok())
+	}
+}
+
 impl AsType<'_> for bool {
 	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
 		value.as_bool().ok_or(JsonValueExtError::ValueNotOfType("bool"))
@@ -82,3 +410,29 @@ impl AsType<'_> for Option<bool> {
 		Ok(value.as_bool())
 	}
 }
+
+// region:    --- Support
+
+/// Converts to f32 only if the value is within the f32 finite range (rather than silently producing infinities).
+fn f64_to_f32(v: f64) -> Option<f32> {
+	(v.is_finite() && (f32::MIN as f64..=f32::MAX as f64).contains(&v)).then_some(v as f32)
+}
+
+fn as_char(value: &Value) -> Option<char> {
+	let mut chars = value.as_str()?.chars();
+	match (chars.next(), chars.next()) {
+		(Some(c), None) => Some(c),
+		_ => None,
+	}
+}
+
+fn as_cow_str(value: &Value) -> Option<Cow<'_, str>> {
+	match value {
+		Value::String(s) => Some(Cow::Borrowed(s.as_str())),
+		Value::Number(n) => Some(Cow::Owned(n.to_string())),
+		Value::Bool(b) => Some(Cow::Owned(b.to_string())),
+		_ => None,
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,87 @@
+//! (feature `chrono`) `AsType` implementations for the chrono types.
+
+use crate::{AsType, JsonValueExtError};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::Value;
+
+/// From a RFC 3339 string (e.g., `2024-05-01T10:30:00Z`), or a number of seconds since the Unix epoch.
+impl AsType<'_> for DateTime<Utc> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_datetime_utc(value).ok_or(JsonValueExtError::ValueNotOfType("DateTime<Utc>"))
+	}
+}
+
+impl AsType<'_> for Option<DateTime<Utc>> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_datetime_utc(value))
+	}
+}
+
+/// From a `YYYY-MM-DD` string.
+impl AsType<'_> for NaiveDate {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_naive_date(value).ok_or(JsonValueExtError::ValueNotOfType("NaiveDate"))
+	}
+}
+
+impl AsType<'_> for Option<NaiveDate> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_naive_date(value))
+	}
+}
+
+/// From a `YYYY-MM-DDTHH:MM:SS[.fff]` string (or with a space separator), a RFC 3339 string (as UTC),
+/// or a number of seconds since the Unix epoch.
+impl AsType<'_> for NaiveDateTime {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_naive_datetime(value).ok_or(JsonValueExtError::ValueNotOfType("NaiveDateTime"))
+	}
+}
+
+impl AsType<'_> for Option<NaiveDateTime> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_naive_datetime(value))
+	}
+}
+
+// region:    --- Support
+
+fn as_datetime_utc(value: &Value) -> Option<DateTime<Utc>> {
+	match value {
+		Value::String(s) => DateTime::parse_from_rfc3339(s).ok().map(|dt| dt.with_timezone(&Utc)),
+		Value::Number(n) => {
+			if let Some(secs) = n.as_i64() {
+				DateTime::from_timestamp(secs, 0)
+			} else {
+				// floored, so the nanos of the pre-epoch timestamps are positive (e.g., `-1.5` is `-2` + 0.5s)
+				let secs = n.as_f64()?;
+				let (floor, nanos) = (secs.floor(), ((secs - secs.floor()) * 1e9).round() as u32);
+				// the nanos rounded up to a full second (e.g., `1.9999999999`) are carried into the seconds
+				let (floor, nanos) = if nanos >= 1_000_000_000 {
+					(floor + 1.0, 0)
+				} else {
+					(floor, nanos)
+				};
+				DateTime::from_timestamp(floor as i64, nanos)
+			}
+		}
+		_ => None,
+	}
+}
+
+fn as_naive_date(value: &Value) -> Option<NaiveDate> {
+	NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d").ok()
+}
+
+fn as_naive_datetime(value: &Value) -> Option<NaiveDateTime> {
+	match value {
+		Value::String(s) => NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+			.or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+			.ok()
+			.or_else(|| DateTime::parse_from_rfc3339(s).ok().map(|dt| dt.naive_utc())),
+		Value::Number(_) => as_datetime_utc(value).map(|dt| dt.naive_utc()),
+		_ => None,
+	}
+}
+
+// endregion: --- Support
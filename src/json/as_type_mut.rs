@@ -0,0 +1,57 @@
+use crate::JsonValueExtError;
+use serde_json::{Map, Value};
+
+/// The mutable counterpart of `AsType`, used by `JsonValueExt::x_get_as_mut` to mutate a nested value in place.
+pub trait AsTypeMut<'a>: Sized {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError>;
+}
+
+impl<'a> AsTypeMut<'a> for &'a mut Value {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		Ok(value)
+	}
+}
+
+impl<'a> AsTypeMut<'a> for &'a mut String {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		match value {
+			Value::String(s) => Ok(s),
+			_ => Err(JsonValueExtError::ValueNotOfType("String")),
+		}
+	}
+}
+
+impl<'a> AsTypeMut<'a> for Option<&'a mut String> {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		match value {
+			Value::String(s) => Ok(Some(s)),
+			_ => Ok(None),
+		}
+	}
+}
+
+impl<'a> AsTypeMut<'a> for &'a mut Vec<Value> {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		value
+			.as_array_mut()
+			.ok_or(JsonValueExtError::ValueNotOfType("Vec<Value>"))
+	}
+}
+
+impl<'a> AsTypeMut<'a> for Option<&'a mut Vec<Value>> {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_array_mut())
+	}
+}
+
+impl<'a> AsTypeMut<'a> for &'a mut Map<String, Value> {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		value.as_object_mut().ok_or(JsonValueExtError::ValueNotOfType("Map"))
+	}
+}
+
+impl<'a> AsTypeMut<'a> for Option<&'a mut Map<String, Value>> {
+	fn from_value_mut(value: &'a mut Value) -> Result<Self, JsonValueExtError> {
+		Ok(value.as_object_mut())
+	}
+}
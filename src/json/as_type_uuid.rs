@@ -0,0 +1,22 @@
+//! (feature `uuid`) `AsType` implementations for `uuid::Uuid`.
+
+use crate::{AsType, JsonValueExtError};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// From a hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`) or simple (`67e5504410b1426f9247bb680e5fe0c8`) string.
+impl AsType<'_> for Uuid {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_uuid(value).ok_or(JsonValueExtError::ValueNotOfType("Uuid"))
+	}
+}
+
+impl AsType<'_> for Option<Uuid> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_uuid(value))
+	}
+}
+
+fn as_uuid(value: &Value) -> Option<Uuid> {
+	Uuid::try_parse(value.as_str()?).ok()
+}
@@ -0,0 +1,82 @@
+use crate::json::array::push_value;
+use crate::{JsonValueExt, JsonValueExtError};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// A builder of a JSON object from name or pointer path edits (see `JsonValueExt::x_builder`).
+///
+/// The edits are applied in order by `build()`, which returns the first failing one (e.g., a `set` under a scalar).
+///
+/// ```rust
+/// let value = Value::x_builder()
+///     .set("/user/name", "Jen")
+///     .push("/tags", "admin")
+///     .push("/tags", "dev")
+///     .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct JsonObjectBuilder {
+	edits: Vec<Edit>,
+}
+
+impl JsonObjectBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the `value` at the name or pointer path (like `JsonValueExt::x_insert`).
+	pub fn set<T: Serialize>(mut self, name_or_pointer: &str, value: T) -> Self {
+		self.edits.push(Edit {
+			op: EditOp::Set,
+			name_or_pointer: name_or_pointer.to_string(),
+			value: serde_json::to_value(value),
+		});
+		self
+	}
+
+	/// Appends the `value` to the array at the name or pointer path, which gets created if missing.
+	pub fn push<T: Serialize>(mut self, name_or_pointer: &str, value: T) -> Self {
+		self.edits.push(Edit {
+			op: EditOp::Push,
+			name_or_pointer: name_or_pointer.to_string(),
+			value: serde_json::to_value(value),
+		});
+		self
+	}
+
+	/// Returns the object with all the edits applied, or the error of the first failing edit.
+	pub fn build(self) -> Result<Value, JsonValueExtError> {
+		let mut root = Value::Object(Map::new());
+		for Edit {
+			op,
+			name_or_pointer,
+			value,
+		} in self.edits
+		{
+			let value = value?;
+			match op {
+				EditOp::Set => root.x_insert(&name_or_pointer, value)?,
+				EditOp::Push => push_value(&mut root, &name_or_pointer, value)?,
+			}
+		}
+		Ok(root)
+	}
+}
+
+// region:    --- Support
+
+#[derive(Debug)]
+struct Edit {
+	op: EditOp,
+	name_or_pointer: String,
+	/// The serialized value (its error is reported by `build`).
+	value: Result<Value, serde_json::Error>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EditOp {
+	Set,
+	Push,
+}
+
+// endregion: --- Support
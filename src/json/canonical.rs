@@ -0,0 +1,98 @@
+use serde_json::{Number, Value};
+use std::fmt::Write;
+
+/// Returns the RFC 8785 (JSON Canonicalization Scheme) serialization of `value`.
+pub(crate) fn canonical(value: &Value) -> String {
+	let mut buf = String::new();
+	write_canonical(&mut buf, value);
+	buf
+}
+
+// region:    --- Support
+
+fn write_canonical(buf: &mut String, value: &Value) {
+	match value {
+		Value::Null => buf.push_str("null"),
+		Value::Bool(b) => buf.push_str(if *b { "true" } else { "false" }),
+		Value::Number(n) => write_number(buf, n),
+		Value::String(s) => write_string(buf, s),
+		Value::Array(arr) => {
+			buf.push('[');
+			for (idx, item) in arr.iter().enumerate() {
+				if idx > 0 {
+					buf.push(',');
+				}
+				write_canonical(buf, item);
+			}
+			buf.push(']');
+		}
+		Value::Object(map) => {
+			// JCS sorts the keys by their UTF-16 code units (not their UTF-8 bytes or chars).
+			let mut entries: Vec<_> = map.iter().collect();
+			entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+			buf.push('{');
+			for (idx, (key, item)) in entries.into_iter().enumerate() {
+				if idx > 0 {
+					buf.push(',');
+				}
+				write_string(buf, key);
+				buf.push(':');
+				write_canonical(buf, item);
+			}
+			buf.push('}');
+		}
+	}
+}
+
+/// Writes the JSON string, with the JCS escaping (which is the serde_json one): only `"`, `\`, and the control
+/// characters are escaped, with the short forms (e.g., `\n`) when they exist, and `\u00xx` otherwise.
+fn write_string(buf: &mut String, s: &str) {
+	// Serializing a `&str` cannot fail.
+	buf.push_str(&serde_json::to_string(s).unwrap_or_default());
+}
+
+/// Writes the number as an IEEE 754 double, formatted like the ECMAScript `Number.prototype.toString`
+/// (as JCS requires, so integers above 2^53 lose their precision).
+fn write_number(buf: &mut String, n: &Number) {
+	let f = n.as_f64().unwrap_or_default();
+	if f == 0.0 {
+		// also for -0
+		buf.push('0');
+		return;
+	}
+	if f < 0.0 {
+		buf.push('-');
+	}
+
+	// The `{:e}` format gives the shortest round-trip digits (e.g., `1.2345e6`), as ECMAScript does.
+	let scientific = format!("{:e}", f.abs());
+	let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+	let digits = mantissa.replace('.', "");
+	let k = digits.len() as i32;
+	// the value is `digits * 10^(n - k)`
+	let n = exponent.parse::<i32>().unwrap_or_default() + 1;
+
+	if k <= n && n <= 21 {
+		buf.push_str(&digits);
+		buf.extend(std::iter::repeat('0').take((n - k) as usize));
+	} else if 0 < n && n <= 21 {
+		let (int_part, frac_part) = digits.split_at(n as usize);
+		let _ = write!(buf, "{int_part}.{frac_part}");
+	} else if -6 < n && n <= 0 {
+		buf.push_str("0.");
+		buf.extend(std::iter::repeat('0').take(-n as usize));
+		buf.push_str(&digits);
+	} else {
+		let (first, rest) = digits.split_at(1);
+		buf.push_str(first);
+		if !rest.is_empty() {
+			buf.push('.');
+			buf.push_str(rest);
+		}
+		let sign = if n > 0 { '+' } else { '-' };
+		let _ = write!(buf, "e{sign}{}", (n - 1).abs());
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,50 @@
+use serde_json::{Number, Value};
+
+/// Returns the alternate representations of a scalar value, in the order they should be tried
+/// by `JsonValueExt::x_get_lenient`.
+/// - string: as integer, as float, as bool (`"true"`/`"false"`, case insensitive)
+/// - number: as integer (when a float has no fractional part), as bool (`1`/`0`), as string
+/// - bool: as number (`1`/`0`), as string
+pub(crate) fn coerce_candidates(value: &Value) -> Vec<Value> {
+	let mut candidates = Vec::new();
+
+	match value {
+		Value::String(s) => {
+			let s = s.trim();
+			if let Ok(n) = s.parse::<i64>() {
+				candidates.push(Value::from(n));
+			} else if let Ok(n) = s.parse::<u64>() {
+				candidates.push(Value::from(n));
+			}
+			if let Some(n) = s.parse::<f64>().ok().and_then(Number::from_f64) {
+				candidates.push(Value::Number(n));
+			}
+			if s.eq_ignore_ascii_case("true") {
+				candidates.push(Value::Bool(true));
+			} else if s.eq_ignore_ascii_case("false") {
+				candidates.push(Value::Bool(false));
+			}
+		}
+		Value::Number(n) => {
+			if let Some(f) = n
+				.as_f64()
+				.filter(|f| n.is_f64() && f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+			{
+				candidates.push(Value::from(f as i64));
+			}
+			match n.as_f64() {
+				Some(1.0) => candidates.push(Value::Bool(true)),
+				Some(0.0) => candidates.push(Value::Bool(false)),
+				_ => (),
+			}
+			candidates.push(Value::String(n.to_string()));
+		}
+		Value::Bool(b) => {
+			candidates.push(Value::from(*b as i64));
+			candidates.push(Value::String(b.to_string()));
+		}
+		Value::Null | Value::Array(_) | Value::Object(_) => (),
+	}
+
+	candidates
+}
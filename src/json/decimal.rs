@@ -0,0 +1,92 @@
+//! (feature `decimal`) `rust_decimal::Decimal` support.
+
+use crate::{AsType, JsonValueExtError};
+use rust_decimal::Decimal;
+use serde::{Serialize, Serializer};
+use serde_json::{Number, Value};
+use std::str::FromStr;
+
+/// How a `DecimalValue` serializes its `Decimal`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalFormat {
+	/// As a JSON number (note: without the serde_json `arbitrary_precision` feature,
+	/// non-integer numbers are stored as `f64` and may lose precision).
+	Number,
+	/// As a JSON string (e.g., `"12.3400"`), which is always lossless.
+	#[default]
+	String,
+}
+
+/// A `Decimal` serialized per its `DecimalFormat`, e.g., to `x_insert` it.
+///
+/// ```rust
+/// value.x_insert("/order/total", DecimalValue::number(total))?;
+/// value.x_insert("/order/tax", DecimalValue::from(tax))?; // DecimalFormat::String (the default)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalValue {
+	pub value: Decimal,
+	pub format: DecimalFormat,
+}
+
+impl DecimalValue {
+	pub fn new(value: Decimal, format: DecimalFormat) -> Self {
+		Self { value, format }
+	}
+
+	/// Serialized as a JSON number (see `DecimalFormat::Number`).
+	pub fn number(value: Decimal) -> Self {
+		Self::new(value, DecimalFormat::Number)
+	}
+
+	/// Serialized as a JSON string (see `DecimalFormat::String`).
+	pub fn string(value: Decimal) -> Self {
+		Self::new(value, DecimalFormat::String)
+	}
+}
+
+impl From<Decimal> for DecimalValue {
+	fn from(value: Decimal) -> Self {
+		Self::new(value, DecimalFormat::default())
+	}
+}
+
+impl Serialize for DecimalValue {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.format {
+			DecimalFormat::String => serializer.collect_str(&self.value),
+			DecimalFormat::Number => {
+				let number: Number = serde_json::from_str(&self.value.normalize().to_string()).map_err(|err| {
+					serde::ser::Error::custom(format!("decimal '{}' not a number: {err}", self.value))
+				})?;
+				number.serialize(serializer)
+			}
+		}
+	}
+}
+
+/// From a JSON number or a numeric string (e.g., `"12.34"` or `"1.2e3"`).
+impl AsType<'_> for Decimal {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		as_decimal(value).ok_or(JsonValueExtError::ValueNotOfType("Decimal"))
+	}
+}
+
+impl AsType<'_> for Option<Decimal> {
+	fn from_value(value: &Value) -> Result<Self, JsonValueExtError> {
+		Ok(as_decimal(value))
+	}
+}
+
+// region:    --- Support
+
+fn as_decimal(value: &Value) -> Option<Decimal> {
+	let s = match value {
+		Value::Number(n) => n.to_string(),
+		Value::String(s) => s.trim().to_string(),
+		_ => return None,
+	};
+	Decimal::from_str(&s).or_else(|_| Decimal::from_scientific(&s)).ok()
+}
+
+// endregion: --- Support
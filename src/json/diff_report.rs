@@ -0,0 +1,141 @@
+use crate::json::json_path::escape_token;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A structural diff between two JSON values, as returned by `JsonValueExt::x_diff_report`.
+///
+/// Objects are compared property by property, and arrays index by index.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DiffReport {
+	changes: Vec<DiffChange>,
+}
+
+/// A change at a given pointer path.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum DiffChange {
+	Added { path: String, value: Value },
+	Removed { path: String, value: Value },
+	Changed { path: String, old: Value, new: Value },
+}
+
+impl DiffChange {
+	pub fn path(&self) -> &str {
+		match self {
+			DiffChange::Added { path, .. } | DiffChange::Removed { path, .. } | DiffChange::Changed { path, .. } => {
+				path
+			}
+		}
+	}
+}
+
+impl DiffReport {
+	/// Returns all the changes, in document order.
+	pub fn changes(&self) -> &[DiffChange] {
+		&self.changes
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+
+	pub fn len(&self) -> usize {
+		self.changes.len()
+	}
+
+	/// Returns the `(path, value)` of the added properties and array elements.
+	pub fn added(&self) -> impl Iterator<Item = (&str, &Value)> {
+		self.changes.iter().filter_map(|change| match change {
+			DiffChange::Added { path, value } => Some((path.as_str(), value)),
+			_ => None,
+		})
+	}
+
+	/// Returns the `(path, value)` of the removed properties and array elements.
+	pub fn removed(&self) -> impl Iterator<Item = (&str, &Value)> {
+		self.changes.iter().filter_map(|change| match change {
+			DiffChange::Removed { path, value } => Some((path.as_str(), value)),
+			_ => None,
+		})
+	}
+
+	/// Returns the `(path, old, new)` of the changed values.
+	pub fn changed(&self) -> impl Iterator<Item = (&str, &Value, &Value)> {
+		self.changes.iter().filter_map(|change| match change {
+			DiffChange::Changed { path, old, new } => Some((path.as_str(), old, new)),
+			_ => None,
+		})
+	}
+
+	/// Returns the paths of all the changes.
+	pub fn paths(&self) -> impl Iterator<Item = &str> {
+		self.changes.iter().map(DiffChange::path)
+	}
+}
+
+impl IntoIterator for DiffReport {
+	type Item = DiffChange;
+	type IntoIter = std::vec::IntoIter<DiffChange>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.changes.into_iter()
+	}
+}
+
+/// Returns the report of the differences from `old` to `new`.
+pub(crate) fn diff_report(old: &Value, new: &Value) -> DiffReport {
+	let mut report = DiffReport::default();
+	report_at("", old, new, &mut report.changes);
+	report
+}
+
+fn report_at(path: &str, old: &Value, new: &Value, changes: &mut Vec<DiffChange>) {
+	if old == new {
+		return;
+	}
+
+	match (old, new) {
+		(Value::Object(old_map), Value::Object(new_map)) => {
+			for (key, old_value) in old_map {
+				let child_path = format!("{path}/{}", escape_token(key));
+				match new_map.get(key) {
+					Some(new_value) => report_at(&child_path, old_value, new_value, changes),
+					None => changes.push(DiffChange::Removed {
+						path: child_path,
+						value: old_value.clone(),
+					}),
+				}
+			}
+			for (key, new_value) in new_map {
+				if !old_map.contains_key(key) {
+					changes.push(DiffChange::Added {
+						path: format!("{path}/{}", escape_token(key)),
+						value: new_value.clone(),
+					});
+				}
+			}
+		}
+		(Value::Array(old_arr), Value::Array(new_arr)) => {
+			for idx in 0..old_arr.len().max(new_arr.len()) {
+				let child_path = format!("{path}/{idx}");
+				match (old_arr.get(idx), new_arr.get(idx)) {
+					(Some(old_value), Some(new_value)) => report_at(&child_path, old_value, new_value, changes),
+					(Some(old_value), None) => changes.push(DiffChange::Removed {
+						path: child_path,
+						value: old_value.clone(),
+					}),
+					(None, Some(new_value)) => changes.push(DiffChange::Added {
+						path: child_path,
+						value: new_value.clone(),
+					}),
+					(None, None) => (),
+				}
+			}
+		}
+		(old, new) => changes.push(DiffChange::Changed {
+			path: path.to_string(),
+			old: old.clone(),
+			new: new.clone(),
+		}),
+	}
+}
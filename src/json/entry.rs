@@ -0,0 +1,74 @@
+use crate::json::json_value_ext::{get_value, get_value_mut};
+use crate::{JsonValueExt, JsonValueExtError};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A property, existing or not, at a name or pointer path of a value (see `JsonValueExt::x_entry`).
+///
+/// ```rust
+/// value
+///     .x_entry("/counters/hits")
+///     .and_modify(|hits| *hits = json!(hits.as_i64().unwrap_or(0) + 1))
+///     .or_insert(1)?;
+/// ```
+pub struct JsonEntry<'a> {
+	root: &'a mut Value,
+	name_or_pointer: String,
+}
+
+impl<'a> JsonEntry<'a> {
+	pub(crate) fn new(root: &'a mut Value, name_or_pointer: &str) -> Self {
+		Self {
+			root,
+			name_or_pointer: name_or_pointer.to_string(),
+		}
+	}
+
+	/// Returns the name or pointer path of this entry.
+	pub fn name_or_pointer(&self) -> &str {
+		&self.name_or_pointer
+	}
+
+	/// Returns `true` if the property exists (even if its value is `Null`).
+	pub fn is_occupied(&self) -> bool {
+		get_value(self.root, &self.name_or_pointer).is_some()
+	}
+
+	/// Calls `f` with the existing value, if any.
+	pub fn and_modify(self, f: impl FnOnce(&mut Value)) -> Self {
+		if let Some(value) = get_value_mut(self.root, &self.name_or_pointer) {
+			f(value);
+		}
+		self
+	}
+
+	/// Returns the existing value, or inserts the `default` one (creating the missing parents like `x_insert`).
+	pub fn or_insert<T: Serialize>(self, default: T) -> Result<&'a mut Value, JsonValueExtError> {
+		self.or_insert_with(|| default)
+	}
+
+	/// Returns the existing value, or inserts the one returned by `f` (creating the missing parents like `x_insert`).
+	pub fn or_insert_with<T: Serialize>(self, f: impl FnOnce() -> T) -> Result<&'a mut Value, JsonValueExtError> {
+		let Self { root, name_or_pointer } = self;
+		if get_value_mut(root, &name_or_pointer).is_none() {
+			root.x_insert(&name_or_pointer, f())?;
+		}
+		// should not fail, as the value got inserted
+		inserted_value_mut(root, &name_or_pointer).ok_or_else(|| JsonValueExtError::InvalidPath {
+			name: name_or_pointer.clone(),
+			cause: "the inserted value cannot be resolved".to_string(),
+		})
+	}
+}
+
+// region:    --- Support
+
+/// Returns the value at the path, where the `-` last part (an append) is the last element of the array.
+fn inserted_value_mut<'a>(root: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	match name_or_pointer.strip_suffix("/-") {
+		Some(parent) if name_or_pointer.starts_with('/') => get_value_mut(root, parent)?.as_array_mut()?.last_mut(),
+		_ => get_value_mut(root, name_or_pointer),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,140 @@
+use crate::json::template::{scan_refs, Segment};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Options for `JsonValueExt::x_expand_env_with`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvOptions {
+	/// When set, only these env vars are expanded, the other references being kept as is (and reported as denied).
+	pub allowlist: Option<Vec<String>>,
+}
+
+impl EnvOptions {
+	/// Sets the `allowlist` to these env var names.
+	pub fn with_allowlist<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+		self.allowlist = Some(names.into_iter().map(Into::into).collect());
+		self
+	}
+}
+
+/// The report of `JsonValueExt::x_expand_env`, with the env var names (sorted, without duplicates).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvReport {
+	/// The env vars that were set, and expanded.
+	pub used: Vec<String>,
+	/// The env vars that were not set, with their reference replaced with their default or kept as is if none
+	/// (`${NAME:-default}` vs `${NAME}`).
+	pub missing: Vec<String>,
+	/// The env vars not in the `allowlist`, with their reference kept as is.
+	pub denied: Vec<String>,
+}
+
+impl EnvReport {
+	/// Returns `true` if all the referenced env vars were set and allowed.
+	pub fn is_complete(&self) -> bool {
+		self.denied.is_empty() && self.missing.is_empty()
+	}
+}
+
+/// Replaces the `${NAME}` and `${NAME:-default}` references of all the string leaves of `value` with the env vars.
+pub(crate) fn expand_env(value: &mut Value, options: &EnvOptions) -> EnvReport {
+	let mut names = Names::default();
+	expand_at(value, options, &mut names);
+	EnvReport {
+		used: names.used.into_iter().collect(),
+		missing: names.missing.into_iter().collect(),
+		denied: names.denied.into_iter().collect(),
+	}
+}
+
+// region:    --- Support
+
+#[derive(Default)]
+struct Names {
+	used: BTreeSet<String>,
+	missing: BTreeSet<String>,
+	denied: BTreeSet<String>,
+}
+
+fn expand_at(value: &mut Value, options: &EnvOptions, names: &mut Names) {
+	match value {
+		Value::Object(map) => {
+			for item in map.values_mut() {
+				expand_at(item, options, names);
+			}
+		}
+		Value::Array(arr) => {
+			for item in arr {
+				expand_at(item, options, names);
+			}
+		}
+		Value::String(text) if text.contains("${") => {
+			let expanded = expand_string(text, options, names);
+			*text = expanded;
+		}
+		_ => (),
+	}
+}
+
+fn expand_string(text: &str, options: &EnvOptions, names: &mut Names) -> String {
+	let mut expanded = String::with_capacity(text.len());
+	for segment in scan_refs(text) {
+		let reference = match segment {
+			Segment::Text(text) => {
+				expanded.push_str(text);
+				continue;
+			}
+			Segment::Ref(reference) => reference,
+		};
+		let (name, default) = match reference.split_once(":-") {
+			Some((name, default)) => (name, Some(default)),
+			None => (reference, None),
+		};
+
+		// not an env var reference (e.g., a `${/pointer}` template reference), so kept as is
+		if !is_env_name(name) {
+			push_reference(&mut expanded, reference);
+			continue;
+		}
+		let is_allowed = options
+			.allowlist
+			.as_ref()
+			.map_or(true, |allowlist| allowlist.iter().any(|allowed| allowed == name));
+		if !is_allowed {
+			names.denied.insert(name.to_string());
+			push_reference(&mut expanded, reference);
+			continue;
+		}
+		match (std::env::var(name), default) {
+			(Ok(var), _) => {
+				names.used.insert(name.to_string());
+				expanded.push_str(&var);
+			}
+			(Err(_), Some(default)) => {
+				names.missing.insert(name.to_string());
+				expanded.push_str(default);
+			}
+			(Err(_), None) => {
+				names.missing.insert(name.to_string());
+				push_reference(&mut expanded, reference);
+			}
+		}
+	}
+	expanded
+}
+
+/// Pushes a kept reference back as `${reference}`.
+fn push_reference(expanded: &mut String, reference: &str) {
+	expanded.push_str("${");
+	expanded.push_str(reference);
+	expanded.push('}');
+}
+
+/// Returns `true` for the env var names like `HOME` or `_APP_PORT_2` (ASCII letters, digits, and `_`).
+fn is_env_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// endregion: --- Support
@@ -0,0 +1,170 @@
+use crate::json::json_path::{path_tokens, tokens_match};
+use serde_json::{Map, Value};
+
+/// Options for `JsonValueExt::x_equals`.
+#[derive(Debug, Clone, Default)]
+pub struct EqOptions {
+	/// When `true`, arrays are equal when they have the same elements in any order (default `false`).
+	pub ignore_array_order: bool,
+	/// When `true`, a missing property equals a `null` one (default `false`).
+	pub missing_as_null: bool,
+	/// When set, numbers are compared as `f64` and equal when they differ by at most this value
+	/// (default `None`, so numbers are compared with `==`, where `1` and `1.0` differ).
+	pub float_tolerance: Option<f64>,
+	/// The pointer paths of the values to not compare, where a `*` part matches any property or index
+	/// (e.g., `/meta/updated_at` or `/items/*/id`).
+	pub ignore_paths: Vec<String>,
+}
+
+impl EqOptions {
+	pub fn with_ignore_array_order(mut self, ignore_array_order: bool) -> Self {
+		self.ignore_array_order = ignore_array_order;
+		self
+	}
+
+	pub fn with_missing_as_null(mut self, missing_as_null: bool) -> Self {
+		self.missing_as_null = missing_as_null;
+		self
+	}
+
+	pub fn with_float_tolerance(mut self, float_tolerance: f64) -> Self {
+		self.float_tolerance = Some(float_tolerance);
+		self
+	}
+
+	/// Adds a path (name or pointer path) to the ones to not compare.
+	pub fn with_ignore_path(mut self, name_or_pointer: &str) -> Self {
+		self.ignore_paths.push(name_or_pointer.to_string());
+		self
+	}
+}
+
+/// Returns `true` if `a` and `b` are equal per the `options`.
+pub(crate) fn values_equal(a: &Value, b: &Value, options: &EqOptions) -> bool {
+	let ignore_paths: Vec<Vec<String>> = options.ignore_paths.iter().map(|path| path_tokens(path)).collect();
+	let ctx = EqContext { options, ignore_paths };
+	ctx.equal(a, b, &mut Vec::new())
+}
+
+/// Returns `true` if `value` contains (recursively) everything of `pattern`.
+pub(crate) fn contains_subset(value: &Value, pattern: &Value) -> bool {
+	match (value, pattern) {
+		(Value::Object(map), Value::Object(pattern_map)) => pattern_map
+			.iter()
+			.all(|(key, pattern_item)| map.get(key).is_some_and(|item| contains_subset(item, pattern_item))),
+		(Value::Array(arr), Value::Array(pattern_arr)) => pattern_arr
+			.iter()
+			.all(|pattern_item| arr.iter().any(|item| contains_subset(item, pattern_item))),
+		_ => value == pattern,
+	}
+}
+
+// region:    --- Support
+
+struct EqContext<'a> {
+	options: &'a EqOptions,
+	/// The `ignore_paths` split in path tokens.
+	ignore_paths: Vec<Vec<String>>,
+}
+
+impl EqContext<'_> {
+	/// Returns `true` if `a` and `b` (at the path `tokens`) are equal.
+	fn equal(&self, a: &Value, b: &Value, tokens: &mut Vec<String>) -> bool {
+		if self.is_ignored(tokens) {
+			return true;
+		}
+
+		match (a, b) {
+			(Value::Number(a_num), Value::Number(b_num)) => match self.options.float_tolerance {
+				Some(tolerance) => match (a_num.as_f64(), b_num.as_f64()) {
+					(Some(a_f), Some(b_f)) => (a_f - b_f).abs() <= tolerance,
+					_ => a_num == b_num,
+				},
+				None => a_num == b_num,
+			},
+			(Value::Array(a_arr), Value::Array(b_arr)) => {
+				if a_arr.len() != b_arr.len() {
+					return false;
+				}
+				if self.options.ignore_array_order {
+					self.equal_unordered(a_arr, b_arr, tokens)
+				} else {
+					a_arr.iter().zip(b_arr).enumerate().all(|(idx, (a_item, b_item))| {
+						with_token(tokens, idx.to_string(), |tokens| self.equal(a_item, b_item, tokens))
+					})
+				}
+			}
+			(Value::Object(a_map), Value::Object(b_map)) => {
+				self.equal_entries(a_map, b_map, tokens) && self.equal_entries(b_map, a_map, tokens)
+			}
+			_ => a == b,
+		}
+	}
+
+	/// Returns `true` if every `a_map` property has an equal one in `b_map`.
+	fn equal_entries(&self, a_map: &Map<String, Value>, b_map: &Map<String, Value>, tokens: &mut Vec<String>) -> bool {
+		a_map.iter().all(|(key, a_item)| {
+			with_token(tokens, key.to_string(), |tokens| match b_map.get(key) {
+				Some(b_item) => self.equal(a_item, b_item, tokens),
+				None => self.is_ignored(tokens) || (self.options.missing_as_null && a_item.is_null()),
+			})
+		})
+	}
+
+	/// Returns `true` if every `a_arr` element has its own equal element in `b_arr` (in any order).
+	/// - The equality may not be transitive (e.g., with a `float_tolerance`), so the elements are paired with
+	///   a bipartite matching (augmenting paths), rather than with their first equal element.
+	fn equal_unordered(&self, a_arr: &[Value], b_arr: &[Value], tokens: &mut Vec<String>) -> bool {
+		// the indices of the `b_arr` elements equal to each `a_arr` element
+		let candidates: Vec<Vec<usize>> = a_arr
+			.iter()
+			.enumerate()
+			.map(|(idx, a_item)| {
+				with_token(tokens, idx.to_string(), |tokens| {
+					let equal_items = b_arr
+						.iter()
+						.enumerate()
+						.filter(|(_, b_item)| self.equal(a_item, b_item, tokens));
+					equal_items.map(|(b_idx, _)| b_idx).collect()
+				})
+			})
+			.collect();
+		if candidates.iter().any(Vec::is_empty) {
+			return false;
+		}
+
+		// the `a_arr` element paired with each `b_arr` element
+		let mut pairs: Vec<Option<usize>> = vec![None; b_arr.len()];
+		(0..a_arr.len()).all(|a_idx| augment(a_idx, &candidates, &mut pairs, &mut vec![false; b_arr.len()]))
+	}
+
+	fn is_ignored(&self, tokens: &[String]) -> bool {
+		self.ignore_paths.iter().any(|path| tokens_match(path, tokens))
+	}
+}
+
+/// Pairs the `a_idx` element with one of its `candidates`, re-pairing the already paired ones when needed,
+/// and returns `false` if there is no such pairing.
+fn augment(a_idx: usize, candidates: &[Vec<usize>], pairs: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+	for &b_idx in &candidates[a_idx] {
+		if visited[b_idx] {
+			continue;
+		}
+		visited[b_idx] = true;
+		if pairs[b_idx].map_or(true, |other_idx| augment(other_idx, candidates, pairs, visited)) {
+			pairs[b_idx] = Some(a_idx);
+			return true;
+		}
+	}
+	false
+}
+
+/// Runs `f` with the `token` pushed to the path `tokens`.
+fn with_token<T>(tokens: &mut Vec<String>, token: String, f: impl FnOnce(&mut Vec<String>) -> T) -> T {
+	tokens.push(token);
+	let res = f(tokens);
+	tokens.pop();
+	res
+}
+
+// endregion: --- Support
@@ -0,0 +1,95 @@
+use crate::json::pointer::token_index;
+use crate::{JsonPointer, JsonValueExtError};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A set of JSON Pointer paths compiled into a trie, to extract all of them in a single pass over a value
+/// (the shared prefixes, like `/user` of `/user/name` and `/user/age`, are only resolved once).
+///
+/// ```rust
+/// let extractor = Extractor::new(&["/id", "/user/name", "/items/0/id"])?;
+/// for value in values.iter() {
+///     // the `Option<&Value>` of each pointer, in order
+///     let found = extractor.extract(value);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Extractor {
+	pointers: Vec<JsonPointer>,
+	root: Node,
+}
+
+impl Extractor {
+	/// Compiles the pointers (validated like `JsonPointer::parse`).
+	pub fn new(pointers: &[&str]) -> Result<Self, JsonValueExtError> {
+		let pointers = pointers
+			.iter()
+			.map(|pointer| JsonPointer::parse(pointer))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut root = Node::default();
+		for (target, pointer) in pointers.iter().enumerate() {
+			let mut node = &mut root;
+			for token in pointer.tokens() {
+				node = node.children.entry(token.clone()).or_insert_with(|| Node {
+					index: token_index(token),
+					..Node::default()
+				});
+			}
+			node.targets.push(target);
+		}
+
+		Ok(Self { pointers, root })
+	}
+
+	/// Returns the compiled pointers, in the `new` order.
+	pub fn pointers(&self) -> &[JsonPointer] {
+		&self.pointers
+	}
+
+	/// Returns the values at each pointer (`None` when missing), in the `new` order.
+	pub fn extract<'a>(&self, value: &'a Value) -> Vec<Option<&'a Value>> {
+		let mut found = vec![None; self.pointers.len()];
+		self.root.extract(value, &mut found);
+		found
+	}
+
+	/// Same as `extract`, but with the cloned values, `Null` for the missing ones.
+	pub fn extract_cloned(&self, value: &Value) -> Vec<Value> {
+		self.extract(value)
+			.into_iter()
+			.map(|found| found.cloned().unwrap_or_default())
+			.collect()
+	}
+}
+
+// region:    --- Support
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+	/// The array index of the token of this node, if it is one.
+	index: Option<usize>,
+	/// The indices of the pointers ending at this node.
+	targets: Vec<usize>,
+	children: HashMap<String, Node>,
+}
+
+impl Node {
+	fn extract<'a>(&self, value: &'a Value, found: &mut [Option<&'a Value>]) {
+		for target in &self.targets {
+			found[*target] = Some(value);
+		}
+		for (token, child) in &self.children {
+			let child_value = match value {
+				Value::Object(map) => map.get(token),
+				Value::Array(arr) => child.index.and_then(|idx| arr.get(idx)),
+				_ => None,
+			};
+			if let Some(child_value) = child_value {
+				child.extract(child_value, found);
+			}
+		}
+	}
+}
+
+// endregion: --- Support
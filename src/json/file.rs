@@ -0,0 +1,108 @@
+use crate::json::pretty::pretty;
+use crate::{JsonValueExtError, PrettyOptions};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Options for `JsonValueExt::x_save_file_with`.
+///
+/// By default, the JSON is written compact, and atomically.
+#[derive(Debug, Clone)]
+pub struct SaveOptions {
+	/// When set, the JSON is pretty-printed with these options (default `None`, compact).
+	pub pretty: Option<PrettyOptions>,
+	/// When `true`, the JSON is written to a temporary file of the same directory, and then renamed to the `path`,
+	/// so the readers never see a partially written file (default `true`).
+	pub atomic: bool,
+}
+
+impl Default for SaveOptions {
+	fn default() -> Self {
+		Self {
+			pretty: None,
+			atomic: true,
+		}
+	}
+}
+
+impl SaveOptions {
+	pub fn with_pretty(mut self, pretty: PrettyOptions) -> Self {
+		self.pretty = Some(pretty);
+		self
+	}
+
+	pub fn with_atomic(mut self, atomic: bool) -> Self {
+		self.atomic = atomic;
+		self
+	}
+}
+
+/// Returns the JSON value of the file at `path`.
+pub(crate) fn load_file(path: &Path) -> Result<Value, JsonValueExtError> {
+	let content = fs::read(path).map_err(|cause| file_io(path, cause))?;
+	parse_file_content(path, &content)
+}
+
+/// Writes the JSON of `value` to the file at `path` per the `options`.
+pub(crate) fn save_file(value: &Value, path: &Path, options: &SaveOptions) -> Result<(), JsonValueExtError> {
+	let content = file_content(value, options)?;
+
+	if !options.atomic {
+		return fs::write(path, content).map_err(|cause| file_io(path, cause));
+	}
+
+	let tmp_path = tmp_path(path);
+	let res = write_synced(&tmp_path, &content).and_then(|_| fs::rename(&tmp_path, path));
+	if let Err(cause) = res {
+		let _ = fs::remove_file(&tmp_path);
+		return Err(file_io(path, cause));
+	}
+	Ok(())
+}
+
+// region:    --- Support
+
+/// Returns the JSON value of the `content` of the file at `path`.
+pub(crate) fn parse_file_content(path: &Path, content: &[u8]) -> Result<Value, JsonValueExtError> {
+	serde_json::from_slice(content).map_err(|cause| JsonValueExtError::FileNotParsable {
+		path: path.to_path_buf(),
+		cause,
+	})
+}
+
+/// Returns the file content of `value` per the `options` (compact, or pretty).
+pub(crate) fn file_content(value: &Value, options: &SaveOptions) -> Result<Vec<u8>, JsonValueExtError> {
+	match &options.pretty {
+		Some(pretty_options) => Ok(pretty(value, pretty_options).into_bytes()),
+		None => Ok(serde_json::to_vec(value)?),
+	}
+}
+
+/// The counter of the temporary files, so the concurrent atomic writes of a process never share one.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the unique temporary file path of an atomic write to `path` (e.g., `dir/.config.json.tmp-1234-0`).
+pub(crate) fn tmp_path(path: &Path) -> PathBuf {
+	let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+	let count = TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+	path.with_file_name(format!(".{file_name}.tmp-{}-{count}", std::process::id()))
+}
+
+/// Writes the `content` to the new file at `path` (failing if it already exists), synced to the disk.
+fn write_synced(path: &Path, content: &[u8]) -> std::io::Result<()> {
+	let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+	file.write_all(content)?;
+	file.sync_all()
+}
+
+/// Returns the `FileIo` error of the file at `path`.
+pub(crate) fn file_io(path: &Path, cause: std::io::Error) -> JsonValueExtError {
+	JsonValueExtError::FileIo {
+		path: path.to_path_buf(),
+		cause,
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,119 @@
+//! (feature `tokio`) The async versions of the file helpers of `file.rs`, using the tokio fs.
+
+use crate::json::file::{file_io, parse_file_content, tmp_path};
+use crate::JsonValueExtError;
+use serde_json::Value;
+use std::path::Path;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+/// The size of the chunks written by `to_async_writer`.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Returns the JSON value of the file at `path`.
+pub(crate) async fn load_file_async(path: &Path) -> Result<Value> {
+	let content = fs::read(path).await.map_err(|cause| file_io(path, cause))?;
+	parse_file_content(path, &content)
+}
+
+/// Writes the `content` to the file at `path`, atomically (see `file::save_file`) if `atomic`.
+pub(crate) async fn save_file_async(path: &Path, content: Vec<u8>, atomic: bool) -> Result<()> {
+	if !atomic {
+		return fs::write(path, content).await.map_err(|cause| file_io(path, cause));
+	}
+
+	let tmp_path = tmp_path(path);
+	let res = match write_synced(&tmp_path, &content).await {
+		Ok(()) => fs::rename(&tmp_path, path).await,
+		Err(err) => Err(err),
+	};
+	if let Err(cause) = res {
+		let _ = fs::remove_file(&tmp_path).await;
+		return Err(file_io(path, cause));
+	}
+	Ok(())
+}
+
+/// Writes the compact JSON of `value` to `writer`, in chunks of about `CHUNK_SIZE` bytes, so without building
+/// the full JSON text (the containers are written element by element).
+pub(crate) async fn to_async_writer<W: AsyncWrite + Unpin>(value: &Value, mut writer: W) -> Result<()> {
+	/// An open container, with its remaining children, and whether none was written yet.
+	enum Frame<'a> {
+		Object(serde_json::map::Iter<'a>, bool),
+		Array(std::slice::Iter<'a, Value>, bool),
+	}
+
+	let mut buf = Vec::with_capacity(CHUNK_SIZE);
+	let mut stack = Vec::new();
+	let mut next = Some(value);
+
+	loop {
+		// -- Write the next value (opening it, when a container)
+		match next.take() {
+			Some(Value::Object(map)) => {
+				buf.push(b'{');
+				stack.push(Frame::Object(map.iter(), true));
+			}
+			Some(Value::Array(arr)) => {
+				buf.push(b'[');
+				stack.push(Frame::Array(arr.iter(), true));
+			}
+			Some(scalar) => serde_json::to_writer(&mut buf, scalar)?,
+			None => (),
+		}
+
+		// -- Select the next child of the current container, or close it
+		let Some(frame) = stack.last_mut() else {
+			break;
+		};
+		match frame {
+			Frame::Object(iter, first) => match iter.next() {
+				Some((key, child)) => {
+					if !std::mem::take(first) {
+						buf.push(b',');
+					}
+					serde_json::to_writer(&mut buf, key)?;
+					buf.push(b':');
+					next = Some(child);
+				}
+				None => {
+					buf.push(b'}');
+					stack.pop();
+				}
+			},
+			Frame::Array(iter, first) => match iter.next() {
+				Some(child) => {
+					if !std::mem::take(first) {
+						buf.push(b',');
+					}
+					next = Some(child);
+				}
+				None => {
+					buf.push(b']');
+					stack.pop();
+				}
+			},
+		}
+
+		if buf.len() >= CHUNK_SIZE {
+			writer.write_all(&buf).await?;
+			buf.clear();
+		}
+	}
+
+	writer.write_all(&buf).await?;
+	writer.flush().await?;
+	Ok(())
+}
+
+// region:    --- Support
+
+async fn write_synced(path: &Path, content: &[u8]) -> std::io::Result<()> {
+	let mut file = OpenOptions::new().write(true).create_new(true).open(path).await?;
+	file.write_all(content).await?;
+	file.sync_all().await
+}
+
+// endregion: --- Support
@@ -0,0 +1,247 @@
+use crate::json::json_path::{escape_token, is_array_token, path_tokens};
+use crate::JsonValueExtError;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// Options for `JsonValueExt::x_flatten_with`.
+///
+/// By default, the keys are dot paths (e.g., `user.address.city`), with the array indices as parts (e.g., `items.0.name`).
+#[derive(Debug, Clone, Default)]
+pub struct FlattenOptions {
+	pub keys: FlattenKeys,
+	pub arrays: FlattenArrays,
+}
+
+/// The syntax of the flattened keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlattenKeys {
+	/// Dot paths (e.g., `user.address.city`), with the `.`, `\`, and `[` of the keys (and a leading `/`)
+	/// escaped with a `\` (as read by `JsonPath::dot` and `x_unflatten`).
+	#[default]
+	Dot,
+	/// JSON Pointer paths (e.g., `/user/address/city`).
+	Pointer,
+}
+
+/// How the arrays get flattened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlattenArrays {
+	/// Each element gets its own key, with its index as a part (e.g., `items.0.name` or `/items/0/name`).
+	#[default]
+	Index,
+	/// Same as `Index`, but with bracket indices for the dot keys (e.g., `items[0].name`).
+	Brackets,
+	/// The arrays are kept as values (e.g., `tags` -> `["a", "b"]`).
+	Keep,
+}
+
+impl FlattenOptions {
+	pub fn with_keys(mut self, keys: FlattenKeys) -> Self {
+		self.keys = keys;
+		self
+	}
+
+	pub fn with_arrays(mut self, arrays: FlattenArrays) -> Self {
+		self.arrays = arrays;
+		self
+	}
+}
+
+/// Returns the single level map of all the leaves of `value`, keyed by their paths.
+pub(crate) fn flatten(value: &Value, options: &FlattenOptions) -> Map<String, Value> {
+	let mut flat = Map::new();
+	flatten_into(value, &mut String::new(), options, &mut flat);
+	flat
+}
+
+/// Returns the nested value rebuilt from the `flat` map keys (pointer paths when they start with `/`, dot paths otherwise).
+pub(crate) fn unflatten(flat: Map<String, Value>) -> Result<Value, JsonValueExtError> {
+	let mut root = Node::Branch(BTreeMap::new());
+	for (key, value) in flat {
+		let tokens = key_tokens(&key);
+		// the `""` key is the root value (e.g., from a flattened scalar)
+		if tokens.is_empty() {
+			match &root {
+				Node::Branch(children) if children.is_empty() => root = Node::Leaf(value),
+				_ => return Err(unflatten_conflict(key, &[])),
+			}
+			continue;
+		}
+
+		let mut current = &mut root;
+		for (depth, token) in tokens.iter().enumerate() {
+			let Node::Branch(children) = current else {
+				return Err(unflatten_conflict(key, &tokens[..depth]));
+			};
+			let is_last = depth == tokens.len() - 1;
+			if is_last {
+				if children.contains_key(token) {
+					return Err(unflatten_conflict(key, &tokens));
+				}
+				children.insert(token.clone(), Node::Leaf(value));
+				break;
+			}
+			current = children
+				.entry(token.clone())
+				.or_insert_with(|| Node::Branch(BTreeMap::new()));
+		}
+	}
+
+	Ok(root.into_value())
+}
+
+// region:    --- Support
+
+/// A node of the tree being unflattened.
+enum Node {
+	Leaf(Value),
+	Branch(BTreeMap<String, Node>),
+}
+
+impl Node {
+	/// Returns the value for this node, where the branches with the `0..len` keys become arrays.
+	fn into_value(self) -> Value {
+		match self {
+			Node::Leaf(value) => value,
+			Node::Branch(children) => {
+				let is_array = !children.is_empty()
+					&& children.keys().all(|key| is_array_token(key) && key != "-")
+					&& (0..children.len()).all(|idx| children.contains_key(&idx.to_string()));
+				if is_array {
+					let mut items: Vec<(usize, Value)> = children
+						.into_iter()
+						.map(|(key, node)| (key.parse::<usize>().unwrap_or_default(), node.into_value()))
+						.collect();
+					items.sort_by_key(|(idx, _)| *idx);
+					Value::Array(items.into_iter().map(|(_, value)| value).collect())
+				} else {
+					let map = children
+						.into_iter()
+						.map(|(key, node)| (key, node.into_value()))
+						.collect();
+					Value::Object(map)
+				}
+			}
+		}
+	}
+}
+
+/// Returns the tokens of a flat key, a pointer path if it starts with `/`, otherwise a dot path
+/// (with the `[0]` indices, and the `\` escapes, where `\[` is not an index and a leading `\/` is not a pointer).
+fn key_tokens(key: &str) -> Vec<String> {
+	if key.is_empty() {
+		return Vec::new();
+	}
+	if key.starts_with('/') {
+		return path_tokens(key);
+	}
+
+	let mut tokens = Vec::new();
+	let mut token = String::new();
+	let mut chars = key.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => token.push(chars.next().unwrap_or('\\')),
+			'.' => tokens.push(std::mem::take(&mut token)),
+			'[' => {
+				let index: String = chars.clone().take_while(|c| *c != ']').collect();
+				let is_index = !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit());
+				if is_index && chars.clone().nth(index.len()) == Some(']') {
+					// `a[0]` and `a[0][1]` (the token before is only empty for a leading or repeated index)
+					if !token.is_empty() {
+						tokens.push(std::mem::take(&mut token));
+					}
+					tokens.push(index.clone());
+					chars.nth(index.len());
+					// skip the `.` of `a[0].b`
+					if chars.peek() == Some(&'.') {
+						chars.next();
+					}
+					continue;
+				}
+				token.push(c);
+			}
+			c => token.push(c),
+		}
+	}
+	// a key ending with an index has nothing left
+	if !token.is_empty() || !key.ends_with(']') {
+		tokens.push(token);
+	}
+	tokens
+}
+
+fn unflatten_conflict(key: String, tokens: &[String]) -> JsonValueExtError {
+	let path = tokens.iter().map(|token| format!("/{}", escape_token(token))).collect();
+	JsonValueExtError::UnflattenConflict { key, path }
+}
+
+fn flatten_into(value: &Value, key: &mut String, options: &FlattenOptions, flat: &mut Map<String, Value>) {
+	match value {
+		Value::Object(map) if !map.is_empty() => {
+			for (name, item) in map {
+				let len = key.len();
+				push_name(key, name, options);
+				flatten_into(item, key, options, flat);
+				key.truncate(len);
+			}
+		}
+		Value::Array(arr) if !arr.is_empty() && options.arrays != FlattenArrays::Keep => {
+			for (idx, item) in arr.iter().enumerate() {
+				let len = key.len();
+				push_index(key, idx, options);
+				flatten_into(item, key, options, flat);
+				key.truncate(len);
+			}
+		}
+		// the scalars, the empty containers, and the kept arrays
+		_ => {
+			flat.insert(key.clone(), value.clone());
+		}
+	}
+}
+
+fn push_name(key: &mut String, name: &str, options: &FlattenOptions) {
+	match options.keys {
+		FlattenKeys::Dot => {
+			// a leading `/` would be read as a pointer path by `x_unflatten`
+			if key.is_empty() && name.starts_with('/') {
+				key.push('\\');
+			} else if !key.is_empty() {
+				key.push('.');
+			}
+			for c in name.chars() {
+				if matches!(c, '.' | '\\' | '[') {
+					key.push('\\');
+				}
+				key.push(c);
+			}
+		}
+		FlattenKeys::Pointer => {
+			key.push('/');
+			key.push_str(&escape_token(name));
+		}
+	}
+}
+
+fn push_index(key: &mut String, idx: usize, options: &FlattenOptions) {
+	match (options.keys, options.arrays) {
+		(FlattenKeys::Dot, FlattenArrays::Brackets) => {
+			key.push('[');
+			key.push_str(&idx.to_string());
+			key.push(']');
+		}
+		(FlattenKeys::Dot, _) => {
+			if !key.is_empty() {
+				key.push('.');
+			}
+			key.push_str(&idx.to_string());
+		}
+		(FlattenKeys::Pointer, _) => {
+			key.push('/');
+			key.push_str(&idx.to_string());
+		}
+	}
+}
+
+// endregion: --- Support
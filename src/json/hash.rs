@@ -0,0 +1,98 @@
+use serde_json::{Number, Value};
+use std::hash::Hasher;
+
+/// Returns the stable 64 bit FNV-1a structural hash of `value` (see `hash_value`).
+pub(crate) fn stable_hash(value: &Value) -> u64 {
+	let mut hasher = Fnv1aHasher::default();
+	hash_value(value, &mut hasher);
+	hasher.finish()
+}
+
+/// Feeds the structure of `value` into the `hasher`, with the object entries in key order (so the insertion
+/// order has no effect), and the integers as little endian bytes (so the bytes are the same on all the platforms).
+pub(crate) fn hash_value<H: Hasher>(value: &Value, hasher: &mut H) {
+	match value {
+		Value::Null => hasher.write_u8(0),
+		Value::Bool(b) => {
+			hasher.write_u8(1);
+			hasher.write_u8(*b as u8);
+		}
+		Value::Number(n) => {
+			hasher.write_u8(2);
+			hash_number(n, hasher);
+		}
+		Value::String(s) => {
+			hasher.write_u8(3);
+			hash_str(s, hasher);
+		}
+		Value::Array(arr) => {
+			hasher.write_u8(4);
+			hash_len(arr.len(), hasher);
+			for item in arr {
+				hash_value(item, hasher);
+			}
+		}
+		Value::Object(map) => {
+			hasher.write_u8(5);
+			hash_len(map.len(), hasher);
+			let mut entries: Vec<_> = map.iter().collect();
+			entries.sort_unstable_by_key(|(key, _)| *key);
+			for (key, item) in entries {
+				hash_str(key, hasher);
+				hash_value(item, hasher);
+			}
+		}
+	}
+}
+
+// region:    --- Support
+
+/// Hashes the number per its `serde_json` equality (`1` and `1.0` are different, `0.0` and `-0.0` are equal).
+fn hash_number<H: Hasher>(n: &Number, hasher: &mut H) {
+	if let Some(u) = n.as_u64() {
+		hasher.write_u8(0);
+		hasher.write(&u.to_le_bytes());
+	} else if let Some(i) = n.as_i64() {
+		hasher.write_u8(1);
+		hasher.write(&i.to_le_bytes());
+	} else {
+		let f = n.as_f64().unwrap_or_default();
+		// `+ 0.0` turns the `-0.0` into `0.0`
+		hasher.write_u8(2);
+		hasher.write(&(f + 0.0).to_bits().to_le_bytes());
+	}
+}
+
+fn hash_str<H: Hasher>(s: &str, hasher: &mut H) {
+	hash_len(s.len(), hasher);
+	hasher.write(s.as_bytes());
+}
+
+fn hash_len<H: Hasher>(len: usize, hasher: &mut H) {
+	hasher.write(&(len as u64).to_le_bytes());
+}
+
+/// The 64 bit FNV-1a hasher, which (unlike the std `DefaultHasher`) is specified, so its hashes are stable
+/// across Rust versions and processes.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+	fn default() -> Self {
+		Self(0xcbf2_9ce4_8422_2325)
+	}
+}
+
+impl Hasher for Fnv1aHasher {
+	fn finish(&self) -> u64 {
+		self.0
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.0 ^= *byte as u64;
+			self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+}
+
+// endregion: --- Support
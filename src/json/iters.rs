@@ -0,0 +1,187 @@
+use crate::json::walk::child_path;
+use serde_json::{map, Value};
+
+/// A lazy depth-first iterator over the scalar leaves (`Null`, `Bool`, `Number`, `String`) of a JSON value tree,
+/// yielding `(pointer_path, &Value)` in document order.
+///
+/// Returned by `JsonValueExt::x_leaves`.
+pub struct Leaves<'a> {
+	stack: Vec<(String, &'a Value)>,
+}
+
+impl<'a> Leaves<'a> {
+	pub(crate) fn new(root: &'a Value) -> Self {
+		Self {
+			stack: vec![(String::new(), root)],
+		}
+	}
+}
+
+impl<'a> Iterator for Leaves<'a> {
+	type Item = (String, &'a Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some((path, value)) = self.stack.pop() {
+			match value {
+				Value::Object(map) => {
+					// reversed, so the first child is popped first
+					for (key, child) in map.iter().rev() {
+						self.stack.push((child_path(&path, key), child));
+					}
+				}
+				Value::Array(arr) => {
+					for (idx, child) in arr.iter().enumerate().rev() {
+						self.stack.push((format!("{path}/{idx}"), child));
+					}
+				}
+				_ => return Some((path, value)),
+			}
+		}
+		None
+	}
+}
+
+/// Same as `Leaves`, but yielding mutable references to the leaves.
+///
+/// Returned by `JsonValueExt::x_leaves_mut`.
+pub struct LeavesMut<'a> {
+	stack: Vec<(String, &'a mut Value)>,
+}
+
+impl<'a> LeavesMut<'a> {
+	pub(crate) fn new(root: &'a mut Value) -> Self {
+		Self {
+			stack: vec![(String::new(), root)],
+		}
+	}
+}
+
+impl<'a> Iterator for LeavesMut<'a> {
+	type Item = (String, &'a mut Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some((path, value)) = self.stack.pop() {
+			match value {
+				Value::Object(map) => {
+					for (key, child) in map.iter_mut().rev() {
+						self.stack.push((child_path(&path, key), child));
+					}
+				}
+				Value::Array(arr) => {
+					for (idx, child) in arr.iter_mut().enumerate().rev() {
+						self.stack.push((format!("{path}/{idx}"), child));
+					}
+				}
+				_ => return Some((path, value)),
+			}
+		}
+		None
+	}
+}
+
+/// A lazy depth-first (pre-order) iterator over the pointer paths of all the values of a JSON value tree
+/// (the root value excluded), in document order.
+///
+/// Returned by `JsonValueExt::x_paths`.
+pub struct Paths<'a> {
+	nodes: Nodes<'a>,
+}
+
+impl<'a> Paths<'a> {
+	pub(crate) fn new(root: &'a Value) -> Self {
+		let mut nodes = Nodes::new(root);
+		// skip the root value
+		nodes.next();
+		Self { nodes }
+	}
+}
+
+impl Iterator for Paths<'_> {
+	type Item = String;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.nodes.next().map(|(path, _)| path)
+	}
+}
+
+/// A lazy depth-first (pre-order) iterator over all the `(pointer_path, &Value)` of a JSON value tree
+/// (the root value included, with an empty path).
+pub(crate) struct Nodes<'a> {
+	stack: Vec<(String, &'a Value)>,
+}
+
+impl<'a> Nodes<'a> {
+	pub(crate) fn new(root: &'a Value) -> Self {
+		Self {
+			stack: vec![(String::new(), root)],
+		}
+	}
+}
+
+impl<'a> Iterator for Nodes<'a> {
+	type Item = (String, &'a Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (path, value) = self.stack.pop()?;
+		match value {
+			Value::Object(map) => {
+				for (key, child) in map.iter().rev() {
+					self.stack.push((child_path(&path, key), child));
+				}
+			}
+			Value::Array(arr) => {
+				for (idx, child) in arr.iter().enumerate().rev() {
+					self.stack.push((format!("{path}/{idx}"), child));
+				}
+			}
+			_ => (),
+		}
+		Some((path, value))
+	}
+}
+
+/// An iterator over the `(property_name, &Value)` of a JSON object (empty if the value is not an object).
+///
+/// Returned by `JsonValueExt::x_entries`.
+pub struct Entries<'a> {
+	iter: Option<map::Iter<'a>>,
+}
+
+impl<'a> Entries<'a> {
+	pub(crate) fn new(value: &'a Value) -> Self {
+		Self {
+			iter: value.as_object().map(|map| map.iter()),
+		}
+	}
+}
+
+impl<'a> Iterator for Entries<'a> {
+	type Item = (&'a str, &'a Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.as_mut()?.next().map(|(key, value)| (key.as_str(), value))
+	}
+}
+
+/// Same as `Entries`, but yielding mutable references to the property values.
+///
+/// Returned by `JsonValueExt::x_entries_mut`.
+pub struct EntriesMut<'a> {
+	iter: Option<map::IterMut<'a>>,
+}
+
+impl<'a> EntriesMut<'a> {
+	pub(crate) fn new(value: &'a mut Value) -> Self {
+		Self {
+			iter: value.as_object_mut().map(|map| map.iter_mut()),
+		}
+	}
+}
+
+impl<'a> Iterator for EntriesMut<'a> {
+	type Item = (&'a str, &'a mut Value);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.as_mut()?.next().map(|(key, value)| (key.as_str(), value))
+	}
+}
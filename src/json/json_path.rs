@@ -0,0 +1,221 @@
+use std::ops::Deref;
+
+/// A JSON Pointer path built from another path syntax (e.g., dot notation).
+///
+/// `JsonPath` derefs to `&str`, so it can be passed to any `name_or_pointer` argument
+/// of the `JsonValueExt` accessors (`x_get`, `x_take`, `x_insert`, ...).
+///
+/// ```rust
+/// let city: String = value.x_get(&JsonPath::dot("user.address.city"))?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonPath(String);
+
+impl JsonPath {
+	/// Creates a path from a dot notation path (e.g., `user.address.city` or `items.0.name`).
+	///
+	/// - A key containing a `.` can be escaped with `\.` (e.g., `files.readme\.md`).
+	/// - A `\` can be escaped with `\\`.
+	/// - Numeric parts address array elements (same as pointer paths).
+	pub fn dot(dot_path: &str) -> Self {
+		let mut pointer = String::with_capacity(dot_path.len() + 1);
+		pointer.push('/');
+
+		let mut chars = dot_path.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'\\' => match chars.next() {
+					Some(escaped) => push_escaped(&mut pointer, escaped),
+					None => pointer.push('\\'),
+				},
+				'.' => pointer.push('/'),
+				c => push_escaped(&mut pointer, c),
+			}
+		}
+
+		Self(pointer)
+	}
+
+	/// Returns the JSON Pointer representation of this path.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+/// An owned JSON Pointer path built segment by segment, with the `~` and `/` of the keys escaped per RFC 6901
+/// (so a key like `a/b` is addressed safely).
+///
+/// Like `JsonPath`, `PointerBuf` derefs to `&str`, so it can be passed to any `name_or_pointer` argument.
+///
+/// ```rust
+/// let mut pointer = PointerBuf::new();
+/// pointer.push("files").push("docs/readme.md").push_index(0);
+/// assert_eq!(pointer.as_str(), "/files/docs~1readme.md/0");
+/// ```
+///
+/// Note: The empty (root) pointer is read as the direct `""` name by the accessors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PointerBuf(String);
+
+impl PointerBuf {
+	/// Creates an empty (root) pointer.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends a key (or any reference token), escaped.
+	pub fn push(&mut self, segment: &str) -> &mut Self {
+		self.0.push('/');
+		self.0.push_str(&escape_token(segment));
+		self
+	}
+
+	/// Appends an array index.
+	pub fn push_index(&mut self, index: usize) -> &mut Self {
+		self.0.push('/');
+		self.0.push_str(&index.to_string());
+		self
+	}
+
+	/// Removes the last segment, and returns it unescaped (`None` for the root pointer).
+	pub fn pop(&mut self) -> Option<String> {
+		let idx = self.0.rfind('/')?;
+		let segment = unescape_token(&self.0[idx + 1..]);
+		self.0.truncate(idx);
+		Some(segment)
+	}
+
+	/// Returns `true` for the empty (root) pointer.
+	pub fn is_root(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns the JSON Pointer representation of this path.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+/// Pushes a key char into the pointer, escaping `~` and `/` per RFC 6901.
+fn push_escaped(pointer: &mut String, c: char) {
+	match c {
+		'~' => pointer.push_str("~0"),
+		'/' => pointer.push_str("~1"),
+		c => pointer.push(c),
+	}
+}
+
+/// Escapes a JSON Pointer reference token (`~` to `~0`, and `/` to `~1`), e.g., to build a pointer from a key.
+pub fn escape_token(token: &str) -> String {
+	token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Unescapes a JSON Pointer reference token (`~1` to `/`, and `~0` to `~`), e.g., to get a key from a pointer.
+pub fn unescape_token(token: &str) -> String {
+	token.replace("~1", "/").replace("~0", "~")
+}
+
+/// Returns the (unescaped) tokens of a direct name (a single token) or a pointer path (if it starts with '/').
+pub(crate) fn path_tokens(name_or_pointer: &str) -> Vec<String> {
+	if name_or_pointer.starts_with('/') {
+		name_or_pointer.split('/').skip(1).map(unescape_token).collect()
+	} else {
+		vec![name_or_pointer.to_string()]
+	}
+}
+
+/// Returns the pointer of a direct name or a pointer path (e.g., `a/b` -> `/a~1b`), as reported in the errors.
+pub(crate) fn to_pointer(name_or_pointer: &str) -> String {
+	if name_or_pointer.starts_with('/') {
+		name_or_pointer.to_string()
+	} else {
+		format!("/{}", escape_token(name_or_pointer))
+	}
+}
+
+/// Returns `true` if the token addresses an array element (numeric or `-`).
+pub(crate) fn is_array_token(token: &str) -> bool {
+	token == "-" || (!token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Returns `true` if the path `tokens` match the `pattern` tokens, where a `*` pattern token matches any token.
+pub(crate) fn tokens_match(pattern: &[String], tokens: &[String]) -> bool {
+	pattern.len() == tokens.len()
+		&& pattern
+			.iter()
+			.zip(tokens)
+			.all(|(part, token)| part == "*" || part == token)
+}
+
+// region:    --- Froms & Deref
+
+impl Deref for JsonPath {
+	type Target = str;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl AsRef<str> for JsonPath {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<JsonPath> for String {
+	fn from(path: JsonPath) -> Self {
+		path.0
+	}
+}
+
+impl core::fmt::Display for JsonPath {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl Deref for PointerBuf {
+	type Target = str;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl AsRef<str> for PointerBuf {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<PointerBuf> for String {
+	fn from(pointer: PointerBuf) -> Self {
+		pointer.0
+	}
+}
+
+impl From<JsonPath> for PointerBuf {
+	fn from(path: JsonPath) -> Self {
+		Self(path.0)
+	}
+}
+
+/// Builds the pointer from its (unescaped) segments, e.g., `["files", "docs/readme.md"]`.
+impl<S: AsRef<str>> FromIterator<S> for PointerBuf {
+	fn from_iter<I: IntoIterator<Item = S>>(segments: I) -> Self {
+		let mut pointer = Self::new();
+		for segment in segments {
+			pointer.push(segment.as_ref());
+		}
+		pointer
+	}
+}
+
+impl core::fmt::Display for PointerBuf {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+// endregion: --- Froms & Deref
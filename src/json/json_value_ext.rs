@@ -1,8 +1,63 @@
-use crate::AsType;
+use crate::json::array::{dedup_by, extend_values, group_by, insert_at, push_value, remove_at};
+use crate::json::canonical::canonical;
+use crate::json::coerce::coerce_candidates;
+use crate::json::diff_report::diff_report;
+use crate::json::env::expand_env;
+use crate::json::equals::{contains_subset, values_equal};
+#[cfg(feature = "tokio")]
+use crate::json::file::file_content;
+use crate::json::file::{load_file, save_file};
+#[cfg(feature = "tokio")]
+use crate::json::file_async::{load_file_async, save_file_async, to_async_writer};
+use crate::json::flatten::{flatten, unflatten};
+use crate::json::hash::{hash_value, stable_hash};
+use crate::json::iters::Nodes;
+use crate::json::json_path::{escape_token, is_array_token, path_tokens, unescape_token};
+use crate::json::key_case::rename_keys;
+use crate::json::lines::append_jsonl;
+use crate::json::merge::merge_value;
+use crate::json::order::remove_key;
+#[cfg(feature = "preserve_order")]
+use crate::json::order::{insert_at_position, move_key_before};
+#[cfg(feature = "rayon")]
+use crate::json::par_walk::{par_leaves, par_walk_ref};
+use crate::json::patch::{apply_patch, diff_values};
+use crate::json::pointer::token_index;
+use crate::json::pretty::pretty;
+#[cfg(feature = "color")]
+use crate::json::pretty::pretty_colored;
+use crate::json::preview::preview;
+use crate::json::projection::{omit, pick, project};
+use crate::json::prune::prune;
+use crate::json::redact::redact;
+use crate::json::relocate::{copy_value, move_value, rename_key};
+use crate::json::replace::replace_where;
+use crate::json::schema::{coerce_to_schema, infer_schema};
+use crate::json::sort::{sort_array_by, sort_keys_by};
+use crate::json::stats::stats;
+use crate::json::template::render_template;
+use crate::json::visitor::accept;
+use crate::json::walk::{walk_mut, walk_ref, walk_with_path};
+#[cfg(feature = "toml")]
+use crate::toml::{json_to_toml, toml_to_json};
+#[cfg(feature = "yaml")]
+use crate::yaml::{json_to_yaml, yaml_to_json};
+use crate::{
+	AsType, AsTypeMut, Case, CopyMode, DedupKeep, DiffOptions, DiffReport, Entries, EntriesMut, EnvOptions, EnvReport,
+	EqOptions, FlattenOptions, JsonEntry, JsonKind, JsonObjectBuilder, JsonPointer, JsonQuery, JsonStats, JsonVisitor,
+	Leaves, LeavesMut, MergeOptions, Order, Patch, Paths, PrettyOptions, PruneOptions, RedactRules, SaveOptions,
+	TemplateOptions, WalkControl, WalkOrder,
+};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{json, Map, Value};
-use std::collections::VecDeque;
+#[cfg(feature = "raw_value")]
+use serde_json::value::RawValue;
+use serde_json::{json, Map, Number, Value};
+use std::cmp::Ordering;
+#[cfg(feature = "tokio")]
+use std::future::Future;
+use std::hash::Hasher;
+use std::path::Path;
 
 /// Extension trait for working with JSON values in a more convenient way.
 ///
@@ -12,16 +67,90 @@ use std::collections::VecDeque;
 ///
 /// # Provided Methods
 ///
+/// - **`x_builder`**: Returns a `JsonObjectBuilder`, to build a nested object from name or pointer path edits.
+/// - **`x_contains`**: Returns `true` if a property exists at the given name or pointer path.
+/// - **`x_is_null`**: Returns `true` if a property exists at the given name or pointer path and is `Null`.
+/// - **`x_kind`** / **`x_len`** / **`x_is_empty`**: Returns the `JsonKind`, and the length (or emptiness) of a string, array, or object.
 /// - **`x_get`**: Returns a value of a specified type `T` from a JSON object using either a direct name or a pointer path. (will do a new allocation)
+/// - **`x_get_pointer`** / **`x_take_pointer`** / **`x_insert_pointer`**: Same as `x_get` / `x_take` / `x_insert`, with a pre-parsed `JsonPointer`.
+/// - **`x_get_opt`**: Same as `x_get`, but returns `None` if the property does not exist.
+/// - **`x_get_or`** / **`x_get_or_default`**: Same as `x_get`, but fall back to a default value if the property does not exist.
+/// - **`x_get_all`**: Returns all the values (with their pointer paths) matching a pointer path with `*` wildcards.
+/// - **`x_get_many`**: Returns the values at several names or pointer paths (`Vec<Result<Value>>`), or as a typed tuple with the `x_get_many!` macro.
+/// - **`x_get_raw`**: (feature `raw_value`) Returns the compact JSON of a subtree as a `RawValue`, to forward it as is.
+/// - **`x_get_lenient`**: Same as `x_get`, but converts scalars across representations (e.g., `"42"` -> `42`).
 /// - **`x_get_as`**: Returns a reference of a specified type `T` from a JSON object using either a direct name or a pointer path.
+/// - **`x_get_as_mut`**: Returns a mutable reference of a specified type `T` (e.g., `&mut String`, `&mut Vec<Value>`) to mutate it in place.
 /// - **`x_get_str`**: Returns a `&str` from a JSON object using either a direct name or a pointer path.
 /// - **`x_get_i64`**: Returns an `i64` from a JSON object using either a direct name or a pointer path.
 /// - **`x_get_f64`**: Returns an `f64` from a JSON object using either a direct name or a pointer path.
 /// - **`x_get_bool`**: Returns a `bool` from a JSON object using either a direct name or a pointer path.
 /// - **`x_take`**: Takes a value from a JSON object using a specified name or pointer path, replacing it with `Null`.
+/// - **`x_take_opt`**: Same as `x_take`, but returns `None` if the property does not exist.
+/// - **`x_remove`**: Removes a value from its parent object (or array) using a specified name or pointer path, and returns it.
+/// - **`x_take_prune`**: Same as `x_remove`, but also removes the ancestor objects or arrays that became empty.
+/// - **`x_insert_at_position`** / **`x_move_key_before`**: (feature `preserve_order`) Inserts a property at a position, and moves one before a sibling.
+/// - **`x_rename`**: Renames the key of the property at a name or pointer path, keeping its value (and position).
+/// - **`x_move`**: Moves a value from one name or pointer path to another (JSON Patch `move`), atomically.
+/// - **`x_copy`** / **`x_copy_with`**: Copies (deep clone) a value from one name or pointer path to another, overwriting or erroring if it exists.
 /// - **`x_insert`**: Inserts a value of type `T` into a JSON object at the specified name or pointer path, creating any missing objects along the way.
+/// - **`x_insert_many`**: Inserts all the `(name_or_pointer, value)` pairs, and reports every failure (not just the first).
+/// - **`x_push`** / **`x_insert_at`** / **`x_remove_at`**: Appends to, inserts at an index of, or removes at an index of the array at a name or pointer path.
+/// - **`x_extend`**: Appends the serialized items of an iterator to the array at a name or pointer path, creating it if missing.
+/// - **`x_group_by`**: Returns the elements of an array grouped by their value at a key path (e.g., `/category`).
+/// - **`x_dedup_by`**: Removes the elements of an array with the same value at a key path, keeping the first (or last) occurrence.
+/// - **`x_entry`**: Returns the `JsonEntry` at a name or pointer path, to modify the existing value (`and_modify`) or insert a missing one (`or_insert`).
+/// - **`x_get_or_insert_with`**: Returns the mutable value at a name or pointer path, inserting a lazily created default if missing.
+/// - **`x_merge`**: Deep merges another JSON value into this one, with configurable array and null strategies.
+/// - **`x_diff`** / **`x_diff_with`**: Returns the RFC 6902 JSON Patch transforming this value into another one.
+/// - **`x_apply_patch`**: Applies a RFC 6902 JSON Patch to the value, atomically.
+/// - **`x_diff_report`**: Returns a structural report of the added, removed, and changed paths to another value.
 /// - **`x_walk`**: Traverses all properties within the JSON value tree, applying a user-provided callback function on each property.
+/// - **`x_query`** / **`x_query_mut`**: Queries the JSON value tree with a JSONPath expression (see `JsonQuery`).
+/// - **`x_walk_with_path`**: Same as `x_walk`, but the callback also receives the JSON pointer path of the property.
+/// - **`x_walk_mut`** / **`x_walk_mut_with`**: Walks depth-first (pre-order or post-order) through all the values, passing the path and a mutable reference to each value.
+/// - **`x_walk_ref`** / **`x_walk_ref_with`**: Same as `x_walk_mut`, but read-only (on `&self`).
+/// - **`x_par_walk_ref`** / **`x_par_leaves`**: (feature `rayon`) Parallel versions of `x_walk_ref` and `x_leaves`.
+/// - **`x_accept`**: Drives a `JsonVisitor` (enter/leave object and array, entry, element, and scalar hooks) over the JSON value tree.
+/// - **`x_leaves`** / **`x_leaves_mut`**: Returns a lazy iterator over every scalar leaf with its pointer path.
+/// - **`x_paths`**: Returns a lazy iterator over every pointer path of the JSON value tree.
+/// - **`x_map_strings`**: Replaces every string leaf with the value returned by a `(path, string)` closure.
+/// - **`x_map_numbers`**: Replaces every number leaf with the value returned by a `(path, number)` closure.
+/// - **`x_replace_where`**: Replaces every value matching a `(path, value)` predicate, and returns the replaced paths.
+/// - **`x_find_first`** / **`x_find_all`**: Returns the first (or all) values matching a `(path, value)` predicate, with their pointer paths.
+/// - **`x_entries`** / **`x_entries_mut`**: Returns an iterator over the top-level `(property_name, value)` of a JSON object.
+/// - **`x_infer_schema`**: Returns a JSON Schema describing the shape of the value (e.g., for LLM structured output schemas).
+/// - **`x_coerce_to_schema`**: Coerces the value in place to conform to a JSON Schema (e.g., `"42"` -> `42`, `"a"` -> `["a"]`).
+/// - **`x_equals`**: Deep equality with options (ignore array order, missing as null, float tolerance, ignored paths).
+/// - **`x_contains_subset`**: Returns `true` if the value contains (recursively) every property and value of a pattern.
+/// - **`x_flatten`** / **`x_flatten_with`**: Returns the single level map of all the leaves, keyed by their dot or pointer paths.
+/// - **`x_unflatten`**: Returns the nested value rebuilt from a flat map of dot or pointer paths, reporting the conflicts.
+/// - **`x_rename_keys`** / **`x_rename_keys_with`**: Renames every object key to a `Case` (snake, camel, Pascal, kebab), except under the excluded paths.
+/// - **`x_prune`**: Removes recursively the `null` values and the empty containers (or strings), per `PruneOptions`.
+/// - **`x_redact`**: Replaces the values matching key names, key globs, or pointer paths with a placeholder or a hash.
+/// - **`x_pick`** / **`x_omit`** / **`x_project`**: Returns a new value with only (or without) some paths, or with values mapped to new paths.
+/// - **`x_render_template`** / **`x_render_template_with`**: Substitutes the `${path}` references of the string leaves with their values in a context.
+/// - **`x_expand_env`** / **`x_expand_env_with`**: Replaces the `${ENV_VAR}` / `${ENV_VAR:-default}` references of the string leaves, with a report.
+/// - **`x_sort_keys`** / **`x_sort_keys_by`**: Sorts (recursively) the object keys lexicographically or with a comparator.
+/// - **`x_sort_array_by`** / **`x_sort_array_by_keys`**: Sorts in place the elements of an array by their values at one or more key paths.
+/// - **`x_stats`**: Returns the structural statistics (node counts by kind, max depth, string bytes, estimated heap size).
+/// - **`x_canonical`**: Returns the RFC 8785 (JCS) canonical serialization of the value.
+/// - **`x_hash`** / **`x_hash_with`**: Returns the structural hash of the value, independent of the object key insertion order.
+/// - **`x_to_toml`** / **`x_from_toml`**: (feature `toml`) Converts to and from `toml::Value`, reporting the values with no representation.
+/// - **`x_to_yaml`** / **`x_from_yaml`**: (feature `yaml`) Converts to and from `serde_yaml::Value`, reporting the YAML constructs with no JSON representation.
 /// - **`x_pretty`**: Returns a pretty-printed string representation of the JSON value.
+/// - **`x_to_writer`** / **`x_pretty_to_writer`**: Writes the compact (or pretty) JSON to a `std::io::Write`, without building a `String`.
+/// - **`x_append_jsonl`**: Appends the compact JSON of the value as a line to a JSON Lines file (see the `lines` module).
+/// - **`x_load_file`** / **`x_save_file`** / **`x_save_file_with`**: Loads a JSON file, and saves one (atomically, compact or pretty).
+/// - **`x_load_file_async`** / **`x_save_file_async`** / **`x_save_file_with_async`** / **`x_to_async_writer`**: (feature `tokio`) The async file helpers, and a streaming `AsyncWrite` writer.
+/// - **`x_preview`**: Returns a compact single line preview, with truncated strings and arrays, at most `max_bytes` long.
+/// - **`x_pretty_with`**: Same as `x_pretty`, with a custom indent, sorted keys, one line small arrays, and a trailing newline.
+/// - **`x_pretty_colored`**: (feature `color`) Same as `x_pretty`, with ANSI syntax colors (honoring `NO_COLOR`).
+///
+/// # Paths
+///
+/// All `name_or_pointer` arguments can be a direct name, a JSON Pointer path (if it starts with '/'),
+/// or a `&JsonPath` (e.g., `&JsonPath::dot("user.address.city")`) for other path syntaxes.
 ///
 /// # Usage
 ///
@@ -64,17 +193,96 @@ use std::collections::VecDeque;
 pub trait JsonValueExt {
 	fn x_new_object() -> Value;
 
-	fn x_contains<T: DeserializeOwned>(&self, name_or_pointer: &str) -> bool;
+	/// Returns a new `JsonObjectBuilder`, to build a nested object from name or pointer path edits
+	/// (e.g., `Value::x_builder().set("/user/name", "Jen").push("/tags", "admin").build()?`).
+	fn x_builder() -> JsonObjectBuilder;
+
+	/// Returns `true` if a property exists at the given name or pointer path (even if its value is `Null`).
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_contains(&self, name_or_pointer: &str) -> bool;
+
+	/// Returns `true` if a property exists at the given name or pointer path and its value is `Null`.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_is_null(&self, name_or_pointer: &str) -> bool;
+
+	/// Returns the kind of this value (e.g., `JsonKind::Object`).
+	fn x_kind(&self) -> JsonKind;
+
+	/// Returns the length of this value: the bytes of a string, the elements of an array, or the properties of an object
+	/// (`None` for the other kinds).
+	fn x_len(&self) -> Option<usize>;
+
+	/// Returns `true` if this value is an empty string, array, or object (`false` for the other kinds, including `Null`).
+	fn x_is_empty(&self) -> bool {
+		self.x_len() == Some(0)
+	}
 
 	/// Returns an owned type `T` for a given name or pointer path.
 	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
 	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
 
+	/// Returns an owned type `T` for a given name or pointer path, or `None` if the property does not exist.
+	/// Type and deserialization errors are still returned as errors.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get_opt<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<Option<T>>;
+
+	/// Returns an owned type `T` for a given name or pointer path, or `default` if the property does not exist.
+	/// Type and deserialization errors are still returned as errors.
+	fn x_get_or<T: DeserializeOwned>(&self, name_or_pointer: &str, default: T) -> Result<T> {
+		Ok(self.x_get_opt(name_or_pointer)?.unwrap_or(default))
+	}
+
+	/// Returns an owned type `T` for a given name or pointer path, or `T::default()` if the property does not exist.
+	/// Type and deserialization errors are still returned as errors.
+	fn x_get_or_default<T: DeserializeOwned + Default>(&self, name_or_pointer: &str) -> Result<T> {
+		Ok(self.x_get_opt(name_or_pointer)?.unwrap_or_default())
+	}
+
+	/// Returns an owned type `T` for a given name or pointer path, converting scalars across representations
+	/// when the value does not deserialize as is (e.g., `"42"` -> `42`, `1`/`0` -> `true`/`false`, `3` -> `"3"`).
+	/// - Only the value itself is coerced (not the nested elements of an array or object).
+	/// - When no representation matches, the error of the strict `x_get` is returned.
+	fn x_get_lenient<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Returns all the values (as owned type `T`) matching a pointer path with `*` wildcards,
+	/// along with their concrete pointer paths.
+	/// - A `*` part matches every element of an array or every property of an object at that level.
+	/// - Paths that do not resolve are skipped (an empty `Vec` is returned when nothing matches).
+	///
+	/// e.g., `value.x_get_all::<String>("/items/*/name")`
+	fn x_get_all<T: DeserializeOwned>(&self, pointer: &str) -> Result<Vec<(String, T)>>;
+
+	/// Returns the values at each of the names or pointer paths (like `x_get::<Value>`), in the same order,
+	/// with the error of each missing one.
+	/// - See the `x_get_many!` macro to get them as a typed tuple (e.g., `(String, i64)`).
+	fn x_get_many(&self, names_or_pointers: &[&str]) -> Vec<Result<Value>> {
+		names_or_pointers
+			.iter()
+			.map(|name_or_pointer| self.x_get(name_or_pointer))
+			.collect()
+	}
+
+	/// (feature `raw_value`) Returns the compact JSON text of the subtree at a name or pointer path as a `RawValue`,
+	/// to be forwarded as is (e.g., in a `#[derive(Serialize)]` request body) without going through a `Value` clone.
+	/// - The subtree is serialized directly from the borrowed `Value`, once.
+	/// - A `&RawValue` borrowing the original text cannot be returned, as a parsed `Value` does not keep it
+	///   (parse the source with `serde_json::from_str::<&RawValue>` for that).
+	#[cfg(feature = "raw_value")]
+	fn x_get_raw(&self, name_or_pointer: &str) -> Result<Box<RawValue>>;
+
+	/// Same as `x_get`, but with a pre-parsed `JsonPointer`, to not re-split the path on every access (e.g., in loops).
+	fn x_get_pointer<T: DeserializeOwned>(&self, pointer: &JsonPointer) -> Result<T>;
+
 	/// Returns a reference of type `T` (or value for copy type) for a given name or pointer path.
 	/// Use this one over `x_get` to avoid string allocation and get only the &str
 	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
 	fn x_get_as<'a, T: AsType<'a>>(&'a self, name_or_pointer: &str) -> Result<T>;
 
+	/// Returns a mutable reference of type `T` for a given name or pointer path, to mutate it in place
+	/// (e.g., `&mut String`, `&mut Vec<Value>`, `&mut Map<String, Value>`, `&mut Value`).
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get_as_mut<'a, T: AsTypeMut<'a>>(&'a mut self, name_or_pointer: &str) -> Result<T>;
+
 	/// Returns a &str if present (shortcut for `x_get_as::<&str>(...)`)
 	fn x_get_str(&self, name_or_pointer: &str) -> Result<&str> {
 		self.x_get_as(name_or_pointer)
@@ -97,26 +305,598 @@ pub trait JsonValueExt {
 
 	/// Takes the value at the specified name or pointer path and replaces it with `Null`.
 	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	/// - The value is deserialized before being taken, so a type error leaves it in place.
 	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
 
+	/// Takes the value at the specified name or pointer path (replacing it with `Null`), or returns `None` if the property does not exist.
+	/// Type and deserialization errors are still returned as errors.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_take_opt<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<Option<T>>;
+
+	/// Same as `x_take`, but with a pre-parsed `JsonPointer`.
+	fn x_take_pointer<T: DeserializeOwned>(&mut self, pointer: &JsonPointer) -> Result<T>;
+
+	/// Removes the value at the specified name or pointer path from its parent object (or array),
+	/// and returns it as type `T`.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	/// - The value is deserialized before being removed, so a type error leaves it in place.
+	fn x_remove<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Removes the value at the specified name or pointer path (like `x_remove`), and then removes
+	/// any ancestor objects or arrays that became empty (the root value is never removed).
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_take_prune<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Renames the key of the property at the specified name or pointer path to `new_key`, keeping its value
+	/// (and its position with the serde_json `preserve_order` feature), e.g., `x_rename("/user/adress", "address")`.
+	/// - Returns `PropertyAlreadyExists` if the parent object already has a `new_key` property.
+	fn x_rename(&mut self, name_or_pointer: &str, new_key: &str) -> Result<()>;
+
+	/// Moves the value at `from` to `to` (names or pointer paths), like the JSON Patch `move` operation:
+	/// the value is removed from `from`, and added at `to` (creating the missing parents).
+	/// - In an array, the value is inserted at the `to` index (shifting the next elements), or appended for `-`.
+	/// - `to` cannot be a child of `from`.
+	/// - On any error, this value is left unchanged.
+	fn x_move(&mut self, from: &str, to: &str) -> Result<()>;
+
+	/// Copies (deep clone) the value at `from` to `to` (names or pointer paths), like the JSON Patch `copy` operation:
+	/// the value is added at `to` (creating the missing parents, and replacing an existing property).
+	/// - In an array, the value is inserted at the `to` index (shifting the next elements), or appended for `-`.
+	/// - On any error, this value is left unchanged.
+	fn x_copy(&mut self, from: &str, to: &str) -> Result<()> {
+		self.x_copy_with(from, to, CopyMode::Overwrite)
+	}
+
+	/// Same as `x_copy`, but returns `PropertyAlreadyExists` when `to` exists and `mode` is `CopyMode::ErrorIfExists`.
+	fn x_copy_with(&mut self, from: &str, to: &str, mode: CopyMode) -> Result<()>;
+
+	/// (feature `preserve_order`) Inserts a value of type `T` at the specified name or pointer path (like `x_insert`),
+	/// as the `index`th property of its parent object, e.g., `x_insert_at_position("/server/host", 0, "localhost")`.
+	/// - An existing property is replaced, and moved to `index`.
+	/// - Returns `IndexOutOfBounds` if `index` is past the other properties, and `NotAnObject` for an array parent.
+	#[cfg(feature = "preserve_order")]
+	fn x_insert_at_position<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()>;
+
+	/// (feature `preserve_order`) Moves the property at the specified name or pointer path just before its sibling
+	/// `before` (a name or pointer path of the same parent object), e.g., `x_move_key_before("/server/port", "/server/host")`.
+	/// - Returns `InvalidPath` if `before` is not a sibling, and `PropertyNotFound` if either property does not exist.
+	#[cfg(feature = "preserve_order")]
+	fn x_move_key_before(&mut self, name_or_pointer: &str, before: &str) -> Result<()>;
+
 	/// Inserts a new value of type `T` at the specified name or pointer path.
 	/// This method creates missing `Value::Object` entries as needed.
 	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	///
+	/// Pointer paths follow RFC 6901 for arrays:
+	/// - A numeric part indexes into an array (the index can be equal to the array length to append).
+	/// - A `-` part appends to an array.
+	/// - Missing parents are created as `Value::Array` when the next part is numeric or `-`.
 	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
 
+	/// Appends the `value` to the array at the name or pointer path, creating the array if missing.
+	/// Returns `NotAnArray` if the existing value is not an array.
+	fn x_push<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Returns the elements of the array at `array_path` grouped by their value at `key_path` (a name or pointer path
+	/// relative to each element), as a map of the group keys to the arrays of their elements (cloned, in order).
+	/// - A string is its own group key, and the other values are keyed by their JSON text (e.g., `"3"`, `"true"`).
+	/// - The elements without a value at `key_path` are skipped.
+	///
+	/// e.g., `value.x_group_by("/items", "/category")?`
+	fn x_group_by(&self, array_path: &str, key_path: &str) -> Result<Map<String, Value>>;
+
+	/// Removes the elements of the array at `array_path` having the same value at `key_path` (a name or pointer path
+	/// relative to each element) as another one, keeping the first (or last) occurrence of each distinct value
+	/// at its position, e.g., `x_dedup_by("/items", "/id", DedupKeep::First)`.
+	/// - The elements without a value at `key_path` are all kept.
+	///
+	/// Returns the number of removed elements.
+	fn x_dedup_by(&mut self, array_path: &str, key_path: &str, keep: DedupKeep) -> Result<usize>;
+
+	/// Appends the serialized `items` to the array at the name or pointer path, creating the array if missing.
+	/// - Returns `NotAnArray` if the existing value is not an array.
+	/// - Nothing is appended if an item fails to serialize.
+	fn x_extend<T: Serialize>(&mut self, name_or_pointer: &str, items: impl IntoIterator<Item = T>) -> Result<()>;
+
+	/// Inserts the `value` at the `index` of the array at the name or pointer path, shifting the elements after it
+	/// (where `x_insert` to `/items/2` replaces the element).
+	/// - The `index` can be equal to the array length to append.
+	fn x_insert_at<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()>;
+
+	/// Removes the element at the `index` of the array at the name or pointer path, and returns it as type `T`.
+	/// - The element is deserialized before being removed, so a type error leaves the array unchanged.
+	fn x_remove_at<T: DeserializeOwned>(&mut self, name_or_pointer: &str, index: usize) -> Result<T>;
+
+	/// Returns the entry at the name or pointer path, to modify the existing value or insert a missing one
+	/// (e.g., `value.x_entry("/counters/hits").or_insert(0)?`).
+	fn x_entry(&mut self, name_or_pointer: &str) -> JsonEntry<'_>;
+
+	/// Returns the mutable value at the name or pointer path, first inserting the one returned by `f` if missing
+	/// (creating the missing parents like `x_insert`).
+	/// - `f` is only called when the property does not exist.
+	fn x_get_or_insert_with<T: Serialize>(
+		&mut self,
+		name_or_pointer: &str,
+		f: impl FnOnce() -> T,
+	) -> Result<&mut Value> {
+		self.x_entry(name_or_pointer).or_insert_with(f)
+	}
+
+	/// Same as `x_insert`, but with a pre-parsed `JsonPointer` (the root pointer returns an `InvalidPath` error).
+	fn x_insert_pointer<T: Serialize>(&mut self, pointer: &JsonPointer, value: T) -> Result<()>;
+
+	/// Inserts all the `(name_or_pointer, value)` pairs in order (like `x_insert`), even after a failing one.
+	/// Returns `InsertManyFailed` with every failure (its path and error) if any, the other inserts being still applied.
+	fn x_insert_many<K, T>(&mut self, pairs: impl IntoIterator<Item = (K, T)>) -> Result<()>
+	where
+		K: AsRef<str>,
+		T: Serialize,
+	{
+		let failures: Vec<(String, JsonValueExtError)> = pairs
+			.into_iter()
+			.filter_map(|(name_or_pointer, value)| {
+				let name_or_pointer = name_or_pointer.as_ref();
+				self.x_insert(name_or_pointer, value)
+					.err()
+					.map(|err| (name_or_pointer.to_string(), err))
+			})
+			.collect();
+		if failures.is_empty() {
+			Ok(())
+		} else {
+			Err(JsonValueExtError::InsertManyFailed { failures })
+		}
+	}
+
+	/// Deep merges `other` into this value.
+	/// - Objects are merged recursively, property by property.
+	/// - Arrays are replaced, concatenated, or unioned (by value) per `options.arrays`.
+	/// - `null` values from `other` overwrite or delete the existing properties per `options.nulls`.
+	/// - Any other value from `other` replaces the existing one.
+	fn x_merge(&mut self, other: Value, options: MergeOptions);
+
+	/// Returns the RFC 6902 JSON Patch transforming this value into `other` (with the default `DiffOptions`).
+	fn x_diff(&self, other: &Value) -> Patch {
+		self.x_diff_with(other, DiffOptions::default())
+	}
+
+	/// Returns the RFC 6902 JSON Patch transforming this value into `other`.
+	/// - `options.prefer_replace`: a changed value is a `replace` rather than a `remove` + `add`.
+	/// - `options.detect_moves`: array elements that moved are reported as `move` operations.
+	fn x_diff_with(&self, other: &Value, options: DiffOptions) -> Patch;
+
+	/// Applies the RFC 6902 JSON Patch (e.g., from `x_diff`) to this value, atomically: if an operation fails
+	/// (e.g., a missing path, or a failed `test`), the error is returned and the value is left unchanged.
+	fn x_apply_patch(&mut self, patch: &Patch) -> Result<()>;
+
+	/// Returns a structural report of the added, removed, and changed paths (with their old and new values)
+	/// from this value to `other`.
+	fn x_diff_report(&self, other: &Value) -> DiffReport;
+
 	/// Walks through all properties in the JSON value tree and calls the callback function on each.
-	/// - The callback signature is `(parent_map, property_name) -> bool`.
-	///   - Returns `false` to stop the traversal; returns `true` to continue.
+	/// - The callback signature is `(parent_map, property_name) -> WalkControl` (or `bool`).
+	///   - Returns `WalkControl::Stop` (or `false`) to stop the traversal.
+	///   - Returns `WalkControl::SkipChildren` to not walk into the property value.
+	///   - Returns `WalkControl::Continue` (or `true`) to continue.
 	///
 	/// Returns:
 	/// - `true` if the traversal completes without stopping early.
-	/// - `false` if the traversal is stopped early because the callback returned `false`.
-	fn x_walk<F>(&mut self, callback: F) -> bool
+	/// - `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>;
+
+	/// Same as `x_walk`, but the callback also receives the JSON pointer path of the property.
+	/// - The callback signature is `(property_path, parent_map, property_name) -> WalkControl` (or `bool`).
+	///   - e.g., `property_path` is `/schema/properties/maker` for the `maker` property.
+	fn x_walk_with_path<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>;
+
+	/// Walks depth-first (pre-order) through all the values of the JSON value tree (including the root value, with an empty path)
+	/// and calls the callback function with a mutable reference to each value.
+	/// - The callback signature is `(path, value) -> WalkControl` (or `bool`).
+	///   - The value can be replaced wholesale (the children of the new value are then walked).
+	///   - Returns `WalkControl::Stop` (or `false`) to stop the traversal.
+	///   - Returns `WalkControl::SkipChildren` to not walk into the value children.
+	///   - Returns `WalkControl::Continue` (or `true`) to continue.
+	///
+	/// Returns:
+	/// - `true` if the traversal completes without stopping early.
+	/// - `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk_mut<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Value) -> R,
+		R: Into<WalkControl>,
+	{
+		self.x_walk_mut_with(WalkOrder::PreOrder, callback)
+	}
+
+	/// Same as `x_walk_mut`, with a given depth-first traversal order.
+	/// - `WalkOrder::PostOrder` visits the children before their parent value, which allows to safely
+	///   collapse or remove subtrees from the bottom up.
+	fn x_walk_mut_with<F, R>(&mut self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Value) -> R,
+		R: Into<WalkControl>;
+
+	/// Same as `x_walk_mut`, but read-only (on `&self`), for pure inspection.
+	/// - The callback signature is `(path, value) -> WalkControl` (or `bool`).
+	fn x_walk_ref<F, R>(&self, callback: F) -> bool
+	where
+		F: FnMut(&str, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		self.x_walk_ref_with(WalkOrder::PreOrder, callback)
+	}
+
+	/// Same as `x_walk_ref`, with a given depth-first traversal order.
+	fn x_walk_ref_with<F, R>(&self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &Value) -> R,
+		R: Into<WalkControl>;
+
+	/// Returns references to all the values matching a JSONPath expression (e.g., `$.store.book[?(@.price < 10)].title`).
+	/// Use `JsonQuery::compile(..)` to reuse a compiled query across documents.
+	fn x_query(&self, query: &str) -> Result<Vec<&Value>>;
+
+	/// Calls `callback` with a mutable reference to each value matching a JSONPath expression.
+	///
+	/// Returns the number of values visited.
+	fn x_query_mut<F>(&mut self, query: &str, callback: F) -> Result<usize>
+	where
+		F: FnMut(&mut Value);
+
+	/// (feature `rayon`) Same as `x_walk_ref`, but the independent subtrees are walked in parallel.
+	/// - A value is always visited before its children, but sibling subtrees are visited in no particular order.
+	/// - The callback signature is `(path, value) -> WalkControl` (or `bool`), and must be `Sync`.
+	#[cfg(feature = "rayon")]
+	fn x_par_walk_ref<F, R>(&self, callback: F) -> bool
+	where
+		F: Fn(&str, &Value) -> R + Sync,
+		R: Into<WalkControl>;
+
+	/// (feature `rayon`) Returns all the scalar leaves with their pointer paths (like `x_leaves`),
+	/// collected in parallel, in document order.
+	#[cfg(feature = "rayon")]
+	fn x_par_leaves(&self) -> Vec<(String, &Value)>;
+
+	/// Drives a `JsonVisitor` over the JSON value tree (depth-first, in document order).
+	///
+	/// Returns:
+	/// - `true` if the traversal completes without stopping early.
+	/// - `false` if the traversal is stopped early because a visitor hook returned `WalkControl::Stop`.
+	fn x_accept<V: JsonVisitor + ?Sized>(&self, visitor: &mut V) -> bool;
+
+	/// Returns a lazy iterator over every scalar leaf (`Null`, `Bool`, `Number`, `String`) of the JSON value tree,
+	/// yielding `(pointer_path, &Value)` in document order.
+	fn x_leaves(&self) -> Leaves<'_>;
+
+	/// Same as `x_leaves`, but yielding mutable references to the leaves.
+	fn x_leaves_mut(&mut self) -> LeavesMut<'_>;
+
+	/// Replaces every string leaf with the value returned by `f` (e.g., trimmed, lowercased, or parsed to a number).
+	/// - The `f` signature is `(path, string) -> Value`, with the owned string (e.g., `|_, s| s.trim().into()`).
+	fn x_map_strings<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&str, String) -> Value,
+	{
+		for (path, leaf) in self.x_leaves_mut() {
+			if let Value::String(text) = leaf {
+				let text = std::mem::take(text);
+				*leaf = f(&path, text);
+			}
+		}
+	}
+
+	/// Replaces every number leaf with the value returned by `f` (e.g., scaled, rounded, or clamped).
+	/// - The `f` signature is `(path, number) -> Value` (e.g., `|_, n| json!(n.as_f64().unwrap_or_default() / 100.0)`).
+	fn x_map_numbers<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&str, Number) -> Value,
+	{
+		for (path, leaf) in self.x_leaves_mut() {
+			if let Value::Number(num) = leaf {
+				let num = num.clone();
+				*leaf = f(&path, num);
+			}
+		}
+	}
+
+	/// Replaces every value (depth-first, in document order, root included) matching the `predicate` with the value
+	/// returned by `replacement`, and returns the pointer paths of the replaced values.
+	/// - The `predicate` and `replacement` signatures are `(path, value) -> bool` and `(path, value) -> Value`.
+	/// - A replaced value is not walked into (so its replacement is not matched).
+	fn x_replace_where<P, R>(&mut self, predicate: P, replacement: R) -> Vec<String>
+	where
+		P: FnMut(&str, &Value) -> bool,
+		R: FnMut(&str, &Value) -> Value;
+
+	/// Returns a lazy iterator over the pointer paths of all the values of the JSON value tree
+	/// (the root value excluded), in document order.
+	///
+	/// Note: There is no `x_paths_mut`, as mutable references to both a value and its children cannot be
+	///       handed out at the same time. Use `x_leaves_mut` or `x_walk_mut` instead.
+	fn x_paths(&self) -> Paths<'_>;
+
+	/// Returns the first value (depth-first, in document order, root included) matching the predicate,
+	/// along with its pointer path.
+	/// - The predicate signature is `(path, value) -> bool`.
+	fn x_find_first<F>(&self, predicate: F) -> Option<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool;
+
+	/// Returns all the values (depth-first, in document order, root included) matching the predicate,
+	/// along with their pointer paths.
+	/// - The predicate signature is `(path, value) -> bool`.
+	fn x_find_all<F>(&self, predicate: F) -> Vec<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool;
+
+	/// Returns an iterator over the `(property_name, &Value)` of this JSON object (non-recursive).
+	/// The iterator is empty if the value is not an object.
+	fn x_entries(&self) -> Entries<'_>;
+
+	/// Same as `x_entries`, but yielding mutable references to the property values.
+	fn x_entries_mut(&mut self) -> EntriesMut<'_>;
+
+	/// Returns a JSON Schema (draft 2020-12 keywords, without `$schema`) inferred from this sample value.
+	/// - Objects get their `properties`, and the keys present in all the sampled objects are `required`.
+	/// - Array `items` are the merged shape of all the elements (e.g., the union of the object properties).
+	/// - Mixed types are reported as a `type` array (e.g., `["string", "null"]`), and integers merged with floats are `number`.
+	fn x_infer_schema(&self) -> Value;
+
+	/// Coerces this value in place (best effort) to conform to a JSON Schema, typically before deserializing it into structs.
+	/// - Scalars are converted per the schema `type` (e.g., `"42"` -> `42`, `"true"` -> `true`, `3` -> `"3"`).
+	/// - A single value becomes a one element array, and a string holding JSON is parsed, when an `array` or `object` is expected.
+	/// - Follows `properties`, `additionalProperties`, `items`, `prefixItems`, `allOf`, `anyOf`/`oneOf`, and local `$ref`s.
+	/// - Values that cannot be coerced are left as is.
+	///
+	/// Returns the number of coerced values.
+	fn x_coerce_to_schema(&mut self, schema: &Value) -> usize;
+
+	/// Returns `true` if this value equals `other` per the `options` (see `EqOptions`), e.g., to compare API responses
+	/// in tests while ignoring the array order, the volatile paths, or the float rounding differences.
+	/// - With `EqOptions::default()`, this is the same as `==`.
+	fn x_equals(&self, other: &Value, options: EqOptions) -> bool;
+
+	/// Returns `true` if this value contains (recursively) every property and value of the `pattern`, e.g., to assert
+	/// that a response has at least some fields.
+	/// - Objects must have all the `pattern` properties (and can have more).
+	/// - Arrays must have, for each `pattern` element, at least one element containing it (in any order).
+	/// - Other values must be equal.
+	fn x_contains_subset(&self, pattern: &Value) -> bool;
+
+	/// Returns the single level map of all the leaves of this value, keyed by their dot paths (with the default
+	/// `FlattenOptions`), e.g., to export a document to a flat key/value store, env vars, or a spreadsheet.
+	/// - The empty objects and arrays are kept as values, so no path is lost.
+	/// - A scalar root value is returned with the `""` key.
+	fn x_flatten(&self) -> Map<String, Value> {
+		self.x_flatten_with(FlattenOptions::default())
+	}
+
+	/// Same as `x_flatten`, with the `options` (e.g., `FlattenKeys::Pointer` for the pointer paths).
+	fn x_flatten_with(&self, options: FlattenOptions) -> Map<String, Value>;
+
+	/// Returns the nested value rebuilt from a flat map (the reverse of `x_flatten`).
+	/// - The keys are pointer paths when they start with `/`, and dot paths otherwise (with the `items.0` or `items[0]`
+	///   indices, and the `\` escapes of `x_flatten`: `\.`, `\\`, `\[` for a key `[`, and a leading `\/` for a key `/`),
+	///   so the keys with these characters round-trip.
+	/// - The objects with all the `0..len` keys become arrays.
+	/// - Returns an `UnflattenConflict` error when a key sets a value already set by another key, as a scalar or
+	///   as a parent (e.g., `a` and `a.b`).
+	fn x_unflatten(flat: Map<String, Value>) -> Result<Value>;
+
+	/// Renames every object key of the value tree to the `case` (e.g., `Case::Camel` for `user_id` -> `userId`).
+	/// - When two keys of an object get the same name, the last one (in the object order) wins.
+	fn x_rename_keys(&mut self, case: Case) {
+		self.x_rename_keys_with(case, &[])
+	}
+
+	/// Same as `x_rename_keys`, but leaves the keys at the `exclude` pointer paths (with their original names, and where
+	/// a `*` part matches any key or index) and all their descendants untouched (e.g., `&["/metadata", "/items/*/raw"]`).
+	fn x_rename_keys_with(&mut self, case: Case, exclude: &[&str]);
+
+	/// Removes recursively the object properties and array elements matching the `options` (by default, the `null`
+	/// values and the empty objects and arrays), e.g., to clean up the `Null`s left behind by `x_take`.
+	/// - The pass is bottom-up, so the containers emptied by the pruning are also removed.
+	/// - The root value itself is never removed.
+	///
+	/// Returns the number of removed values.
+	fn x_prune(&mut self, options: PruneOptions) -> usize;
+
+	/// Replaces the values matching the `rules` (by key name, key glob, or pointer path) with a placeholder (`"***"`
+	/// by default) or a hash, e.g., before logging request or response bodies. See `RedactRules`.
+	/// - A matched object or array is replaced as a whole.
+	///
+	/// Returns the number of redacted values.
+	fn x_redact(&mut self, rules: &RedactRules) -> usize;
+
+	/// Sorts (recursively) the keys of all the objects lexicographically, for stable diffs and snapshots.
+	/// - Only needed with the serde_json `preserve_order` feature, as the objects are otherwise always in key order.
+	fn x_sort_keys(&mut self) {
+		self.x_sort_keys_by(|a, b| a.cmp(b))
+	}
+
+	/// Same as `x_sort_keys`, but with the `compare` function on the keys.
+	/// - Only has an effect with the serde_json `preserve_order` feature (see `x_sort_keys`).
+	fn x_sort_keys_by<F>(&mut self, compare: F)
 	where
-		F: FnMut(&mut Map<String, Value>, &str) -> bool;
+		F: FnMut(&str, &str) -> Ordering;
+
+	/// Sorts (stable, in place) the elements of the array at `array_path` by their value at `key_path`
+	/// (a name or pointer path relative to each element), e.g., `x_sort_array_by("/models", "/maker", Order::Asc)`.
+	/// - The missing values come first, and then the values by kind (`null`, bool, number, string, array, object).
+	/// - The numbers are compared by value, the strings lexicographically, the arrays element by element,
+	///   and the objects are all equal.
+	fn x_sort_array_by(&mut self, array_path: &str, key_path: &str, order: Order) -> Result<()> {
+		self.x_sort_array_by_keys(array_path, &[(key_path, order)])
+	}
+
+	/// Same as `x_sort_array_by`, but with several sort keys, where the next key orders the elements equal
+	/// on the previous ones (e.g., `&[("/maker", Order::Asc), ("/year", Order::Desc)]`).
+	fn x_sort_array_by_keys(&mut self, array_path: &str, keys: &[(&str, Order)]) -> Result<()>;
+
+	/// Returns a new value with only the values at the `paths` (names or pointer paths), keeping their nesting,
+	/// e.g., `x_pick(&["/id", "/user/name"])` -> `{"id": .., "user": {"name": ..}}`.
+	/// - A `*` part matches any property or index (e.g., `/items/*/id`).
+	/// - The picked array elements keep their order, without the not picked ones in between.
+	/// - The paths that do not resolve are skipped.
+	fn x_pick(&self, paths: &[&str]) -> Value;
+
+	/// Returns a clone of this value without the values at the `paths` (names or pointer paths, with `*` parts
+	/// like `x_pick`), e.g., `x_omit(&["/password", "/items/*/internal"])`.
+	fn x_omit(&self, paths: &[&str]) -> Value;
+
+	/// Returns a new object with the value at each `from` path (name or pointer path) set at its `to` path
+	/// (like `x_insert`), e.g., `x_project(&[("/user/name", "/name"), ("/meta/id", "/id")])`.
+	/// - The `from` paths that do not resolve are skipped.
+	fn x_project(&self, mapping: &[(&str, &str)]) -> Result<Value>;
+
+	/// Substitutes the `${/some/pointer}` or `${name}` references of all the string leaves with their values in `ctx`
+	/// (with the default `TemplateOptions`, so an error for a reference not found).
+	/// - A string with only a reference gets the referenced value as is (e.g., `"${/max}"` -> `10`).
+	/// - Otherwise, the strings are inserted as is, and the other values as their JSON text.
+	/// - `$${` is a literal `${`.
+	///
+	/// Returns the number of substituted references (on error, the value is left unchanged).
+	fn x_render_template(&mut self, ctx: &Value) -> Result<usize> {
+		self.x_render_template_with(ctx, TemplateOptions::default())
+	}
+
+	/// Same as `x_render_template`, with the `options` (e.g., `MissingVar::Keep` to keep the references not found).
+	fn x_render_template_with(&mut self, ctx: &Value, options: TemplateOptions) -> Result<usize>;
+
+	/// Replaces the `${NAME}` and `${NAME:-default}` env var references of all the string leaves with the env var values
+	/// (e.g., for JSON configuration files), and returns the `EnvReport` of the used and missing env vars.
+	/// - A missing env var is replaced with its default, or kept as is if none.
+	/// - `$${` is a literal `${`, and the references which are not env var names (e.g., `${/pointer}`) are kept as is.
+	fn x_expand_env(&mut self) -> EnvReport {
+		self.x_expand_env_with(EnvOptions::default())
+	}
+
+	/// Same as `x_expand_env`, with the `options` (e.g., an allowlist of the env vars to expand).
+	fn x_expand_env_with(&mut self, options: EnvOptions) -> EnvReport;
+
+	/// Returns the structural statistics of the value tree (node counts by kind, max depth, string bytes, largest array,
+	/// and estimated heap size), e.g., to guard against pathological payloads or to size caches.
+	fn x_stats(&self) -> JsonStats;
+
+	/// Returns the RFC 8785 JSON Canonicalization Scheme (JCS) serialization of this value, to sign or hash it interoperably.
+	/// - Object keys are sorted by their UTF-16 code units, with no whitespace.
+	/// - Numbers are formatted as ECMAScript doubles (e.g., `1e+30`, `4.5`, `0.002`). Note that the serde_json
+	///   parsing is only correctly rounded with its `float_roundtrip` feature.
+	/// - Strings only escape `"`, `\`, and the control characters.
+	fn x_canonical(&self) -> String;
+
+	/// Returns the structural hash of this value, independent of the object key insertion order
+	/// (e.g., to deduplicate values or to use them as cache keys without serializing them first).
+	/// - Equal values (per `==`) have equal hashes.
+	/// - The hash is stable across processes, platforms, and Rust versions (64 bit FNV-1a).
+	fn x_hash(&self) -> u64;
+
+	/// Same as `x_hash`, but feeds the value structure into the given `hasher` (e.g., a `DefaultHasher`).
+	fn x_hash_with<H: Hasher>(&self, hasher: &mut H);
+
+	/// (feature `toml`) Converts this value to a `toml::Value`, without the silent losses of a serde round trip.
+	/// - Integers stay integers, and floats stay floats.
+	/// - Strings holding a TOML datetime (e.g., `"1979-05-27T07:32:00Z"`) become `toml::Value::Datetime`.
+	/// - `null` values and integers above `i64::MAX` cannot be represented, and are all reported (with their paths)
+	///   in the `TomlValueExtError::NotRepresentable` error.
+	#[cfg(feature = "toml")]
+	fn x_to_toml(&self) -> core::result::Result<toml::Value, crate::TomlValueExtError>;
+
+	/// (feature `toml`) Converts a `toml::Value` to a JSON value (the reverse of `x_to_toml`).
+	/// - Datetimes become their RFC 3339 strings.
+	/// - `nan` and `inf` floats cannot be represented, and are all reported (with their paths)
+	///   in the `TomlValueExtError::NotRepresentable` error.
+	#[cfg(feature = "toml")]
+	fn x_from_toml(value: &toml::Value) -> core::result::Result<Value, crate::TomlValueExtError>;
+
+	/// (feature `yaml`) Converts this value to a `serde_yaml::Value` (every JSON value has a YAML representation).
+	#[cfg(feature = "yaml")]
+	fn x_to_yaml(&self) -> serde_yaml::Value;
+
+	/// (feature `yaml`) Converts a `serde_yaml::Value` to a JSON value (the reverse of `x_to_yaml`).
+	/// - Non-string keys, tagged values, merge keys (`<<`), and `.nan`/`.inf` floats cannot be represented, and are
+	///   all reported (with their paths) in the `YamlValueExtError::NotRepresentable` error.
+	#[cfg(feature = "yaml")]
+	fn x_from_yaml(value: &serde_yaml::Value) -> core::result::Result<Value, crate::YamlValueExtError>;
 
 	/// Returns a pretty-printed string representation of the JSON value.
 	fn x_pretty(&self) -> Result<String>;
+
+	/// Writes the compact JSON of the value to the `writer` (e.g., a file or a socket), without building a `String` first.
+	/// - The `writer` is not buffered, so wrap raw files or sockets in a `std::io::BufWriter`.
+	fn x_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()>;
+
+	/// Same as `x_to_writer`, but pretty-printed (like `x_pretty`).
+	fn x_pretty_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()>;
+
+	/// Appends the compact JSON of the value, followed by `\n`, to the JSON Lines file at `path` (created if missing).
+	/// - The line is written with a single `write_all` on a file opened in append mode, so the lines of concurrent
+	///   appenders do not get interleaved (for the usual line sizes).
+	/// - Returns a `FileIo` error (with the `path`) if the file cannot be opened or written.
+	fn x_append_jsonl(&self, path: impl AsRef<Path>) -> Result<()>;
+
+	/// Returns the JSON value of the file at `path`.
+	/// - Returns `FileIo` if the file cannot be read, and `FileNotParsable` if it is not valid JSON (both with the `path`).
+	fn x_load_file(path: impl AsRef<Path>) -> Result<Value>;
+
+	/// Saves the compact JSON of the value to the file at `path`, atomically (see `x_save_file_with`).
+	fn x_save_file(&self, path: impl AsRef<Path>) -> Result<()> {
+		self.x_save_file_with(path, SaveOptions::default())
+	}
+
+	/// Saves the JSON of the value to the file at `path`, per the `options` (see `SaveOptions`).
+	/// - With `atomic` (default), the JSON is written to a temporary file next to `path`, synced, and renamed to `path`.
+	/// - Returns `FileIo` (with the `path`) if the file cannot be written.
+	fn x_save_file_with(&self, path: impl AsRef<Path>, options: SaveOptions) -> Result<()>;
+
+	/// (feature `tokio`) Same as `x_load_file`, but reading the file with the tokio fs, so the runtime is not blocked.
+	#[cfg(feature = "tokio")]
+	fn x_load_file_async(path: impl AsRef<Path>) -> impl Future<Output = Result<Value>> + Send;
+
+	/// (feature `tokio`) Same as `x_save_file`, but writing the file with the tokio fs.
+	#[cfg(feature = "tokio")]
+	fn x_save_file_async(&self, path: impl AsRef<Path>) -> impl Future<Output = Result<()>> + Send {
+		self.x_save_file_with_async(path, SaveOptions::default())
+	}
+
+	/// (feature `tokio`) Same as `x_save_file_with`, but writing the file with the tokio fs.
+	/// - The JSON is serialized when called, so the returned future does not borrow the value.
+	#[cfg(feature = "tokio")]
+	fn x_save_file_with_async(
+		&self,
+		path: impl AsRef<Path>,
+		options: SaveOptions,
+	) -> impl Future<Output = Result<()>> + Send;
+
+	/// (feature `tokio`) Same as `x_to_writer`, but to a tokio `AsyncWrite` (e.g., a `tokio::fs::File`, or a socket).
+	/// - The JSON is written in chunks (of about 8 KB) as it is serialized, without first building the full text.
+	/// - The writer is flushed at the end.
+	#[cfg(feature = "tokio")]
+	fn x_to_async_writer<W>(&self, writer: W) -> impl Future<Output = Result<()>> + Send
+	where
+		W: tokio::io::AsyncWrite + Unpin + Send;
+
+	/// Returns a compact single line preview of the value, at most `max_bytes` long, e.g., for tracing spans and error messages.
+	/// - The long strings are truncated with a `…` (to a quarter of `max_bytes`, or at least 16 bytes).
+	/// - The arrays show at most 10 elements, and the elements (or properties) past the budget are elided as `…+N`.
+	/// - The result is cut (with a trailing `…`) when still longer than `max_bytes`, so it is not always valid JSON.
+	fn x_preview(&self, max_bytes: usize) -> String;
+
+	/// Same as `x_pretty`, but with the `options` for the indent, the key order, the one line arrays of scalars,
+	/// and the trailing newline (see `PrettyOptions`).
+	fn x_pretty_with(&self, options: PrettyOptions) -> String;
+
+	/// (feature `color`) Same as `x_pretty`, but with the ANSI colors for the keys, strings, numbers, booleans, and nulls
+	/// (e.g., for CLIs and debug logs).
+	/// - The colors are omitted when the `NO_COLOR` env var is set (and not empty).
+	#[cfg(feature = "color")]
+	fn x_pretty_colored(&self) -> String;
 }
 
 impl JsonValueExt for Value {
@@ -124,185 +904,1154 @@ impl JsonValueExt for Value {
 		Value::Object(Map::new())
 	}
 
-	fn x_contains<T: DeserializeOwned>(&self, name_or_pointer: &str) -> bool {
-		if name_or_pointer.starts_with('/') {
-			self.pointer(name_or_pointer).is_some()
-		} else {
-			self.get(name_or_pointer).is_some()
+	fn x_builder() -> JsonObjectBuilder {
+		JsonObjectBuilder::new()
+	}
+
+	fn x_contains(&self, name_or_pointer: &str) -> bool {
+		get_value(self, name_or_pointer).is_some()
+	}
+
+	fn x_is_null(&self, name_or_pointer: &str) -> bool {
+		get_value(self, name_or_pointer).is_some_and(Value::is_null)
+	}
+
+	fn x_kind(&self) -> JsonKind {
+		JsonKind::of(self)
+	}
+
+	fn x_len(&self) -> Option<usize> {
+		match self {
+			Value::String(s) => Some(s.len()),
+			Value::Array(arr) => Some(arr.len()),
+			Value::Object(map) => Some(map.len()),
+			_ => None,
 		}
 	}
 
 	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
-		let value = if name_or_pointer.starts_with('/') {
-			self.pointer(name_or_pointer)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
-		} else {
-			self.get(name_or_pointer)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	#[cfg(feature = "raw_value")]
+	fn x_get_raw(&self, name_or_pointer: &str) -> Result<Box<RawValue>> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
+
+		Ok(serde_json::value::to_raw_value(value)?)
+	}
+
+	fn x_get_pointer<T: DeserializeOwned>(&self, pointer: &JsonPointer) -> Result<T> {
+		let value = pointer
+			.resolve(self)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, pointer))?;
+
+		from_value_with_context(pointer, value)
+	}
+
+	fn x_get_lenient<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
+
+		match from_value_with_context(name_or_pointer, value) {
+			Ok(res) => Ok(res),
+			Err(err) => coerce_candidates(value)
+				.into_iter()
+				.find_map(|candidate| serde_json::from_value(candidate).ok())
+				.ok_or(err),
+		}
+	}
+
+	fn x_get_opt<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<Option<T>> {
+		let Some(value) = get_value(self, name_or_pointer) else {
+			return Ok(None);
 		};
 
-		let value: T = serde_json::from_value(value.clone())
-			// first map_err to get the JsonValueExtError
-			.map_err(JsonValueExtError::from)
-			// and then, try to add more property information if possible
-			.map_err(|err| match err {
-				JsonValueExtError::ValueNotOfType(not_of_type) => JsonValueExtError::PropertyValueNotOfType {
-					name: name_or_pointer.to_string(),
-					not_of_type,
-				},
-				other => other,
-			})?;
+		from_value_with_context(name_or_pointer, value).map(Some)
+	}
 
-		Ok(value)
+	fn x_get_all<T: DeserializeOwned>(&self, pointer: &str) -> Result<Vec<(String, T)>> {
+		let mut matches = Vec::new();
+		if pointer.starts_with('/') {
+			let parts: Vec<String> = pointer.split('/').skip(1).map(unescape_token).collect();
+			collect_wildcard_matches(self, &parts, String::new(), &mut matches);
+		} else if let Some(value) = self.get(pointer) {
+			matches.push((format!("/{}", escape_token(pointer)), value));
+		}
+
+		matches
+			.into_iter()
+			.map(|(path, value)| {
+				let value = from_value_with_context(&path, value)?;
+				Ok((path, value))
+			})
+			.collect()
 	}
 
 	fn x_get_as<'a, T: AsType<'a>>(&'a self, name_or_pointer: &str) -> Result<T> {
-		let value = if name_or_pointer.starts_with('/') {
-			self.pointer(name_or_pointer)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
-		} else {
-			self.get(name_or_pointer)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
-		};
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
 
 		// add more error context when possible
-		T::from_value(value).map_err(|err| match err {
+		T::from_value(value).map_err(|err| err.with_property_context(name_or_pointer, value))
+	}
+
+	fn x_get_as_mut<'a, T: AsTypeMut<'a>>(&'a mut self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value_mut_or_err(self, name_or_pointer)?;
+
+		// captured upfront, as the value is mutably borrowed by `T` (bounded cost)
+		let actual = json_kind_name(value);
+		let snippet = value_snippet(value, SNIPPET_MAX_LEN);
+		T::from_value_mut(value).map_err(|err| match err {
 			JsonValueExtError::ValueNotOfType(not_of_type) => JsonValueExtError::PropertyValueNotOfType {
 				name: name_or_pointer.to_string(),
 				not_of_type,
+				actual,
+				snippet,
 			},
 			other => other,
 		})
 	}
 
 	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
-		let value = if name_or_pointer.starts_with('/') {
-			self.pointer_mut(name_or_pointer)
-				.map(Value::take)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
-		} else {
-			self.get_mut(name_or_pointer)
-				.map(Value::take)
-				.ok_or_else(|| JsonValueExtError::PropertyNotFound(name_or_pointer.to_string()))?
-		};
+		// -- Deserialize before taking, so a type error leaves the value unchanged
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
+		let value: T = from_value_with_context(name_or_pointer, value)?;
 
-		let value: T = serde_json::from_value(value)?;
+		if let Some(taken) = get_value_mut(self, name_or_pointer) {
+			taken.take();
+		}
 		Ok(value)
 	}
 
-	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
-		let new_value = serde_json::to_value(value)?;
+	fn x_take_pointer<T: DeserializeOwned>(&mut self, pointer: &JsonPointer) -> Result<T> {
+		let value = pointer
+			.resolve(self)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, pointer))?;
+		let value: T = from_value_with_context(pointer, value)?;
 
-		if !name_or_pointer.starts_with('/') {
-			match self {
-				Value::Object(map) => {
-					map.insert(name_or_pointer.to_string(), new_value);
-					Ok(())
-				}
-				_ => Err(JsonValueExtError::custom("Value is not an Object; cannot x_insert")),
-			}
-		} else {
-			let parts: Vec<&str> = name_or_pointer.split('/').skip(1).collect();
-			let mut current = self;
-
-			// -- Add the eventual missing parents
-			for &part in &parts[..parts.len() - 1] {
-				match current {
-					Value::Object(map) => {
-						current = map.entry(part).or_insert_with(|| json!({}));
-					}
-					_ => return Err(JsonValueExtError::custom("Path does not point to an Object")),
-				}
-			}
+		if let Some(taken) = pointer.resolve_mut(self) {
+			taken.take();
+		}
+		Ok(value)
+	}
 
-			// -- Set the value at the last element
-			if let Some(&last_part) = parts.last() {
-				match current {
-					Value::Object(map) => {
-						map.insert(last_part.to_string(), new_value);
-						Ok(())
-					}
-					_ => Err(JsonValueExtError::custom("Path does not point to an Object")),
-				}
-			} else {
-				Err(JsonValueExtError::custom("Invalid path"))
-			}
+	fn x_take_opt<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<Option<T>> {
+		let Some(value) = get_value(self, name_or_pointer) else {
+			return Ok(None);
+		};
+		let value: T = from_value_with_context(name_or_pointer, value)?;
+
+		if let Some(taken) = get_value_mut(self, name_or_pointer) {
+			taken.take();
 		}
+		Ok(Some(value))
 	}
 
-	fn x_pretty(&self) -> Result<String> {
-		let content = serde_json::to_string_pretty(self)?;
-		Ok(content)
+	fn x_remove<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		// -- Deserialize before removing, so a type error leaves the value unchanged
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(self, name_or_pointer))?;
+		let value: T = from_value_with_context(name_or_pointer, value)?;
+
+		remove_value(self, name_or_pointer);
+		Ok(value)
 	}
 
-	/// Walks through all properties of a JSON value tree and calls the callback function on each property.
-	///
-	/// - The callback signature is `(parent_map, property_name) -> bool`.
-	///   - Return `false` from the callback to stop the traversal; return `true` to continue.
-	///
-	/// Returns:
-	/// - `true` if the traversal completed to the end without being stopped early.
-	/// - `false` if the traversal was stopped early because the callback returned `false`.
-	fn x_walk<F>(&mut self, mut callback: F) -> bool
-	where
-		F: FnMut(&mut Map<String, Value>, &str) -> bool,
-	{
-		let mut queue = VecDeque::new();
-		queue.push_back(self);
-
-		while let Some(current) = queue.pop_front() {
-			if let Value::Object(map) = current {
-				// Call the callback for each property name in the current map
-				for key in map.keys().cloned().collect::<Vec<_>>() {
-					let res = callback(map, &key);
-					if !res {
-						return false;
-					}
-				}
+	fn x_take_prune<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value: T = self.x_remove(name_or_pointer)?;
 
-				// Add all nested objects and arrays to the queue for further processing
-				for value in map.values_mut() {
-					if value.is_object() || value.is_array() {
-						queue.push_back(value);
-					}
+		// -- Remove the ancestors that became empty, bottom up
+		if name_or_pointer.starts_with('/') {
+			let mut pointer = name_or_pointer;
+			while let Some((parent_pointer, _)) = pointer.rsplit_once('/') {
+				if parent_pointer.is_empty() {
+					break;
 				}
-			} else if let Value::Array(arr) = current {
-				// If the current value is an array, add its elements to the queue
-				for value in arr.iter_mut() {
-					if value.is_object() || value.is_array() {
-						queue.push_back(value);
-					}
+				let is_empty = match self.pointer(parent_pointer) {
+					Some(Value::Object(map)) => map.is_empty(),
+					Some(Value::Array(arr)) => arr.is_empty(),
+					_ => false,
+				};
+				if !is_empty {
+					break;
 				}
+				remove_value(self, parent_pointer);
+				pointer = parent_pointer;
 			}
 		}
-		true
+
+		Ok(value)
 	}
-}
 
-// region:    --- Error
-type Result<T> = core::result::Result<T, JsonValueExtError>;
+	fn x_push<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		push_value(self, name_or_pointer, serde_json::to_value(value)?)
+	}
 
-#[derive(Debug, derive_more::From)]
-pub enum JsonValueExtError {
-	Custom(String),
+	fn x_group_by(&self, array_path: &str, key_path: &str) -> Result<Map<String, Value>> {
+		group_by(self, array_path, key_path)
+	}
 
-	PropertyNotFound(String),
+	fn x_dedup_by(&mut self, array_path: &str, key_path: &str, keep: DedupKeep) -> Result<usize> {
+		dedup_by(self, array_path, key_path, keep)
+	}
 
-	PropertyValueNotOfType {
-		name: String,
-		not_of_type: &'static str,
-	},
+	fn x_extend<T: Serialize>(&mut self, name_or_pointer: &str, items: impl IntoIterator<Item = T>) -> Result<()> {
+		let values = items
+			.into_iter()
+			.map(serde_json::to_value)
+			.collect::<core::result::Result<Vec<_>, _>>()?;
+		extend_values(self, name_or_pointer, values)
+	}
 
-	// -- AsType errors
-	ValueNotOfType(&'static str),
+	fn x_insert_at<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()> {
+		insert_at(self, name_or_pointer, index, serde_json::to_value(value)?)
+	}
+
+	fn x_remove_at<T: DeserializeOwned>(&mut self, name_or_pointer: &str, index: usize) -> Result<T> {
+		remove_at(self, name_or_pointer, index)
+	}
+
+	fn x_entry(&mut self, name_or_pointer: &str) -> JsonEntry<'_> {
+		JsonEntry::new(self, name_or_pointer)
+	}
+
+	fn x_insert_pointer<T: Serialize>(&mut self, pointer: &JsonPointer, value: T) -> Result<()> {
+		pointer.insert(self, serde_json::to_value(value)?)
+	}
+
+	fn x_rename(&mut self, name_or_pointer: &str, new_key: &str) -> Result<()> {
+		rename_key(self, name_or_pointer, new_key)
+	}
+
+	fn x_move(&mut self, from: &str, to: &str) -> Result<()> {
+		move_value(self, from, to)
+	}
+
+	#[cfg(feature = "preserve_order")]
+	fn x_insert_at_position<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()> {
+		insert_at_position(self, name_or_pointer, index, serde_json::to_value(value)?)
+	}
+
+	#[cfg(feature = "preserve_order")]
+	fn x_move_key_before(&mut self, name_or_pointer: &str, before: &str) -> Result<()> {
+		move_key_before(self, name_or_pointer, before)
+	}
+
+	fn x_copy_with(&mut self, from: &str, to: &str, mode: CopyMode) -> Result<()> {
+		copy_value(self, from, to, mode)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		insert_value(self, name_or_pointer, serde_json::to_value(value)?)
+	}
+
+	fn x_merge(&mut self, other: Value, options: MergeOptions) {
+		merge_value(self, other, &options)
+	}
+
+	fn x_diff_with(&self, other: &Value, options: DiffOptions) -> Patch {
+		diff_values(self, other, &options)
+	}
+
+	fn x_apply_patch(&mut self, patch: &Patch) -> Result<()> {
+		apply_patch(self, patch)
+	}
+
+	fn x_diff_report(&self, other: &Value) -> DiffReport {
+		diff_report(self, other)
+	}
+
+	fn x_query(&self, query: &str) -> Result<Vec<&Value>> {
+		Ok(JsonQuery::compile(query)?.find(self))
+	}
+
+	fn x_query_mut<F>(&mut self, query: &str, callback: F) -> Result<usize>
+	where
+		F: FnMut(&mut Value),
+	{
+		Ok(JsonQuery::compile(query)?.for_each_mut(self, callback))
+	}
+
+	fn x_walk_with_path<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		walk_with_path(self, callback)
+	}
+
+	fn x_walk_mut_with<F, R>(&mut self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Value) -> R,
+		R: Into<WalkControl>,
+	{
+		walk_mut(self, order, callback)
+	}
+
+	fn x_walk_ref_with<F, R>(&self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		walk_ref(self, order, callback)
+	}
+
+	#[cfg(feature = "rayon")]
+	fn x_par_walk_ref<F, R>(&self, callback: F) -> bool
+	where
+		F: Fn(&str, &Value) -> R + Sync,
+		R: Into<WalkControl>,
+	{
+		par_walk_ref(self, callback)
+	}
+
+	#[cfg(feature = "rayon")]
+	fn x_par_leaves(&self) -> Vec<(String, &Value)> {
+		par_leaves(self)
+	}
+
+	fn x_accept<V: JsonVisitor + ?Sized>(&self, visitor: &mut V) -> bool {
+		accept("", self, visitor)
+	}
+
+	fn x_leaves(&self) -> Leaves<'_> {
+		Leaves::new(self)
+	}
+
+	fn x_leaves_mut(&mut self) -> LeavesMut<'_> {
+		LeavesMut::new(self)
+	}
+
+	fn x_paths(&self) -> Paths<'_> {
+		Paths::new(self)
+	}
+
+	fn x_find_first<F>(&self, mut predicate: F) -> Option<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool,
+	{
+		Nodes::new(self).find(|(path, value)| predicate(path, value))
+	}
+
+	fn x_replace_where<P, R>(&mut self, mut predicate: P, mut replacement: R) -> Vec<String>
+	where
+		P: FnMut(&str, &Value) -> bool,
+		R: FnMut(&str, &Value) -> Value,
+	{
+		replace_where(self, &mut predicate, &mut replacement)
+	}
+
+	fn x_find_all<F>(&self, mut predicate: F) -> Vec<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool,
+	{
+		Nodes::new(self)
+			.filter(|(path, value)| predicate(path, value))
+			.collect()
+	}
+
+	fn x_entries(&self) -> Entries<'_> {
+		Entries::new(self)
+	}
+
+	fn x_entries_mut(&mut self) -> EntriesMut<'_> {
+		EntriesMut::new(self)
+	}
+
+	fn x_infer_schema(&self) -> Value {
+		infer_schema(self)
+	}
+
+	fn x_coerce_to_schema(&mut self, schema: &Value) -> usize {
+		coerce_to_schema(self, schema)
+	}
+
+	fn x_equals(&self, other: &Value, options: EqOptions) -> bool {
+		values_equal(self, other, &options)
+	}
+
+	fn x_contains_subset(&self, pattern: &Value) -> bool {
+		contains_subset(self, pattern)
+	}
+
+	fn x_flatten_with(&self, options: FlattenOptions) -> Map<String, Value> {
+		flatten(self, &options)
+	}
+
+	fn x_unflatten(flat: Map<String, Value>) -> Result<Value> {
+		unflatten(flat)
+	}
+
+	fn x_rename_keys_with(&mut self, case: Case, exclude: &[&str]) {
+		rename_keys(self, case, exclude)
+	}
+
+	fn x_prune(&mut self, options: PruneOptions) -> usize {
+		prune(self, &options)
+	}
+
+	fn x_redact(&mut self, rules: &RedactRules) -> usize {
+		redact(self, rules)
+	}
+
+	fn x_sort_keys_by<F>(&mut self, mut compare: F)
+	where
+		F: FnMut(&str, &str) -> Ordering,
+	{
+		sort_keys_by(self, &mut compare)
+	}
+
+	fn x_sort_array_by_keys(&mut self, array_path: &str, keys: &[(&str, Order)]) -> Result<()> {
+		sort_array_by(self, array_path, keys)
+	}
+
+	fn x_pick(&self, paths: &[&str]) -> Value {
+		pick(self, paths)
+	}
+
+	fn x_omit(&self, paths: &[&str]) -> Value {
+		omit(self, paths)
+	}
+
+	fn x_project(&self, mapping: &[(&str, &str)]) -> Result<Value> {
+		project(self, mapping)
+	}
+
+	fn x_render_template_with(&mut self, ctx: &Value, options: TemplateOptions) -> Result<usize> {
+		render_template(self, ctx, &options)
+	}
+
+	fn x_expand_env_with(&mut self, options: EnvOptions) -> EnvReport {
+		expand_env(self, &options)
+	}
+
+	fn x_stats(&self) -> JsonStats {
+		stats(self)
+	}
+
+	fn x_canonical(&self) -> String {
+		canonical(self)
+	}
+
+	fn x_hash(&self) -> u64 {
+		stable_hash(self)
+	}
+
+	fn x_hash_with<H: Hasher>(&self, hasher: &mut H) {
+		hash_value(self, hasher)
+	}
+
+	#[cfg(feature = "toml")]
+	fn x_to_toml(&self) -> core::result::Result<toml::Value, crate::TomlValueExtError> {
+		json_to_toml(self)
+	}
+
+	#[cfg(feature = "toml")]
+	fn x_from_toml(value: &toml::Value) -> core::result::Result<Value, crate::TomlValueExtError> {
+		toml_to_json(value)
+	}
+
+	#[cfg(feature = "yaml")]
+	fn x_to_yaml(&self) -> serde_yaml::Value {
+		json_to_yaml(self)
+	}
+
+	#[cfg(feature = "yaml")]
+	fn x_from_yaml(value: &serde_yaml::Value) -> core::result::Result<Value, crate::YamlValueExtError> {
+		yaml_to_json(value)
+	}
+
+	fn x_pretty(&self) -> Result<String> {
+		let content = serde_json::to_string_pretty(self)?;
+		Ok(content)
+	}
+
+	fn x_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()> {
+		serde_json::to_writer(writer, self)?;
+		Ok(())
+	}
+
+	fn x_pretty_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()> {
+		serde_json::to_writer_pretty(writer, self)?;
+		Ok(())
+	}
+
+	fn x_load_file(path: impl AsRef<Path>) -> Result<Value> {
+		load_file(path.as_ref())
+	}
+
+	fn x_save_file_with(&self, path: impl AsRef<Path>, options: SaveOptions) -> Result<()> {
+		save_file(self, path.as_ref(), &options)
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_load_file_async(path: impl AsRef<Path>) -> impl Future<Output = Result<Value>> + Send {
+		let path = path.as_ref().to_path_buf();
+		async move { load_file_async(&path).await }
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_save_file_with_async(
+		&self,
+		path: impl AsRef<Path>,
+		options: SaveOptions,
+	) -> impl Future<Output = Result<()>> + Send {
+		let path = path.as_ref().to_path_buf();
+		let content = file_content(self, &options);
+		async move { save_file_async(&path, content?, options.atomic).await }
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_to_async_writer<W>(&self, writer: W) -> impl Future<Output = Result<()>> + Send
+	where
+		W: tokio::io::AsyncWrite + Unpin + Send,
+	{
+		to_async_writer(self, writer)
+	}
+
+	fn x_append_jsonl(&self, path: impl AsRef<Path>) -> Result<()> {
+		append_jsonl(self, path.as_ref())
+	}
+
+	fn x_preview(&self, max_bytes: usize) -> String {
+		preview(self, max_bytes)
+	}
+
+	fn x_pretty_with(&self, options: PrettyOptions) -> String {
+		pretty(self, &options)
+	}
+
+	#[cfg(feature = "color")]
+	fn x_pretty_colored(&self) -> String {
+		pretty_colored(self, &PrettyOptions::default())
+	}
+
+	/// Walks through all properties of a JSON value tree and calls the callback function on each property.
+	///
+	/// - The callback signature is `(parent_map, property_name) -> WalkControl` (or `bool`).
+	///   - Return `WalkControl::Stop` (or `false`) from the callback to stop the traversal.
+	///   - Return `WalkControl::SkipChildren` to not walk into the property value.
+	///   - Return `WalkControl::Continue` (or `true`) to continue.
+	///
+	/// Returns:
+	/// - `true` if the traversal completed to the end without being stopped early.
+	/// - `false` if the traversal was stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		walk_with_path(self, |_path, parent_map, property_name| {
+			callback(parent_map, property_name)
+		})
+	}
+}
+
+// region:    --- Support
+
+/// Returns the value for a direct name or a pointer path (if it starts with '/').
+pub(crate) fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	if name_or_pointer.starts_with('/') {
+		value.pointer(name_or_pointer)
+	} else {
+		value.get(name_or_pointer)
+	}
+}
+
+/// Returns the mutable value for a direct name or a pointer path (if it starts with '/').
+pub(crate) fn get_value_mut<'a>(value: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	if name_or_pointer.starts_with('/') {
+		value.pointer_mut(name_or_pointer)
+	} else {
+		value.get_mut(name_or_pointer)
+	}
+}
+
+/// Same as `get_value_mut`, but with the `PropertyNotFound` error (reported from the deepest existing ancestor).
+pub(crate) fn get_value_mut_or_err<'a>(value: &'a mut Value, name_or_pointer: &str) -> Result<&'a mut Value> {
+	let is_pointer = name_or_pointer.starts_with('/');
+	let mut current = value;
+	let mut resolved = String::new();
+	for token in path_tokens(name_or_pointer) {
+		// checked first, so the not found error can still borrow the parent
+		let has_key = matches!(&*current, Value::Object(map) if map.contains_key(&token));
+		let idx = match &*current {
+			Value::Array(arr) if is_pointer => token_index(&token).filter(|idx| *idx < arr.len()),
+			_ => None,
+		};
+		current = match (current, has_key, idx) {
+			(Value::Object(map), true, _) => &mut map[&token],
+			(Value::Array(arr), _, Some(idx)) => &mut arr[idx],
+			(parent, _, _) => {
+				return Err(JsonValueExtError::not_found_in(
+					name_or_pointer,
+					resolved,
+					parent,
+					token,
+				))
+			}
+		};
+		resolved.push('/');
+		resolved.push_str(&escape_token(&token));
+	}
+	Ok(current)
+}
+
+/// Removes the value for a direct name or a pointer path (if it starts with '/') from its parent object or array.
+pub(crate) fn remove_value(value: &mut Value, name_or_pointer: &str) -> Option<Value> {
+	let (parent, token) = if name_or_pointer.starts_with('/') {
+		let (parent_pointer, token) = name_or_pointer.rsplit_once('/')?;
+		let token = unescape_token(token);
+		(value.pointer_mut(parent_pointer)?, token)
+	} else {
+		(value, name_or_pointer.to_string())
+	};
+
+	match parent {
+		Value::Object(map) => remove_key(map, &token),
+		Value::Array(arr) => {
+			let idx: usize = token.parse().ok()?;
+			(idx < arr.len()).then(|| arr.remove(idx))
+		}
+		_ => None,
+	}
+}
+
+/// Collects the values matching the (unescaped) parts, where a `*` part matches all children.
+fn collect_wildcard_matches<'a>(
+	value: &'a Value,
+	parts: &[String],
+	path: String,
+	matches: &mut Vec<(String, &'a Value)>,
+) {
+	let Some((part, rest)) = parts.split_first() else {
+		matches.push((path, value));
+		return;
+	};
+
+	match (value, part.as_str()) {
+		(Value::Object(map), "*") => {
+			for (key, child) in map {
+				collect_wildcard_matches(child, rest, format!("{path}/{}", escape_token(key)), matches);
+			}
+		}
+		(Value::Array(arr), "*") => {
+			for (idx, child) in arr.iter().enumerate() {
+				collect_wildcard_matches(child, rest, format!("{path}/{idx}"), matches);
+			}
+		}
+		(Value::Object(map), key) => {
+			if let Some(child) = map.get(key) {
+				collect_wildcard_matches(child, rest, format!("{path}/{}", escape_token(key)), matches);
+			}
+		}
+		(Value::Array(arr), idx) => {
+			if let Some(child) = idx.parse::<usize>().ok().and_then(|idx| arr.get(idx)) {
+				collect_wildcard_matches(child, rest, format!("{path}/{idx}"), matches);
+			}
+		}
+		_ => (),
+	}
+}
+
+/// Inserts the `new_value` at the name or pointer path (see `JsonValueExt::x_insert`).
+pub(crate) fn insert_value(root: &mut Value, name_or_pointer: &str, new_value: Value) -> Result<()> {
+	if !name_or_pointer.starts_with('/') {
+		match root {
+			Value::Object(map) => {
+				map.insert(name_or_pointer.to_string(), new_value);
+				Ok(())
+			}
+			other => Err(JsonValueExtError::NotAnObject {
+				name: name_or_pointer.to_string(),
+				path: String::new(),
+				actual: json_kind_name(other),
+			}),
+		}
+	} else {
+		let parts: Vec<String> = name_or_pointer.split('/').skip(1).map(unescape_token).collect();
+		insert_tokens(root, name_or_pointer, &parts, new_value)
+	}
+}
+
+/// Inserts the `new_value` at the (unescaped) pointer `parts` of the `name_or_pointer` (see `JsonValueExt::x_insert`).
+pub(crate) fn insert_tokens(root: &mut Value, name_or_pointer: &str, parts: &[String], new_value: Value) -> Result<()> {
+	let Some((last_part, parent_parts)) = parts.split_last() else {
+		return Err(JsonValueExtError::InvalidPath {
+			name: name_or_pointer.to_string(),
+			cause: "no path segment".to_string(),
+		});
+	};
+	let mut current = root;
+	// the pointer of `current`, for the errors
+	let mut path = String::new();
+
+	// -- Add the eventual missing parents
+	for (i, part) in parent_parts.iter().enumerate() {
+		// the container to create if missing, based on the next part
+		let next_is_index = is_array_token(&parts[i + 1]);
+		let new_container = || if next_is_index { json!([]) } else { json!({}) };
+		current = match current {
+			Value::Object(map) => map.entry(part.as_str()).or_insert_with(new_container),
+			Value::Array(arr) => {
+				let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_container());
+				}
+				&mut arr[idx]
+			}
+			other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+		};
+		path.push('/');
+		path.push_str(&escape_token(part));
+	}
+
+	// -- Set the value at the last element
+	match current {
+		Value::Object(map) => {
+			map.insert(last_part.to_string(), new_value);
+			Ok(())
+		}
+		Value::Array(arr) => {
+			let idx = array_insert_index(arr, last_part, name_or_pointer, &path)?;
+			if idx == arr.len() {
+				arr.push(new_value);
+			} else {
+				arr[idx] = new_value;
+			}
+			Ok(())
+		}
+		other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+	}
+}
+
+/// Returns the error `insert_value` would return for the name or pointer path, without changing `root`.
+pub(crate) fn check_insert(root: &Value, name_or_pointer: &str) -> Result<()> {
+	if !name_or_pointer.starts_with('/') {
+		return match root {
+			Value::Object(_) => Ok(()),
+			other => Err(JsonValueExtError::NotAnObject {
+				name: name_or_pointer.to_string(),
+				path: String::new(),
+				actual: json_kind_name(other),
+			}),
+		};
+	}
+
+	let parts: Vec<String> = name_or_pointer.split('/').skip(1).map(unescape_token).collect();
+	let mut current = Some(root);
+	let mut path = String::new();
+	for (i, part) in parts.iter().enumerate() {
+		let is_last = i == parts.len() - 1;
+		current = match current {
+			Some(Value::Object(map)) => map.get(part.as_str()),
+			Some(Value::Array(arr)) => {
+				let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+				arr.get(idx)
+			}
+			Some(other) => return Err(not_a_container(other, part, name_or_pointer, &path)),
+			// a missing parent is created empty, so only the `0` or `-` part of a new array can be inserted
+			None => {
+				if is_array_token(part) && part != "0" && part != "-" {
+					array_insert_index(&[], part, name_or_pointer, &path)?;
+				}
+				None
+			}
+		};
+		if is_last {
+			break;
+		}
+		path.push('/');
+		path.push_str(&escape_token(part));
+	}
+	Ok(())
+}
+
+/// Returns the index to set or append in `arr` (at `path`, for the requested `name`) for the token
+/// (`-` or `arr.len()` means append).
+pub(crate) fn array_insert_index(arr: &[Value], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		arr.len()
+	} else {
+		// a non index token would be a property name, so the array is not the expected object
+		token.parse::<usize>().map_err(|_| JsonValueExtError::NotAnObject {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > arr.len() {
+		return Err(JsonValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: arr.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Value, token: &str, name: &str, path: &str) -> JsonValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), json_kind_name(value));
+	if is_array_token(token) {
+		JsonValueExtError::NotAnArray { name, path, actual }
+	} else {
+		JsonValueExtError::NotAnObject { name, path, actual }
+	}
+}
+
+/// Deserializes `T` directly from the borrowed `value` (`&Value` is a `Deserializer`), so without cloning the subtree
+/// (only the owned parts of `T`, like its `String`s, get allocated).
+pub(crate) fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: &Value) -> Result<T> {
+	T::deserialize(value).map_err(|cause| JsonValueExtError::PropertyValueNotDeserializable {
+		name: name_or_pointer.to_string(),
+		not_of_type: std::any::type_name::<T>(),
+		actual: json_kind_name(value),
+		snippet: value_snippet(value, SNIPPET_MAX_LEN),
+		cause,
+	})
+}
+
+/// The max length (in bytes) of the value snippets carried by the type errors.
+pub(crate) const SNIPPET_MAX_LEN: usize = 120;
+
+/// Returns the compact JSON rendering of the value, truncated to `max_len` bytes (on a char boundary) with a trailing `…`.
+/// Only the first `max_len` bytes are serialized, so this is cheap even for large values.
+pub(crate) fn value_snippet(value: &Value, max_len: usize) -> String {
+	struct CappedWriter {
+		buf: Vec<u8>,
+		max_len: usize,
+		truncated: bool,
+	}
+
+	impl std::io::Write for CappedWriter {
+		fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+			let remaining = self.max_len - self.buf.len();
+			if bytes.len() > remaining {
+				self.buf.extend_from_slice(&bytes[..remaining]);
+				self.truncated = true;
+				// stops the serialization
+				return Err(std::io::Error::other("snippet max length reached"));
+			}
+			self.buf.extend_from_slice(bytes);
+			Ok(bytes.len())
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	let mut writer = CappedWriter {
+		buf: Vec::new(),
+		max_len,
+		truncated: false,
+	};
+	let _ = serde_json::to_writer(&mut writer, value);
+
+	// the cut can split a multi-byte char, so keep only the valid utf8 prefix
+	let valid_len = match std::str::from_utf8(&writer.buf) {
+		Ok(_) => writer.buf.len(),
+		Err(err) => err.valid_up_to(),
+	};
+	let mut snippet = String::from_utf8_lossy(&writer.buf[..valid_len]).into_owned();
+	if writer.truncated {
+		snippet.push('…');
+	}
+	snippet
+}
+
+/// Returns up to 3 of the `keys` with a name close to `missing` (case insensitive match or small edit distance), closest first.
+pub(crate) fn suggest_keys<'a>(keys: impl IntoIterator<Item = &'a String>, missing: &str) -> Vec<String> {
+	let missing_lower = missing.to_lowercase();
+	let max_distance = (missing.chars().count() / 3).max(1);
+
+	let mut candidates: Vec<(usize, &String)> = keys
+		.into_iter()
+		.filter_map(|key| {
+			let distance = edit_distance(&key.to_lowercase(), &missing_lower);
+			(distance <= max_distance).then_some((distance, key))
+		})
+		.collect();
+	candidates.sort();
+
+	candidates.into_iter().take(3).map(|(_, key)| key.clone()).collect()
+}
+
+/// Levenshtein distance (by chars).
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut prev: Vec<usize> = (0..=b.len()).collect();
+	for (i, ca) in a.chars().enumerate() {
+		let mut row = vec![i + 1; b.len() + 1];
+		for (j, cb) in b.iter().enumerate() {
+			let cost = if ca == *cb { 0 } else { 1 };
+			row[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+		}
+		prev = row;
+	}
+	prev[b.len()]
+}
+
+/// Returns the JSON kind name of the value (e.g., `"string"`, `"object"`), as reported in the errors.
+pub(crate) fn json_kind_name(value: &Value) -> &'static str {
+	JsonKind::of(value).as_str()
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum JsonValueExtError {
+	Custom(String),
+
+	/// The property at `name` (the requested name or pointer path) does not exist.
+	/// - `resolved`: the pointer of the deepest existing ancestor (`""` for the root).
+	/// - `missing`: the first path token not found under `resolved`.
+	/// - `suggestions`: the sibling keys with a close name (closest first).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The `${var}` reference of the string leaf at `path` is not found in the context
+	/// (see `JsonValueExt::x_render_template`).
+	TemplateVarNotFound {
+		path: String,
+		var: String,
+	},
+
+	/// The value at `name` (the requested name or pointer path) is not of type `not_of_type`, but of JSON kind `actual`.
+	/// `snippet` is the truncated JSON rendering of the value (at most 120 bytes, ending with `…` when truncated).
+	PropertyValueNotOfType {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		snippet: String,
+	},
+
+	/// The value at `name` (of JSON kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		snippet: String,
+		cause: serde_json::Error,
+	},
+
+	// -- AsType errors
+	ValueNotOfType(&'static str),
+
+	ArrayElementNotOfType {
+		index: usize,
+		not_of_type: &'static str,
+	},
+
+	ArrayLengthMismatch {
+		expected: usize,
+		actual: usize,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of JSON kind `actual`) is not an object, while the requested `name` needs it to be one.
+	NotAnObject {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of JSON kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	/// The property at `path` already exists, while the requested `name` needs to create it (e.g., `JsonValueExt::x_rename`).
+	PropertyAlreadyExists {
+		name: String,
+		path: String,
+	},
+
+	/// The flat `key` sets a value at `path`, which other keys already set as a scalar or as a parent
+	/// (see `JsonValueExt::x_unflatten`).
+	UnflattenConflict {
+		key: String,
+		path: String,
+	},
+
+	/// The JSON Patch `test` operation at `path` failed, as the value is not the `expected` one
+	/// (see `JsonValueExt::x_apply_patch`).
+	/// `actual_snippet` is the truncated JSON rendering of the actual value (like `PropertyValueNotOfType::snippet`).
+	PatchTestFailed {
+		path: String,
+		expected: Value,
+		actual_snippet: String,
+	},
+
+	/// Some inserts of `JsonValueExt::x_insert_many` failed, with their `name_or_pointer` and error (in the pairs order).
+	InsertManyFailed {
+		failures: Vec<(String, JsonValueExtError)>,
+	},
+
+	/// The requested `name` is not a valid name or pointer path.
+	InvalidPath {
+		name: String,
+		cause: String,
+	},
+
+	// -- Query errors
+	InvalidQuery {
+		query: String,
+		cause: String,
+	},
+
+	// -- Serde & IO errors
+	/// The JSON Lines `line` (1-based) is not valid JSON (see `lines::JsonLines`).
+	InvalidJsonLine {
+		line: usize,
+		cause: serde_json::Error,
+	},
 
 	#[from]
 	SerdeJson(serde_json::Error),
+
+	#[from]
+	Io(std::io::Error),
+
+	/// The file at `path` could not be read or written (see `JsonValueExt::x_load_file` and `x_save_file`).
+	FileIo {
+		path: std::path::PathBuf,
+		cause: std::io::Error,
+	},
+
+	/// The file at `path` is not valid JSON (see `JsonValueExt::x_load_file`).
+	FileNotParsable {
+		path: std::path::PathBuf,
+		cause: serde_json::Error,
+	},
+}
+
+/// The category of a `JsonValueExtError` (see `JsonValueExtError::kind`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonValueExtErrorKind {
+	/// `PropertyNotFound`, `TemplateVarNotFound`
+	NotFound,
+	/// `PropertyValueNotOfType`, `PropertyValueNotDeserializable`, `ValueNotOfType`, `ArrayElementNotOfType`, `ArrayLengthMismatch`,
+	/// `PatchTestFailed`
+	TypeMismatch,
+	/// `NotAnObject`, `NotAnArray`, `IndexOutOfBounds`, `PropertyAlreadyExists`, `UnflattenConflict`
+	Structure,
+	/// `InvalidPath`, `InvalidQuery`
+	InvalidInput,
+	/// `SerdeJson`, `InvalidJsonLine`, `FileNotParsable`
+	Serde,
+	/// `Io`, `FileIo`
+	Io,
+	/// `Custom`
+	Custom,
+	/// `InsertManyFailed` (see the kind of each failure)
+	Multiple,
 }
 
 impl JsonValueExtError {
-	pub(crate) fn custom(val: impl std::fmt::Display) -> Self {
-		Self::Custom(val.to_string())
+	/// Returns the category of this error, to handle the errors programmatically without matching every variant.
+	pub fn kind(&self) -> JsonValueExtErrorKind {
+		match self {
+			Self::Custom(_) => JsonValueExtErrorKind::Custom,
+			Self::PropertyNotFound { .. } | Self::TemplateVarNotFound { .. } => JsonValueExtErrorKind::NotFound,
+			Self::PropertyValueNotOfType { .. }
+			| Self::PropertyValueNotDeserializable { .. }
+			| Self::ValueNotOfType(_)
+			| Self::ArrayElementNotOfType { .. }
+			| Self::ArrayLengthMismatch { .. }
+			| Self::PatchTestFailed { .. } => JsonValueExtErrorKind::TypeMismatch,
+			Self::NotAnObject { .. }
+			| Self::NotAnArray { .. }
+			| Self::IndexOutOfBounds { .. }
+			| Self::PropertyAlreadyExists { .. }
+			| Self::UnflattenConflict { .. } => JsonValueExtErrorKind::Structure,
+			Self::InvalidPath { .. } | Self::InvalidQuery { .. } => JsonValueExtErrorKind::InvalidInput,
+			Self::SerdeJson(_) | Self::InvalidJsonLine { .. } | Self::FileNotParsable { .. } => {
+				JsonValueExtErrorKind::Serde
+			}
+			Self::Io(_) | Self::FileIo { .. } => JsonValueExtErrorKind::Io,
+			Self::InsertManyFailed { .. } => JsonValueExtErrorKind::Multiple,
+		}
+	}
+
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	pub(crate) fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		for token in path_tokens(name_or_pointer) {
+			let next = match current {
+				Value::Object(map) => map.get(&token),
+				Value::Array(arr) => token.parse::<usize>().ok().and_then(|idx| arr.get(idx)),
+				_ => None,
+			};
+			let Some(next) = next else {
+				return Self::not_found_in(name_or_pointer, resolved, current, token);
+			};
+			resolved.push('/');
+			resolved.push_str(&escape_token(&token));
+			current = next;
+		}
+
+		// should not happen (the path resolved), but still report it as not found
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing: String::new(),
+			suggestions: Vec::new(),
+		}
+	}
+
+	/// Returns the `PropertyNotFound` error of the `missing` token under the `parent` value at the `resolved` pointer.
+	fn not_found_in(name_or_pointer: &str, resolved: String, parent: &Value, missing: String) -> Self {
+		let suggestions = parent
+			.as_object()
+			.map(|map| suggest_keys(map.keys(), &missing))
+			.unwrap_or_default();
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+
+	/// Turns an `AsType` `ValueNotOfType` error into a `PropertyValueNotOfType` for the property `name`.
+	pub(crate) fn with_property_context(self, name: &str, value: &Value) -> Self {
+		match self {
+			Self::ValueNotOfType(not_of_type) => Self::PropertyValueNotOfType {
+				name: name.to_string(),
+				not_of_type,
+				actual: json_kind_name(value),
+				snippet: value_snippet(value, SNIPPET_MAX_LEN),
+			},
+			other => other,
+		}
 	}
 }
 
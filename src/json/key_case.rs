@@ -0,0 +1,113 @@
+use crate::json::json_path::{path_tokens, tokens_match};
+use serde_json::{Map, Value};
+
+/// The case of the object keys for `JsonValueExt::x_rename_keys`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+	/// `snake_case`
+	Snake,
+	/// `camelCase`
+	Camel,
+	/// `PascalCase`
+	Pascal,
+	/// `kebab-case`
+	Kebab,
+}
+
+impl Case {
+	/// Returns the `name` converted to this case (e.g., `userId`, `user-id`, and `UserID` are all `user_id` in `Snake`).
+	/// - The words are split on `_`, `-`, spaces, and the case changes (e.g., `HTTPServer` is `HTTP` and `Server`).
+	/// - The leading `_` and `-` are kept (e.g., `_id`).
+	pub fn convert(self, name: &str) -> String {
+		let body = name.trim_start_matches(['_', '-']);
+		let prefix = &name[..name.len() - body.len()];
+
+		let mut res = String::with_capacity(name.len() + 4);
+		res.push_str(prefix);
+		for (idx, word) in split_words(body).iter().enumerate() {
+			match self {
+				Case::Snake | Case::Kebab => {
+					if idx > 0 {
+						res.push(if self == Case::Snake { '_' } else { '-' });
+					}
+					res.push_str(&word.to_lowercase());
+				}
+				Case::Camel if idx == 0 => res.push_str(&word.to_lowercase()),
+				Case::Camel | Case::Pascal => {
+					let mut chars = word.chars();
+					if let Some(first) = chars.next() {
+						res.extend(first.to_uppercase());
+						res.push_str(&chars.as_str().to_lowercase());
+					}
+				}
+			}
+		}
+		res
+	}
+}
+
+/// Renames every object key of `value` to the `case`, except under the `exclude` paths.
+pub(crate) fn rename_keys(value: &mut Value, case: Case, exclude: &[&str]) {
+	let exclude: Vec<Vec<String>> = exclude.iter().map(|path| path_tokens(path)).collect();
+	rename_keys_at(value, case, &exclude, &mut Vec::new());
+}
+
+// region:    --- Support
+
+fn rename_keys_at(value: &mut Value, case: Case, exclude: &[Vec<String>], tokens: &mut Vec<String>) {
+	match value {
+		Value::Object(map) => {
+			let mut renamed = Map::with_capacity(map.len());
+			for (key, mut item) in std::mem::take(map) {
+				tokens.push(key);
+				let new_key = if is_excluded(exclude, tokens) {
+					tokens.last().cloned().unwrap_or_default()
+				} else {
+					rename_keys_at(&mut item, case, exclude, tokens);
+					case.convert(tokens.last().map(String::as_str).unwrap_or_default())
+				};
+				tokens.pop();
+				renamed.insert(new_key, item);
+			}
+			*map = renamed;
+		}
+		Value::Array(arr) => {
+			for (idx, item) in arr.iter_mut().enumerate() {
+				tokens.push(idx.to_string());
+				if !is_excluded(exclude, tokens) {
+					rename_keys_at(item, case, exclude, tokens);
+				}
+				tokens.pop();
+			}
+		}
+		_ => (),
+	}
+}
+
+fn is_excluded(exclude: &[Vec<String>], tokens: &[String]) -> bool {
+	exclude.iter().any(|path| tokens_match(path, tokens))
+}
+
+/// Splits the `name` in words, on `_`, `-`, spaces, and the case changes.
+fn split_words(name: &str) -> Vec<&str> {
+	let mut words = Vec::new();
+	for part in name.split(['_', '-', ' ']).filter(|part| !part.is_empty()) {
+		let chars: Vec<(usize, char)> = part.char_indices().collect();
+		let mut start = 0;
+		for i in 1..chars.len() {
+			let (idx, c) = chars[i];
+			let prev = chars[i - 1].1;
+			let next_is_lower = chars.get(i + 1).is_some_and(|(_, next)| next.is_lowercase());
+			// `userId` -> `user` `Id`, and `HTTPServer` -> `HTTP` `Server`
+			let is_boundary = c.is_uppercase() && (!prev.is_uppercase() || next_is_lower) && prev.is_alphanumeric();
+			if is_boundary {
+				words.push(&part[start..idx]);
+				start = idx;
+			}
+		}
+		words.push(&part[start..]);
+	}
+	words
+}
+
+// endregion: --- Support
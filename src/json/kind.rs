@@ -0,0 +1,51 @@
+use serde_json::Value;
+
+/// The kind of a JSON value (see `JsonValueExt::x_kind`).
+///
+/// The kinds are ordered as declared (e.g., `Null < Bool < Number`), as sorted by `JsonValueExt::x_sort_array_by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JsonKind {
+	Null,
+	Bool,
+	Number,
+	String,
+	Array,
+	Object,
+}
+
+impl JsonKind {
+	/// Returns the kind of the `value`.
+	pub fn of(value: &Value) -> Self {
+		match value {
+			Value::Null => JsonKind::Null,
+			Value::Bool(_) => JsonKind::Bool,
+			Value::Number(_) => JsonKind::Number,
+			Value::String(_) => JsonKind::String,
+			Value::Array(_) => JsonKind::Array,
+			Value::Object(_) => JsonKind::Object,
+		}
+	}
+
+	/// Returns the lowercase name of the kind (e.g., `"string"`), as reported in the errors.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			JsonKind::Null => "null",
+			JsonKind::Bool => "bool",
+			JsonKind::Number => "number",
+			JsonKind::String => "string",
+			JsonKind::Array => "array",
+			JsonKind::Object => "object",
+		}
+	}
+
+	/// Returns `true` for the `Array` and `Object` kinds.
+	pub fn is_container(self) -> bool {
+		matches!(self, JsonKind::Array | JsonKind::Object)
+	}
+}
+
+impl core::fmt::Display for JsonKind {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
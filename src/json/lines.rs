@@ -0,0 +1,112 @@
+//! JSON Lines (NDJSON) reading and writing, one compact JSON value per line.
+
+use crate::json::file::file_io;
+use crate::JsonValueExtError;
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+/// An iterator over the values of a JSON Lines reader, one `Result<Value>` per non-blank line.
+/// - A line that is not valid JSON yields an `InvalidJsonLine` error (with its 1-based line number),
+///   and the iteration can continue with the next lines.
+/// - A read error yields an `Io` error, and ends the iteration.
+///
+/// ```rust
+/// for value in lines::read_jsonl(BufReader::new(File::open("events.jsonl")?)) {
+///     let value = value?;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct JsonLines<R> {
+	reader: R,
+	line: usize,
+	buf: String,
+	done: bool,
+}
+
+impl<R: BufRead> JsonLines<R> {
+	pub fn new(reader: R) -> Self {
+		Self {
+			reader,
+			line: 0,
+			buf: String::new(),
+			done: false,
+		}
+	}
+
+	/// Returns the number of lines read so far (including the blank ones).
+	pub fn line(&self) -> usize {
+		self.line
+	}
+}
+
+impl<R: BufRead> Iterator for JsonLines<R> {
+	type Item = Result<Value, JsonValueExtError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while !self.done {
+			self.buf.clear();
+			match self.reader.read_line(&mut self.buf) {
+				Ok(0) => self.done = true,
+				Ok(_) => {
+					self.line += 1;
+					let text = self.buf.trim();
+					if text.is_empty() {
+						continue;
+					}
+					let res = serde_json::from_str(text)
+						.map_err(|cause| JsonValueExtError::InvalidJsonLine { line: self.line, cause });
+					return Some(res);
+				}
+				Err(err) => {
+					self.done = true;
+					return Some(Err(err.into()));
+				}
+			}
+		}
+		None
+	}
+}
+
+/// Returns the `JsonLines` iterator over the values of `reader`.
+pub fn read_jsonl<R: BufRead>(reader: R) -> JsonLines<R> {
+	JsonLines::new(reader)
+}
+
+/// Writes the `values` to `writer` as JSON Lines (each one compact, followed by `\n`), and returns their count.
+pub fn write_jsonl<'a, W: Write>(
+	mut writer: W,
+	values: impl IntoIterator<Item = &'a Value>,
+) -> Result<usize, JsonValueExtError> {
+	let mut count = 0;
+	for value in values {
+		serde_json::to_writer(&mut writer, value)?;
+		writer.write_all(b"\n")?;
+		count += 1;
+	}
+	writer.flush()?;
+	Ok(count)
+}
+
+/// Appends the JSON Lines line of `value` to the file at `path` (created if missing), with a single `write_all`.
+pub(crate) fn append_jsonl(value: &Value, path: &Path) -> Result<(), JsonValueExtError> {
+	let line = to_jsonl_line(value)?;
+	OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(path)
+		.and_then(|mut file| file.write_all(&line))
+		.map_err(|cause| file_io(path, cause))
+}
+
+// region:    --- Support
+
+/// Returns the JSON Lines line of `value` (compact, followed by `\n`).
+fn to_jsonl_line(value: &Value) -> Result<Vec<u8>, JsonValueExtError> {
+	let mut line = serde_json::to_vec(value)?;
+	line.push(b'\n');
+	Ok(line)
+}
+
+// endregion: --- Support
@@ -0,0 +1,16 @@
+/// Returns the values at the names or pointer paths as a typed tuple, or the first error
+/// (the typed counterpart of `JsonValueExt::x_get_many`).
+///
+/// ```rust
+/// let (name, age) = x_get_many!(value, "/user/name" => String, "/user/age" => u32)?;
+/// ```
+#[macro_export]
+macro_rules! x_get_many {
+	($value:expr, $($name_or_pointer:expr => $ty:ty),+ $(,)?) => {{
+		use $crate::JsonValueExt as _;
+		let value = &$value;
+		(|| -> core::result::Result<_, $crate::JsonValueExtError> {
+			Ok(($(value.x_get::<$ty>($name_or_pointer)?,)+))
+		})()
+	}};
+}
@@ -0,0 +1,86 @@
+use crate::json::order::remove_key;
+use serde_json::{Map, Value};
+
+/// Options for `JsonValueExt::x_merge`.
+///
+/// By default, arrays are replaced and `null` values overwrite the existing values.
+#[derive(Debug, Clone, Default)]
+pub struct MergeOptions {
+	pub arrays: ArrayMerge,
+	pub nulls: NullMerge,
+}
+
+/// How two arrays at the same path get merged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayMerge {
+	/// The array from `other` replaces the existing one.
+	#[default]
+	Replace,
+	/// The elements from `other` are appended to the existing array.
+	Concat,
+	/// The elements from `other` not already present (by value) are appended to the existing array.
+	Union,
+}
+
+/// How `null` values from `other` get merged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NullMerge {
+	/// The `null` value overwrites the existing value.
+	#[default]
+	Overwrite,
+	/// The `null` value deletes the existing property (like RFC 7386 JSON Merge Patch).
+	Delete,
+}
+
+impl MergeOptions {
+	pub fn with_arrays(mut self, arrays: ArrayMerge) -> Self {
+		self.arrays = arrays;
+		self
+	}
+
+	pub fn with_nulls(mut self, nulls: NullMerge) -> Self {
+		self.nulls = nulls;
+		self
+	}
+}
+
+/// Deep merges `other` into `target`.
+pub(crate) fn merge_value(target: &mut Value, other: Value, options: &MergeOptions) {
+	match (target, other) {
+		(Value::Object(target_map), Value::Object(other_map)) => merge_map(target_map, other_map, options),
+		(Value::Array(target_arr), Value::Array(other_arr)) => match options.arrays {
+			ArrayMerge::Replace => *target_arr = other_arr,
+			ArrayMerge::Concat => target_arr.extend(other_arr),
+			ArrayMerge::Union => {
+				for item in other_arr {
+					if !target_arr.contains(&item) {
+						target_arr.push(item);
+					}
+				}
+			}
+		},
+		(target, other) => *target = other,
+	}
+}
+
+fn merge_map(target: &mut Map<String, Value>, other: Map<String, Value>, options: &MergeOptions) {
+	for (key, value) in other {
+		if value.is_null() && options.nulls == NullMerge::Delete {
+			remove_key(target, &key);
+			continue;
+		}
+		match target.get_mut(&key) {
+			Some(existing) => merge_value(existing, value, options),
+			None => {
+				// merge into an empty object so that the null rules apply to the new nested properties
+				let mut new_value = if value.is_object() {
+					Value::Object(Map::new())
+				} else {
+					Value::Null
+				};
+				merge_value(&mut new_value, value, options);
+				target.insert(key, new_value);
+			}
+		}
+	}
+}
@@ -1,9 +1,88 @@
 // region:    --- Modules
 
+mod array;
 mod as_type;
+#[cfg(feature = "chrono")]
+mod as_type_chrono;
+mod as_type_mut;
+#[cfg(feature = "uuid")]
+mod as_type_uuid;
+mod builder;
+mod canonical;
+mod coerce;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod diff_report;
+mod entry;
+mod env;
+mod equals;
+mod extractor;
+mod file;
+#[cfg(feature = "tokio")]
+mod file_async;
+mod flatten;
+mod hash;
+mod iters;
+mod json_path;
 mod json_value_ext;
+mod key_case;
+mod kind;
+pub mod lines;
+mod macros;
+mod merge;
+mod order;
+#[cfg(feature = "rayon")]
+mod par_walk;
+mod patch;
+mod pointer;
+mod pretty;
+mod preview;
+mod projection;
+mod prune;
+mod query;
+mod redact;
+mod relocate;
+mod replace;
+mod schema;
+mod sort;
+mod stats;
+pub mod stream;
+mod template;
+mod tracked;
+mod visitor;
+mod walk;
 
+pub use array::*;
 pub use as_type::*;
+pub use as_type_mut::*;
+pub use builder::*;
+#[cfg(feature = "decimal")]
+pub use decimal::*;
+pub use diff_report::*;
+pub use entry::*;
+pub use env::*;
+pub use equals::*;
+pub use extractor::*;
+pub use file::*;
+pub use flatten::*;
+pub use iters::*;
+pub use json_path::*;
 pub use json_value_ext::*;
+pub use key_case::*;
+pub use kind::*;
+pub use merge::*;
+pub use patch::*;
+pub use pointer::*;
+pub use pretty::*;
+pub use prune::*;
+pub use query::*;
+pub use redact::*;
+pub use relocate::*;
+pub use sort::*;
+pub use stats::*;
+pub use template::{MissingVar, TemplateOptions};
+pub use tracked::*;
+pub use visitor::*;
+pub use walk::*;
 
 // endregion: --- Modules
@@ -0,0 +1,124 @@
+//! The object key order support, with the `preserve_order` feature (serde_json `preserve_order`, objects keeping
+//! their insertion order).
+//!
+//! The removals of this crate shift the following keys (instead of the serde_json `Map::remove` swap with the last key),
+//! so removing, moving, or taking a property never reorders its siblings.
+
+#[cfg(feature = "preserve_order")]
+use crate::json::json_path::to_pointer;
+#[cfg(feature = "preserve_order")]
+use crate::json::json_value_ext::{check_insert, insert_value};
+#[cfg(feature = "preserve_order")]
+use crate::json::relocate::split_parent;
+#[cfg(feature = "preserve_order")]
+use crate::JsonValueExtError;
+use serde_json::{Map, Value};
+
+/// Removes the `key` from `map`, keeping the order of the other keys (with the `preserve_order` feature).
+#[cfg(feature = "preserve_order")]
+pub(crate) fn remove_key(map: &mut Map<String, Value>, key: &str) -> Option<Value> {
+	map.shift_remove(key)
+}
+
+/// Removes the `key` from `map` (sorted without the `preserve_order` feature).
+#[cfg(not(feature = "preserve_order"))]
+pub(crate) fn remove_key(map: &mut Map<String, Value>, key: &str) -> Option<Value> {
+	map.remove(key)
+}
+
+/// Inserts the `key` at `index` in `map` (or at the end when past it), with the `preserve_order` feature.
+#[cfg(feature = "preserve_order")]
+pub(crate) fn insert_key_at(map: &mut Map<String, Value>, index: usize, key: String, value: Value) {
+	map.shift_insert(index.min(map.len()), key, value);
+}
+
+/// Inserts the `key` in `map` (sorted without the `preserve_order` feature, so the `index` does not apply).
+#[cfg(not(feature = "preserve_order"))]
+pub(crate) fn insert_key_at(map: &mut Map<String, Value>, _index: usize, key: String, value: Value) {
+	map.insert(key, value);
+}
+
+/// Inserts the `new_value` at the name or pointer path (like `x_insert`), as the `index`th property of its parent object.
+#[cfg(feature = "preserve_order")]
+pub(crate) fn insert_at_position(
+	root: &mut Value,
+	name_or_pointer: &str,
+	index: usize,
+	new_value: Value,
+) -> Result<(), JsonValueExtError> {
+	let pointer = to_pointer(name_or_pointer);
+	let (parent_pointer, key) = split_parent(&pointer);
+	check_insert(root, &pointer)?;
+
+	// -- Check the index (the len not counting the key, when replaced), before any change
+	let len = match root.pointer(parent_pointer) {
+		Some(Value::Object(map)) => map.len() - usize::from(map.contains_key(&key)),
+		Some(Value::Array(_)) => {
+			return Err(JsonValueExtError::NotAnObject {
+				name: name_or_pointer.to_string(),
+				path: parent_pointer.to_string(),
+				actual: "array",
+			})
+		}
+		// a created parent
+		_ => 0,
+	};
+	if index > len {
+		return Err(JsonValueExtError::IndexOutOfBounds {
+			name: name_or_pointer.to_string(),
+			path: parent_pointer.to_string(),
+			index,
+			len,
+		});
+	}
+
+	insert_value(root, &pointer, new_value)?;
+	if let Some(Value::Object(map)) = root.pointer_mut(parent_pointer) {
+		if let Some(value) = remove_key(map, &key) {
+			insert_key_at(map, index, key, value);
+		}
+	}
+	Ok(())
+}
+
+/// Moves the property at the name or pointer path just before its sibling `before` (a name or pointer path too).
+#[cfg(feature = "preserve_order")]
+pub(crate) fn move_key_before(root: &mut Value, name_or_pointer: &str, before: &str) -> Result<(), JsonValueExtError> {
+	let (pointer, before_pointer) = (to_pointer(name_or_pointer), to_pointer(before));
+	let ((parent_pointer, key), (before_parent_pointer, before_key)) =
+		(split_parent(&pointer), split_parent(&before_pointer));
+	if parent_pointer != before_parent_pointer {
+		return Err(JsonValueExtError::InvalidPath {
+			name: before.to_string(),
+			cause: format!("'{before}' is not a sibling of '{name_or_pointer}'"),
+		});
+	}
+	match root.pointer(parent_pointer) {
+		Some(Value::Object(map)) if !map.contains_key(&key) => {
+			return Err(JsonValueExtError::property_not_found(root, name_or_pointer))
+		}
+		Some(Value::Object(map)) if !map.contains_key(&before_key) => {
+			return Err(JsonValueExtError::property_not_found(root, before))
+		}
+		Some(Value::Object(_)) => (),
+		Some(Value::Array(_)) => {
+			return Err(JsonValueExtError::NotAnObject {
+				name: name_or_pointer.to_string(),
+				path: parent_pointer.to_string(),
+				actual: "array",
+			})
+		}
+		_ => return Err(JsonValueExtError::property_not_found(root, name_or_pointer)),
+	}
+	if key == before_key {
+		return Ok(());
+	}
+
+	if let Some(Value::Object(map)) = root.pointer_mut(parent_pointer) {
+		if let Some(value) = remove_key(map, &key) {
+			let index = map.keys().position(|k| *k == before_key).unwrap_or(map.len());
+			insert_key_at(map, index, key, value);
+		}
+	}
+	Ok(())
+}
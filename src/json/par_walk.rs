@@ -0,0 +1,77 @@
+//! Parallel (rayon) read-only traversals, for very large documents.
+
+use crate::json::walk::child_path;
+use crate::WalkControl;
+use rayon::prelude::*;
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Walks all the values (root included, with an empty path), visiting the independent subtrees in parallel.
+/// A value is always visited before its children, but the sibling subtrees are visited in no particular order.
+///
+/// Returns `false` if the traversal was stopped by the callback (the other threads stop as soon as possible).
+pub(crate) fn par_walk_ref<F, R>(root: &Value, callback: F) -> bool
+where
+	F: Fn(&str, &Value) -> R + Sync,
+	R: Into<WalkControl>,
+{
+	let stopped = AtomicBool::new(false);
+	par_walk_ref_at(String::new(), root, &callback, &stopped);
+	!stopped.load(Ordering::Relaxed)
+}
+
+fn par_walk_ref_at<F, R>(path: String, value: &Value, callback: &F, stopped: &AtomicBool)
+where
+	F: Fn(&str, &Value) -> R + Sync,
+	R: Into<WalkControl>,
+{
+	if stopped.load(Ordering::Relaxed) {
+		return;
+	}
+	match callback(&path, value).into() {
+		WalkControl::Continue => (),
+		WalkControl::SkipChildren => return,
+		WalkControl::Stop => {
+			stopped.store(true, Ordering::Relaxed);
+			return;
+		}
+	}
+
+	match value {
+		Value::Object(map) => {
+			let children: Vec<(&String, &Value)> = map.iter().collect();
+			children
+				.into_par_iter()
+				.for_each(|(key, child)| par_walk_ref_at(child_path(&path, key), child, callback, stopped));
+		}
+		Value::Array(arr) => {
+			arr.par_iter()
+				.enumerate()
+				.for_each(|(idx, child)| par_walk_ref_at(format!("{path}/{idx}"), child, callback, stopped));
+		}
+		_ => (),
+	}
+}
+
+/// Returns all the scalar leaves with their pointer paths, collected in parallel (in document order).
+pub(crate) fn par_leaves(root: &Value) -> Vec<(String, &Value)> {
+	par_leaves_at(String::new(), root)
+}
+
+fn par_leaves_at(path: String, value: &Value) -> Vec<(String, &Value)> {
+	match value {
+		Value::Object(map) => {
+			let children: Vec<(&String, &Value)> = map.iter().collect();
+			children
+				.into_par_iter()
+				.flat_map_iter(|(key, child)| par_leaves_at(child_path(&path, key), child))
+				.collect()
+		}
+		Value::Array(arr) => arr
+			.par_iter()
+			.enumerate()
+			.flat_map_iter(|(idx, child)| par_leaves_at(format!("{path}/{idx}"), child))
+			.collect(),
+		_ => vec![(path, value)],
+	}
+}
@@ -0,0 +1,296 @@
+use crate::json::json_path::escape_token;
+use crate::json::json_value_ext::{json_kind_name, remove_value, value_snippet, SNIPPET_MAX_LEN};
+use crate::json::pointer::token_index;
+use crate::json::relocate::{copy_value, move_value, split_parent};
+use crate::{CopyMode, JsonValueExtError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A RFC 6902 JSON Patch (a list of operations), as returned by `JsonValueExt::x_diff`.
+///
+/// Serializes to (and deserializes from) the standard JSON Patch array format.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Patch(Vec<PatchOperation>);
+
+/// A RFC 6902 JSON Patch operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+	Add { path: String, value: Value },
+	Remove { path: String },
+	Replace { path: String, value: Value },
+	Move { from: String, path: String },
+	Copy { from: String, path: String },
+	Test { path: String, value: Value },
+}
+
+impl Patch {
+	pub fn new(operations: Vec<PatchOperation>) -> Self {
+		Self(operations)
+	}
+
+	pub fn operations(&self) -> &[PatchOperation] {
+		&self.0
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn push(&mut self, operation: PatchOperation) {
+		self.0.push(operation)
+	}
+}
+
+// region:    --- Froms
+
+impl From<Vec<PatchOperation>> for Patch {
+	fn from(operations: Vec<PatchOperation>) -> Self {
+		Self(operations)
+	}
+}
+
+impl From<Patch> for Vec<PatchOperation> {
+	fn from(patch: Patch) -> Self {
+		patch.0
+	}
+}
+
+impl IntoIterator for Patch {
+	type Item = PatchOperation;
+	type IntoIter = std::vec::IntoIter<PatchOperation>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a> IntoIterator for &'a Patch {
+	type Item = &'a PatchOperation;
+	type IntoIter = std::slice::Iter<'a, PatchOperation>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+// endregion: --- Froms
+
+// region:    --- Diff
+
+/// Options for `JsonValueExt::x_diff_with`.
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+	/// When `true` (default), a changed value is a single `replace` operation, otherwise a `remove` followed by an `add`.
+	pub prefer_replace: bool,
+	/// When `true`, array elements that moved are reported with `move` operations (default `false`).
+	pub detect_moves: bool,
+}
+
+impl Default for DiffOptions {
+	fn default() -> Self {
+		Self {
+			prefer_replace: true,
+			detect_moves: false,
+		}
+	}
+}
+
+impl DiffOptions {
+	pub fn with_prefer_replace(mut self, prefer_replace: bool) -> Self {
+		self.prefer_replace = prefer_replace;
+		self
+	}
+
+	pub fn with_detect_moves(mut self, detect_moves: bool) -> Self {
+		self.detect_moves = detect_moves;
+		self
+	}
+}
+
+/// Returns the patch transforming `from` into `to`.
+pub(crate) fn diff_values(from: &Value, to: &Value, options: &DiffOptions) -> Patch {
+	let mut patch = Patch::default();
+	diff_at("", from, to, options, &mut patch);
+	patch
+}
+
+fn diff_at(path: &str, from: &Value, to: &Value, options: &DiffOptions, patch: &mut Patch) {
+	if from == to {
+		return;
+	}
+
+	match (from, to) {
+		(Value::Object(from_map), Value::Object(to_map)) => {
+			for (key, from_value) in from_map {
+				let child_path = format!("{path}/{}", escape_token(key));
+				match to_map.get(key) {
+					Some(to_value) => diff_at(&child_path, from_value, to_value, options, patch),
+					None => patch.push(PatchOperation::Remove { path: child_path }),
+				}
+			}
+			for (key, to_value) in to_map {
+				if !from_map.contains_key(key) {
+					patch.push(PatchOperation::Add {
+						path: format!("{path}/{}", escape_token(key)),
+						value: to_value.clone(),
+					});
+				}
+			}
+		}
+		(Value::Array(from_arr), Value::Array(to_arr)) => diff_arrays(path, from_arr, to_arr, options, patch),
+		(_, to) => {
+			if options.prefer_replace || path.is_empty() {
+				patch.push(PatchOperation::Replace {
+					path: path.to_string(),
+					value: to.clone(),
+				});
+			} else {
+				patch.push(PatchOperation::Remove { path: path.to_string() });
+				patch.push(PatchOperation::Add {
+					path: path.to_string(),
+					value: to.clone(),
+				});
+			}
+		}
+	}
+}
+
+fn diff_arrays(path: &str, from: &[Value], to: &[Value], options: &DiffOptions, patch: &mut Patch) {
+	// -- Detect the moves first, simulating them on a working copy
+	let mut current: Vec<&Value> = from.iter().collect();
+	if options.detect_moves {
+		for (idx, to_value) in to.iter().enumerate() {
+			if current.get(idx) == Some(&to_value) {
+				continue;
+			}
+			let found = current.iter().skip(idx + 1).position(|value| *value == to_value);
+			if let Some(offset) = found {
+				let from_idx = idx + 1 + offset;
+				let value = current.remove(from_idx);
+				current.insert(idx, value);
+				patch.push(PatchOperation::Move {
+					from: format!("{path}/{from_idx}"),
+					path: format!("{path}/{idx}"),
+				});
+			}
+		}
+	}
+
+	// -- Then diff element by element
+	let common = current.len().min(to.len());
+	for idx in 0..common {
+		diff_at(&format!("{path}/{idx}"), current[idx], &to[idx], options, patch);
+	}
+	// remove from the end, so the indices stay valid
+	for idx in (common..current.len()).rev() {
+		patch.push(PatchOperation::Remove {
+			path: format!("{path}/{idx}"),
+		});
+	}
+	for (idx, to_value) in to.iter().enumerate().skip(common) {
+		patch.push(PatchOperation::Add {
+			path: format!("{path}/{idx}"),
+			value: to_value.clone(),
+		});
+	}
+}
+
+// endregion: --- Diff
+
+// region:    --- Apply
+
+/// Applies the operations of `patch` to `root`, on a copy swapped in once all of them succeeded.
+pub(crate) fn apply_patch(root: &mut Value, patch: &Patch) -> Result<(), JsonValueExtError> {
+	let mut patched = root.clone();
+	for operation in patch {
+		apply_operation(&mut patched, operation)?;
+	}
+	*root = patched;
+	Ok(())
+}
+
+/// Applies the RFC 6902 `operation` to `root` (e.g., to undo or redo a `TrackedValue` edit).
+pub(crate) fn apply_operation(root: &mut Value, operation: &PatchOperation) -> Result<(), JsonValueExtError> {
+	match operation {
+		PatchOperation::Add { path, value } => add_value(root, path, value.clone()),
+		PatchOperation::Remove { path } => match remove_value(root, path) {
+			Some(_) => Ok(()),
+			None => Err(JsonValueExtError::property_not_found(root, path)),
+		},
+		PatchOperation::Replace { path, value } => match root.pointer_mut(path) {
+			Some(target) => {
+				*target = value.clone();
+				Ok(())
+			}
+			None => Err(JsonValueExtError::property_not_found(root, path)),
+		},
+		PatchOperation::Move { from, path } => move_value(root, from, path),
+		PatchOperation::Copy { from, path } => copy_value(root, from, path, CopyMode::Overwrite),
+		PatchOperation::Test { path, value } => match root.pointer(path) {
+			Some(actual) if actual == value => Ok(()),
+			Some(actual) => Err(JsonValueExtError::PatchTestFailed {
+				path: path.clone(),
+				expected: value.clone(),
+				actual_snippet: value_snippet(actual, SNIPPET_MAX_LEN),
+			}),
+			None => Err(JsonValueExtError::property_not_found(root, path)),
+		},
+	}
+}
+
+/// Adds the `value` at the pointer `path` (the JSON Patch `add`, inserting in arrays), whose parent must exist.
+fn add_value(root: &mut Value, path: &str, value: Value) -> Result<(), JsonValueExtError> {
+	if path.is_empty() {
+		*root = value;
+		return Ok(());
+	}
+	let (parent_pointer, token) = split_parent(path);
+	if root.pointer(parent_pointer).is_none() {
+		return Err(JsonValueExtError::property_not_found(root, parent_pointer));
+	}
+	match root.pointer_mut(parent_pointer) {
+		Some(Value::Object(map)) => {
+			map.insert(token, value);
+			Ok(())
+		}
+		Some(Value::Array(arr)) => {
+			let len = arr.len();
+			match token_index(&token) {
+				_ if token == "-" => arr.push(value),
+				Some(idx) if idx <= len => arr.insert(idx, value),
+				Some(index) => {
+					return Err(JsonValueExtError::IndexOutOfBounds {
+						name: path.to_string(),
+						path: parent_pointer.to_string(),
+						index,
+						len,
+					})
+				}
+				None => {
+					return Err(JsonValueExtError::InvalidPath {
+						name: path.to_string(),
+						cause: format!("'{token}' is not an array index"),
+					})
+				}
+			}
+			Ok(())
+		}
+		Some(other) => Err(JsonValueExtError::NotAnObject {
+			name: path.to_string(),
+			path: parent_pointer.to_string(),
+			actual: json_kind_name(other),
+		}),
+		None => Err(JsonValueExtError::InvalidPath {
+			name: path.to_string(),
+			cause: "the parent cannot be resolved".to_string(),
+		}),
+	}
+}
+
+// endregion: --- Apply
@@ -0,0 +1,142 @@
+use crate::json::json_path::unescape_token;
+use crate::json::json_value_ext::insert_tokens;
+use crate::JsonValueExtError;
+use serde_json::Value;
+use std::ops::Deref;
+
+/// A JSON Pointer path parsed and validated once, for the hot path lookups
+/// (see `JsonValueExt::x_get_pointer`, `x_take_pointer`, and `x_insert_pointer`).
+///
+/// The tokens are unescaped, and the array indices parsed, at `parse` time, so the lookups do not re-split the path.
+///
+/// ```rust
+/// let pointer = JsonPointer::parse("/user/address/city")?;
+/// for value in values.iter() {
+///     let city: String = value.x_get_pointer(&pointer)?;
+/// }
+/// ```
+///
+/// `JsonPointer` also derefs to `&str`, so it can be passed to any `name_or_pointer` argument.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonPointer {
+	pointer: String,
+	tokens: Vec<String>,
+	/// The array index of each token, if it is one.
+	indices: Vec<Option<usize>>,
+}
+
+impl JsonPointer {
+	/// Parses a JSON Pointer path (`""` for the root, otherwise starting with `/`).
+	/// Returns `InvalidPath` for a path not starting with `/`, or with a `~` not followed by `0` or `1`.
+	pub fn parse(pointer: &str) -> Result<Self, JsonValueExtError> {
+		let invalid = |cause: &str| JsonValueExtError::InvalidPath {
+			name: pointer.to_string(),
+			cause: cause.to_string(),
+		};
+		if !pointer.is_empty() && !pointer.starts_with('/') {
+			return Err(invalid("a JSON Pointer must be empty or start with '/'"));
+		}
+
+		let mut tokens = Vec::new();
+		let mut indices = Vec::new();
+		for token in pointer.split('/').skip(1) {
+			let mut escapes = token.split('~').skip(1);
+			if escapes.any(|after| !after.starts_with(['0', '1'])) {
+				return Err(invalid("a '~' must be followed by '0' or '1'"));
+			}
+			indices.push(token_index(token));
+			tokens.push(unescape_token(token));
+		}
+
+		Ok(Self {
+			pointer: pointer.to_string(),
+			tokens,
+			indices,
+		})
+	}
+
+	/// Returns the JSON Pointer representation of this path.
+	pub fn as_str(&self) -> &str {
+		&self.pointer
+	}
+
+	/// Returns the (unescaped) tokens of this path.
+	pub fn tokens(&self) -> &[String] {
+		&self.tokens
+	}
+
+	/// Returns the value at this path in `root`.
+	pub fn resolve<'a>(&self, root: &'a Value) -> Option<&'a Value> {
+		let mut current = root;
+		for (token, index) in self.tokens.iter().zip(&self.indices) {
+			current = match current {
+				Value::Object(map) => map.get(token)?,
+				Value::Array(arr) => arr.get((*index)?)?,
+				_ => return None,
+			};
+		}
+		Some(current)
+	}
+
+	/// Returns the mutable value at this path in `root`.
+	pub fn resolve_mut<'a>(&self, root: &'a mut Value) -> Option<&'a mut Value> {
+		let mut current = root;
+		for (token, index) in self.tokens.iter().zip(&self.indices) {
+			current = match current {
+				Value::Object(map) => map.get_mut(token)?,
+				Value::Array(arr) => arr.get_mut((*index)?)?,
+				_ => return None,
+			};
+		}
+		Some(current)
+	}
+
+	/// Inserts the `new_value` at this path in `root` (like `JsonValueExt::x_insert`).
+	pub(crate) fn insert(&self, root: &mut Value, new_value: Value) -> Result<(), JsonValueExtError> {
+		insert_tokens(root, &self.pointer, &self.tokens, new_value)
+	}
+}
+
+/// Returns the array index of a pointer token, the same way as the serde_json `pointer`
+/// (no sign, and no leading zeros like `01`).
+pub(crate) fn token_index(token: &str) -> Option<usize> {
+	let is_index =
+		!token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()) && (token == "0" || !token.starts_with('0'));
+	if is_index {
+		token.parse::<usize>().ok()
+	} else {
+		None
+	}
+}
+
+// region:    --- Froms & Deref
+
+impl Deref for JsonPointer {
+	type Target = str;
+
+	fn deref(&self) -> &Self::Target {
+		&self.pointer
+	}
+}
+
+impl AsRef<str> for JsonPointer {
+	fn as_ref(&self) -> &str {
+		&self.pointer
+	}
+}
+
+impl TryFrom<&str> for JsonPointer {
+	type Error = JsonValueExtError;
+
+	fn try_from(pointer: &str) -> Result<Self, Self::Error> {
+		Self::parse(pointer)
+	}
+}
+
+impl core::fmt::Display for JsonPointer {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.pointer)
+	}
+}
+
+// endregion: --- Froms & Deref
@@ -0,0 +1,200 @@
+use serde_json::Value;
+
+/// Options for `JsonValueExt::x_pretty_with`.
+///
+/// By default, the output is the same as `x_pretty` (two spaces indent, no trailing newline).
+#[derive(Debug, Clone)]
+pub struct PrettyOptions {
+	/// The indent of each level (default two spaces).
+	pub indent: String,
+	/// When `true`, the object keys are written in lexicographic order (default `false`).
+	pub sort_keys: bool,
+	/// The max width of the arrays of scalars written on one line (e.g., `[1, 2, 3]`), `0` to never do it (default).
+	pub compact_array_width: usize,
+	/// When `true`, the output ends with a newline (default `false`).
+	pub trailing_newline: bool,
+}
+
+impl Default for PrettyOptions {
+	fn default() -> Self {
+		Self {
+			indent: "  ".to_string(),
+			sort_keys: false,
+			compact_array_width: 0,
+			trailing_newline: false,
+		}
+	}
+}
+
+impl PrettyOptions {
+	pub fn with_indent(mut self, indent: impl Into<String>) -> Self {
+		self.indent = indent.into();
+		self
+	}
+
+	/// Sets the indent to `width` spaces.
+	pub fn with_indent_width(mut self, width: usize) -> Self {
+		self.indent = " ".repeat(width);
+		self
+	}
+
+	pub fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+		self.sort_keys = sort_keys;
+		self
+	}
+
+	pub fn with_compact_array_width(mut self, compact_array_width: usize) -> Self {
+		self.compact_array_width = compact_array_width;
+		self
+	}
+
+	pub fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+		self.trailing_newline = trailing_newline;
+		self
+	}
+}
+
+/// Returns the pretty-printed `value` per the `options`.
+pub(crate) fn pretty(value: &Value, options: &PrettyOptions) -> String {
+	Printer {
+		options,
+		colored: false,
+	}
+	.print(value)
+}
+
+/// Same as `pretty`, but with the ANSI colors for the keys and scalars, unless the `NO_COLOR` env var is set
+/// (see <https://no-color.org>).
+#[cfg(feature = "color")]
+pub(crate) fn pretty_colored(value: &Value, options: &PrettyOptions) -> String {
+	let colored = std::env::var_os("NO_COLOR").map_or(true, |no_color| no_color.is_empty());
+	Printer { options, colored }.print(value)
+}
+
+// region:    --- Support
+
+// The ANSI colors (SGR codes) of the colored output.
+const KEY_COLOR: &str = "34"; // blue
+const STRING_COLOR: &str = "32"; // green
+const NUMBER_COLOR: &str = "36"; // cyan
+const BOOL_COLOR: &str = "33"; // yellow
+const NULL_COLOR: &str = "90"; // bright black
+
+struct Printer<'a> {
+	options: &'a PrettyOptions,
+	colored: bool,
+}
+
+impl Printer<'_> {
+	fn print(&self, value: &Value) -> String {
+		let mut buf = String::new();
+		self.write_value(&mut buf, value, 0);
+		if self.options.trailing_newline {
+			buf.push('\n');
+		}
+		buf
+	}
+
+	fn write_value(&self, buf: &mut String, value: &Value, depth: usize) {
+		match value {
+			Value::Array(arr) if arr.is_empty() => buf.push_str("[]"),
+			Value::Array(arr) => {
+				if self.write_compact_array(buf, arr) {
+					return;
+				}
+				buf.push('[');
+				for (idx, item) in arr.iter().enumerate() {
+					if idx > 0 {
+						buf.push(',');
+					}
+					self.push_newline(buf, depth + 1);
+					self.write_value(buf, item, depth + 1);
+				}
+				self.push_newline(buf, depth);
+				buf.push(']');
+			}
+			Value::Object(map) if map.is_empty() => buf.push_str("{}"),
+			Value::Object(map) => {
+				let mut entries: Vec<_> = map.iter().collect();
+				if self.options.sort_keys {
+					entries.sort_by_key(|(key, _)| *key);
+				}
+				buf.push('{');
+				for (idx, (key, item)) in entries.into_iter().enumerate() {
+					if idx > 0 {
+						buf.push(',');
+					}
+					self.push_newline(buf, depth + 1);
+					self.write_colored(buf, KEY_COLOR, &scalar_json(&Value::String(key.clone())));
+					buf.push_str(": ");
+					self.write_value(buf, item, depth + 1);
+				}
+				self.push_newline(buf, depth);
+				buf.push('}');
+			}
+			scalar => self.write_scalar(buf, scalar),
+		}
+	}
+
+	/// Writes the array on one line, and returns `true`, if it only has scalars and fits the `compact_array_width`.
+	fn write_compact_array(&self, buf: &mut String, arr: &[Value]) -> bool {
+		let width = self.options.compact_array_width;
+		if width == 0 || arr.iter().any(|item| item.is_array() || item.is_object()) {
+			return false;
+		}
+		// the width is the one of the text without the colors
+		let mut line_width = 1;
+		for (idx, item) in arr.iter().enumerate() {
+			line_width += scalar_json(item).len() + if idx > 0 { 2 } else { 0 };
+			if line_width >= width {
+				return false;
+			}
+		}
+
+		buf.push('[');
+		for (idx, item) in arr.iter().enumerate() {
+			if idx > 0 {
+				buf.push_str(", ");
+			}
+			self.write_scalar(buf, item);
+		}
+		buf.push(']');
+		true
+	}
+
+	fn write_scalar(&self, buf: &mut String, value: &Value) {
+		let color = match value {
+			Value::String(_) => STRING_COLOR,
+			Value::Number(_) => NUMBER_COLOR,
+			Value::Bool(_) => BOOL_COLOR,
+			_ => NULL_COLOR,
+		};
+		self.write_colored(buf, color, &scalar_json(value));
+	}
+
+	fn write_colored(&self, buf: &mut String, color: &str, text: &str) {
+		if self.colored {
+			buf.push_str("\x1b[");
+			buf.push_str(color);
+			buf.push('m');
+			buf.push_str(text);
+			buf.push_str("\x1b[0m");
+		} else {
+			buf.push_str(text);
+		}
+	}
+
+	fn push_newline(&self, buf: &mut String, depth: usize) {
+		buf.push('\n');
+		for _ in 0..depth {
+			buf.push_str(&self.options.indent);
+		}
+	}
+}
+
+fn scalar_json(value: &Value) -> String {
+	// Serializing a scalar `Value` cannot fail.
+	serde_json::to_string(value).unwrap_or_default()
+}
+
+// endregion: --- Support
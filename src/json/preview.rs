@@ -0,0 +1,91 @@
+use serde_json::Value;
+
+/// The max number of elements previewed per array.
+const ARRAY_MAX_ITEMS: usize = 10;
+
+/// The min length (in bytes) a string is truncated to.
+const STRING_MIN_LEN: usize = 16;
+
+/// Returns the compact single line rendering of `value`, with the long strings and arrays truncated with `…`,
+/// and at most `max_bytes` long.
+pub(crate) fn preview(value: &Value, max_bytes: usize) -> String {
+	let mut buf = String::new();
+	write_preview(&mut buf, value, max_bytes);
+	if buf.len() > max_bytes {
+		let ellipsis = if max_bytes >= '…'.len_utf8() { "…" } else { "" };
+		buf.truncate(char_boundary(&buf, max_bytes - ellipsis.len()));
+		buf.push_str(ellipsis);
+	}
+	buf
+}
+
+// region:    --- Support
+
+fn write_preview(buf: &mut String, value: &Value, max_bytes: usize) {
+	match value {
+		Value::String(s) => {
+			let max_len = (max_bytes / 4).max(STRING_MIN_LEN);
+			if s.len() > max_len {
+				let truncated = format!("{}…", &s[..char_boundary(s, max_len)]);
+				push_json(buf, &Value::String(truncated));
+			} else {
+				push_json(buf, value);
+			}
+		}
+		Value::Array(arr) => {
+			buf.push('[');
+			for (idx, item) in arr.iter().enumerate() {
+				if idx >= ARRAY_MAX_ITEMS || buf.len() >= max_bytes {
+					push_more(buf, idx, arr.len() - idx);
+					break;
+				}
+				if idx > 0 {
+					buf.push(',');
+				}
+				write_preview(buf, item, max_bytes);
+			}
+			buf.push(']');
+		}
+		Value::Object(map) => {
+			buf.push('{');
+			for (idx, (key, item)) in map.iter().enumerate() {
+				if buf.len() >= max_bytes {
+					push_more(buf, idx, map.len() - idx);
+					break;
+				}
+				if idx > 0 {
+					buf.push(',');
+				}
+				push_json(buf, &Value::String(key.clone()));
+				buf.push(':');
+				write_preview(buf, item, max_bytes);
+			}
+			buf.push('}');
+		}
+		scalar => push_json(buf, scalar),
+	}
+}
+
+/// Pushes the `…+N` marker of the `remaining` elements not previewed (after `idx` previewed ones).
+fn push_more(buf: &mut String, idx: usize, remaining: usize) {
+	if idx > 0 {
+		buf.push(',');
+	}
+	buf.push_str(&format!("…+{remaining}"));
+}
+
+fn push_json(buf: &mut String, value: &Value) {
+	// Serializing a `Value` cannot fail.
+	buf.push_str(&serde_json::to_string(value).unwrap_or_default());
+}
+
+/// Returns the largest char boundary of `s` at or before `idx`.
+fn char_boundary(s: &str, idx: usize) -> usize {
+	let mut idx = idx.min(s.len());
+	while !s.is_char_boundary(idx) {
+		idx -= 1;
+	}
+	idx
+}
+
+// endregion: --- Support
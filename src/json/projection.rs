@@ -0,0 +1,106 @@
+use crate::json::json_path::path_tokens;
+use crate::json::json_value_ext::{get_value, insert_value};
+use crate::JsonValueExtError;
+use serde_json::{Map, Value};
+
+/// Returns a new value with only the values at the `paths` (names or pointer paths, with `*` wildcard parts),
+/// keeping their nesting.
+pub(crate) fn pick(value: &Value, paths: &[&str]) -> Value {
+	let tokens: Vec<Vec<String>> = paths.iter().map(|path| path_tokens(path)).collect();
+	let paths: Vec<&[String]> = tokens.iter().map(Vec::as_slice).collect();
+	pick_at(value, &paths).unwrap_or_else(|| empty_like(value))
+}
+
+/// Returns a clone of the value without the values at the `paths` (names or pointer paths, with `*` wildcard parts).
+pub(crate) fn omit(value: &Value, paths: &[&str]) -> Value {
+	let tokens: Vec<Vec<String>> = paths.iter().map(|path| path_tokens(path)).collect();
+	let paths: Vec<&[String]> = tokens.iter().map(Vec::as_slice).collect();
+	let mut res = value.clone();
+	omit_at(&mut res, &paths);
+	res
+}
+
+/// Returns a new object with the values at each `from` path set at their `to` path (the missing ones are skipped).
+pub(crate) fn project(value: &Value, mapping: &[(&str, &str)]) -> Result<Value, JsonValueExtError> {
+	let mut res = Value::Object(Map::new());
+	for (from, to) in mapping {
+		if let Some(item) = get_value(value, from) {
+			insert_value(&mut res, to, item.clone())?;
+		}
+	}
+	Ok(res)
+}
+
+// region:    --- Support
+
+/// Returns the picked value, or `None` if none of the `paths` (the remaining tokens) resolves.
+fn pick_at(value: &Value, paths: &[&[String]]) -> Option<Value> {
+	if paths.iter().any(|path| path.is_empty()) {
+		return Some(value.clone());
+	}
+	match value {
+		Value::Object(map) => {
+			let picked: Map<String, Value> = map
+				.iter()
+				.filter_map(|(key, item)| Some((key.clone(), pick_at(item, &sub_paths(paths, key))?)))
+				.collect();
+			(!picked.is_empty()).then_some(Value::Object(picked))
+		}
+		Value::Array(arr) => {
+			let picked: Vec<Value> = arr
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, item)| pick_at(item, &sub_paths(paths, &idx.to_string())))
+				.collect();
+			(!picked.is_empty()).then_some(Value::Array(picked))
+		}
+		_ => None,
+	}
+}
+
+fn omit_at(value: &mut Value, paths: &[&[String]]) {
+	match value {
+		Value::Object(map) => map.retain(|key, item| retain_item(item, &sub_paths(paths, key))),
+		Value::Array(arr) => {
+			let mut idx = 0;
+			arr.retain_mut(|item| {
+				let retain = retain_item(item, &sub_paths(paths, &idx.to_string()));
+				idx += 1;
+				retain
+			});
+		}
+		_ => (),
+	}
+}
+
+/// Returns `false` if the item is omitted as a whole, otherwise omits its matching children.
+fn retain_item(item: &mut Value, sub_paths: &[&[String]]) -> bool {
+	if sub_paths.iter().any(|path| path.is_empty()) {
+		return false;
+	}
+	if !sub_paths.is_empty() {
+		omit_at(item, sub_paths);
+	}
+	true
+}
+
+/// Returns the remaining tokens of the `paths` whose first token matches `token` (or is a `*`).
+fn sub_paths<'a>(paths: &[&'a [String]], token: &str) -> Vec<&'a [String]> {
+	paths
+		.iter()
+		.filter_map(|path| match path.split_first() {
+			Some((first, rest)) if first == token || first == "*" => Some(rest),
+			_ => None,
+		})
+		.collect()
+}
+
+fn empty_like(value: &Value) -> Value {
+	match value {
+		Value::Array(_) => Value::Array(Vec::new()),
+		Value::Object(_) => Value::Object(Map::new()),
+		_ => Value::Null,
+	}
+}
+
+// endregion: --- Support
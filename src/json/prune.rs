@@ -0,0 +1,81 @@
+use serde_json::Value;
+
+/// Options for `JsonValueExt::x_prune`, selecting which values get removed.
+///
+/// By default, the `null` values and the empty objects and arrays are removed, but not the empty strings.
+#[derive(Debug, Clone)]
+pub struct PruneOptions {
+	pub nulls: bool,
+	pub empty_objects: bool,
+	pub empty_arrays: bool,
+	pub empty_strings: bool,
+}
+
+impl Default for PruneOptions {
+	fn default() -> Self {
+		Self {
+			nulls: true,
+			empty_objects: true,
+			empty_arrays: true,
+			empty_strings: false,
+		}
+	}
+}
+
+impl PruneOptions {
+	pub fn with_nulls(mut self, nulls: bool) -> Self {
+		self.nulls = nulls;
+		self
+	}
+
+	pub fn with_empty_objects(mut self, empty_objects: bool) -> Self {
+		self.empty_objects = empty_objects;
+		self
+	}
+
+	pub fn with_empty_arrays(mut self, empty_arrays: bool) -> Self {
+		self.empty_arrays = empty_arrays;
+		self
+	}
+
+	pub fn with_empty_strings(mut self, empty_strings: bool) -> Self {
+		self.empty_strings = empty_strings;
+		self
+	}
+}
+
+/// Removes (recursively, bottom-up) the object properties and array elements matching the `options`,
+/// and returns the number of removed values.
+pub(crate) fn prune(value: &mut Value, options: &PruneOptions) -> usize {
+	match value {
+		Value::Object(map) => {
+			let mut count: usize = map.values_mut().map(|item| prune(item, options)).sum();
+			let len = map.len();
+			map.retain(|_, item| !is_prunable(item, options));
+			count += len - map.len();
+			count
+		}
+		Value::Array(arr) => {
+			let mut count: usize = arr.iter_mut().map(|item| prune(item, options)).sum();
+			let len = arr.len();
+			arr.retain(|item| !is_prunable(item, options));
+			count += len - arr.len();
+			count
+		}
+		_ => 0,
+	}
+}
+
+// region:    --- Support
+
+fn is_prunable(value: &Value, options: &PruneOptions) -> bool {
+	match value {
+		Value::Null => options.nulls,
+		Value::Object(map) => options.empty_objects && map.is_empty(),
+		Value::Array(arr) => options.empty_arrays && arr.is_empty(),
+		Value::String(s) => options.empty_strings && s.is_empty(),
+		_ => false,
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,594 @@
+//! A JSONPath subset to query a `serde_json::Value`.
+//!
+//! Supported syntax:
+//! - `$` root, `.name` / `['name']` child, `.*` / `[*]` wildcard, `..` recursive descent
+//! - `[0]`, `[-1]` index, `[1:3]` slice, `[0,2]` / `['a','b']` union
+//! - `[?(@.price < 10)]` filters with `==`, `!=`, `<`, `<=`, `>`, `>=`, `&&`, `||`, `!`, and existence (`[?(@.isbn)]`)
+//!
+//! e.g., `$.store.book[?(@.price < 10)].title`
+
+use crate::json::json_path::escape_token;
+use crate::JsonValueExtError;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+/// A compiled JSONPath query, which can be reused across documents.
+///
+/// ```rust
+/// let query = JsonQuery::compile("$.store.book[?(@.price < 10)].title")?;
+/// let titles: Vec<&Value> = query.find(&doc);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonQuery {
+	query: String,
+	segments: Vec<Segment>,
+}
+
+impl JsonQuery {
+	/// Compiles a JSONPath expression (must start with `$`).
+	pub fn compile(query: &str) -> Result<Self> {
+		let segments = Parser::new(query).parse_query()?;
+		Ok(Self {
+			query: query.to_string(),
+			segments,
+		})
+	}
+
+	/// Returns the original query expression.
+	pub fn as_str(&self) -> &str {
+		&self.query
+	}
+
+	/// Returns references to all the values matching this query.
+	pub fn find<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+		self.select(root).into_iter().map(|(_, value)| value).collect()
+	}
+
+	/// Returns the JSON Pointer paths of all the values matching this query.
+	pub fn find_paths(&self, root: &Value) -> Vec<String> {
+		self.select(root).into_iter().map(|(path, _)| path).collect()
+	}
+
+	/// Calls `callback` with a mutable reference to each value matching this query.
+	///
+	/// Returns the number of values visited.
+	pub fn for_each_mut<F>(&self, root: &mut Value, mut callback: F) -> usize
+	where
+		F: FnMut(&mut Value),
+	{
+		let mut count = 0;
+		for path in self.find_paths(root) {
+			if let Some(value) = root.pointer_mut(&path) {
+				callback(value);
+				count += 1;
+			}
+		}
+		count
+	}
+
+	fn select<'a>(&self, root: &'a Value) -> Vec<(String, &'a Value)> {
+		let mut nodes = vec![(String::new(), root)];
+		for segment in &self.segments {
+			let mut next = Vec::new();
+			for (path, value) in nodes {
+				match segment {
+					Segment::Child(selectors) => apply_selectors(selectors, root, path, value, &mut next),
+					Segment::Descendant(selectors) => {
+						let mut descendants = Vec::new();
+						collect_descendants(path, value, &mut descendants);
+						for (path, value) in descendants {
+							apply_selectors(selectors, root, path, value, &mut next);
+						}
+					}
+				}
+			}
+			nodes = next;
+		}
+		nodes
+	}
+}
+
+impl FromStr for JsonQuery {
+	type Err = JsonValueExtError;
+
+	fn from_str(query: &str) -> Result<Self> {
+		Self::compile(query)
+	}
+}
+
+// region:    --- Ast
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+	Child(Vec<Selector>),
+	Descendant(Vec<Selector>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+	Name(String),
+	Wildcard,
+	Index(i64),
+	Slice(Option<i64>, Option<i64>),
+	Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+	Exists(Operand),
+	Compare(Operand, CmpOp, Operand),
+	Not(Box<Filter>),
+	And(Box<Filter>, Box<Filter>),
+	Or(Box<Filter>, Box<Filter>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+	/// Path relative to the current node (`@`).
+	Current(Vec<PathPart>),
+	/// Path relative to the root (`$`).
+	Root(Vec<PathPart>),
+	Literal(Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathPart {
+	Name(String),
+	Index(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CmpOp {
+	Eq,
+	Ne,
+	Lt,
+	Le,
+	Gt,
+	Ge,
+}
+
+// endregion: --- Ast
+
+// region:    --- Eval
+
+fn apply_selectors<'a>(
+	selectors: &[Selector],
+	root: &'a Value,
+	path: String,
+	value: &'a Value,
+	out: &mut Vec<(String, &'a Value)>,
+) {
+	for selector in selectors {
+		match (selector, value) {
+			(Selector::Name(name), Value::Object(map)) => {
+				if let Some(child) = map.get(name) {
+					out.push((child_path(&path, name), child));
+				}
+			}
+			(Selector::Wildcard, Value::Object(map)) => {
+				for (key, child) in map {
+					out.push((child_path(&path, key), child));
+				}
+			}
+			(Selector::Wildcard, Value::Array(arr)) => {
+				for (idx, child) in arr.iter().enumerate() {
+					out.push((format!("{path}/{idx}"), child));
+				}
+			}
+			(Selector::Index(idx), Value::Array(arr)) => {
+				if let Some(idx) = resolve_index(*idx, arr.len()) {
+					out.push((format!("{path}/{idx}"), &arr[idx]));
+				}
+			}
+			(Selector::Slice(start, end), Value::Array(arr)) => {
+				let len = arr.len() as i64;
+				let clamp = |i: i64| (if i < 0 { (len + i).max(0) } else { i.min(len) }) as usize;
+				let start = start.map(clamp).unwrap_or(0);
+				let end = end.map(clamp).unwrap_or(arr.len());
+				for (idx, child) in arr.iter().enumerate().take(end).skip(start) {
+					out.push((format!("{path}/{idx}"), child));
+				}
+			}
+			(Selector::Filter(filter), Value::Array(arr)) => {
+				for (idx, child) in arr.iter().enumerate() {
+					if filter.matches(root, child) {
+						out.push((format!("{path}/{idx}"), child));
+					}
+				}
+			}
+			(Selector::Filter(filter), Value::Object(map)) => {
+				for (key, child) in map {
+					if filter.matches(root, child) {
+						out.push((child_path(&path, key), child));
+					}
+				}
+			}
+			_ => (),
+		}
+	}
+}
+
+/// Collects the value and all its descendants (pre-order).
+fn collect_descendants<'a>(path: String, value: &'a Value, out: &mut Vec<(String, &'a Value)>) {
+	out.push((path.clone(), value));
+	match value {
+		Value::Object(map) => {
+			for (key, child) in map {
+				collect_descendants(child_path(&path, key), child, out);
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, child) in arr.iter().enumerate() {
+				collect_descendants(format!("{path}/{idx}"), child, out);
+			}
+		}
+		_ => (),
+	}
+}
+
+fn child_path(path: &str, key: &str) -> String {
+	format!("{path}/{}", escape_token(key))
+}
+
+fn resolve_index(idx: i64, len: usize) -> Option<usize> {
+	let idx = if idx < 0 { len as i64 + idx } else { idx };
+	(0..len as i64).contains(&idx).then_some(idx as usize)
+}
+
+impl Filter {
+	fn matches(&self, root: &Value, current: &Value) -> bool {
+		match self {
+			Filter::Exists(operand) => operand.resolve(root, current).is_some(),
+			Filter::Compare(left, op, right) => match (left.resolve(root, current), right.resolve(root, current)) {
+				(Some(left), Some(right)) => op.eval(left, right),
+				_ => false,
+			},
+			Filter::Not(filter) => !filter.matches(root, current),
+			Filter::And(a, b) => a.matches(root, current) && b.matches(root, current),
+			Filter::Or(a, b) => a.matches(root, current) || b.matches(root, current),
+		}
+	}
+}
+
+impl Operand {
+	fn resolve<'a>(&'a self, root: &'a Value, current: &'a Value) -> Option<&'a Value> {
+		let (start, parts) = match self {
+			Operand::Literal(value) => return Some(value),
+			Operand::Current(parts) => (current, parts),
+			Operand::Root(parts) => (root, parts),
+		};
+		parts.iter().try_fold(start, |value, part| match (part, value) {
+			(PathPart::Name(name), Value::Object(map)) => map.get(name),
+			(PathPart::Index(idx), Value::Array(arr)) => resolve_index(*idx, arr.len()).map(|idx| &arr[idx]),
+			_ => None,
+		})
+	}
+}
+
+impl CmpOp {
+	fn eval(self, left: &Value, right: &Value) -> bool {
+		let ordering = match (left, right) {
+			(Value::Number(l), Value::Number(r)) => l.as_f64().zip(r.as_f64()).and_then(|(l, r)| l.partial_cmp(&r)),
+			(Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+			(l, r) if l == r => Some(Ordering::Equal),
+			_ => None,
+		};
+		match self {
+			CmpOp::Eq => ordering == Some(Ordering::Equal),
+			CmpOp::Ne => ordering != Some(Ordering::Equal),
+			CmpOp::Lt => ordering == Some(Ordering::Less),
+			CmpOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+			CmpOp::Gt => ordering == Some(Ordering::Greater),
+			CmpOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+		}
+	}
+}
+
+// endregion: --- Eval
+
+// region:    --- Parser
+
+struct Parser<'a> {
+	query: &'a str,
+	chars: Vec<char>,
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn new(query: &'a str) -> Self {
+		Self {
+			query,
+			chars: query.chars().collect(),
+			pos: 0,
+		}
+	}
+
+	fn parse_query(mut self) -> Result<Vec<Segment>> {
+		self.skip_ws();
+		self.expect('$')?;
+		let mut segments = Vec::new();
+		while self.skip_ws().is_some() {
+			segments.push(self.parse_segment()?);
+		}
+		Ok(segments)
+	}
+
+	fn parse_segment(&mut self) -> Result<Segment> {
+		if self.eat_str("..") {
+			let selectors = match self.peek() {
+				Some('[') => self.parse_bracket()?,
+				Some('*') => {
+					self.pos += 1;
+					vec![Selector::Wildcard]
+				}
+				_ => vec![Selector::Name(self.parse_name()?)],
+			};
+			Ok(Segment::Descendant(selectors))
+		} else if self.eat('.') {
+			if self.eat('*') {
+				Ok(Segment::Child(vec![Selector::Wildcard]))
+			} else {
+				Ok(Segment::Child(vec![Selector::Name(self.parse_name()?)]))
+			}
+		} else if self.peek() == Some('[') {
+			Ok(Segment::Child(self.parse_bracket()?))
+		} else {
+			Err(self.err("expected '.', '..', or '['"))
+		}
+	}
+
+	fn parse_bracket(&mut self) -> Result<Vec<Selector>> {
+		self.expect('[')?;
+		let mut selectors = Vec::new();
+		loop {
+			self.skip_ws();
+			selectors.push(self.parse_bracket_selector()?);
+			self.skip_ws();
+			if self.eat(']') {
+				return Ok(selectors);
+			}
+			self.expect(',')?;
+		}
+	}
+
+	fn parse_bracket_selector(&mut self) -> Result<Selector> {
+		match self.peek() {
+			Some('\'' | '"') => Ok(Selector::Name(self.parse_quoted()?)),
+			Some('*') => {
+				self.pos += 1;
+				Ok(Selector::Wildcard)
+			}
+			Some('?') => {
+				self.pos += 1;
+				// the parenthesized groups (e.g., `?(@.a < 1) && (@.b)`) are parsed by `parse_unary`
+				Ok(Selector::Filter(self.parse_or()?))
+			}
+			_ => {
+				let start = self.parse_opt_int()?;
+				self.skip_ws();
+				if self.eat(':') {
+					self.skip_ws();
+					let end = self.parse_opt_int()?;
+					Ok(Selector::Slice(start, end))
+				} else {
+					start
+						.map(Selector::Index)
+						.ok_or_else(|| self.err("expected a selector"))
+				}
+			}
+		}
+	}
+
+	fn parse_or(&mut self) -> Result<Filter> {
+		let mut filter = self.parse_and()?;
+		while self.skip_ws().is_some() && self.eat_str("||") {
+			filter = Filter::Or(Box::new(filter), Box::new(self.parse_and()?));
+		}
+		Ok(filter)
+	}
+
+	fn parse_and(&mut self) -> Result<Filter> {
+		let mut filter = self.parse_unary()?;
+		while self.skip_ws().is_some() && self.eat_str("&&") {
+			filter = Filter::And(Box::new(filter), Box::new(self.parse_unary()?));
+		}
+		Ok(filter)
+	}
+
+	fn parse_unary(&mut self) -> Result<Filter> {
+		self.skip_ws();
+		if self.peek() == Some('!') && self.chars.get(self.pos + 1) != Some(&'=') {
+			self.pos += 1;
+			return Ok(Filter::Not(Box::new(self.parse_unary()?)));
+		}
+		if self.eat('(') {
+			let filter = self.parse_or()?;
+			self.skip_ws();
+			self.expect(')')?;
+			return Ok(filter);
+		}
+
+		let left = self.parse_operand()?;
+		self.skip_ws();
+		let op = if self.eat_str("==") {
+			CmpOp::Eq
+		} else if self.eat_str("!=") {
+			CmpOp::Ne
+		} else if self.eat_str("<=") {
+			CmpOp::Le
+		} else if self.eat_str(">=") {
+			CmpOp::Ge
+		} else if self.eat('<') {
+			CmpOp::Lt
+		} else if self.eat('>') {
+			CmpOp::Gt
+		} else {
+			return Ok(Filter::Exists(left));
+		};
+		self.skip_ws();
+		let right = self.parse_operand()?;
+		Ok(Filter::Compare(left, op, right))
+	}
+
+	fn parse_operand(&mut self) -> Result<Operand> {
+		match self.peek() {
+			Some('@') => {
+				self.pos += 1;
+				Ok(Operand::Current(self.parse_path_parts()?))
+			}
+			Some('$') => {
+				self.pos += 1;
+				Ok(Operand::Root(self.parse_path_parts()?))
+			}
+			Some('\'' | '"') => Ok(Operand::Literal(Value::String(self.parse_quoted()?))),
+			Some(_) => {
+				let start = self.pos;
+				while self
+					.peek()
+					.is_some_and(|c| c.is_alphanumeric() || matches!(c, '-' | '+' | '.' | '_'))
+				{
+					self.pos += 1;
+				}
+				let literal: String = self.chars[start..self.pos].iter().collect();
+				match literal.as_str() {
+					"true" => Ok(Operand::Literal(Value::Bool(true))),
+					"false" => Ok(Operand::Literal(Value::Bool(false))),
+					"null" => Ok(Operand::Literal(Value::Null)),
+					num => serde_json::from_str::<serde_json::Number>(num)
+						.map(|num| Operand::Literal(Value::Number(num)))
+						.map_err(|_| self.err(format!("invalid literal '{num}'"))),
+				}
+			}
+			None => Err(self.err("expected an operand")),
+		}
+	}
+
+	fn parse_path_parts(&mut self) -> Result<Vec<PathPart>> {
+		let mut parts = Vec::new();
+		loop {
+			if self.peek() == Some('.') && self.chars.get(self.pos + 1) != Some(&'.') {
+				self.pos += 1;
+				parts.push(PathPart::Name(self.parse_name()?));
+			} else if self.eat('[') {
+				self.skip_ws();
+				let part = match self.peek() {
+					Some('\'' | '"') => PathPart::Name(self.parse_quoted()?),
+					_ => PathPart::Index(self.parse_opt_int()?.ok_or_else(|| self.err("expected an index"))?),
+				};
+				self.skip_ws();
+				self.expect(']')?;
+				parts.push(part);
+			} else {
+				return Ok(parts);
+			}
+		}
+	}
+
+	fn parse_name(&mut self) -> Result<String> {
+		let start = self.pos;
+		while self
+			.peek()
+			.is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '$'))
+		{
+			self.pos += 1;
+		}
+		if start == self.pos {
+			return Err(self.err("expected a property name"));
+		}
+		Ok(self.chars[start..self.pos].iter().collect())
+	}
+
+	fn parse_quoted(&mut self) -> Result<String> {
+		let Some(quote) = self.peek() else {
+			return Err(self.err("expected a quoted string"));
+		};
+		self.pos += 1;
+		let mut content = String::new();
+		loop {
+			match self.peek() {
+				Some('\\') => {
+					self.pos += 1;
+					let escaped = self.peek().ok_or_else(|| self.err("unterminated string"))?;
+					content.push(escaped);
+				}
+				Some(c) if c == quote => {
+					self.pos += 1;
+					return Ok(content);
+				}
+				Some(c) => content.push(c),
+				None => return Err(self.err("unterminated string")),
+			}
+			self.pos += 1;
+		}
+	}
+
+	fn parse_opt_int(&mut self) -> Result<Option<i64>> {
+		let start = self.pos;
+		if self.peek() == Some('-') {
+			self.pos += 1;
+		}
+		while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+			self.pos += 1;
+		}
+		if start == self.pos {
+			return Ok(None);
+		}
+		let num: String = self.chars[start..self.pos].iter().collect();
+		num.parse()
+			.map(Some)
+			.map_err(|_| self.err(format!("invalid integer '{num}'")))
+	}
+
+	// -- Cursor helpers
+
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.pos).copied()
+	}
+
+	/// Skips the whitespaces, and returns the next char (if any).
+	fn skip_ws(&mut self) -> Option<char> {
+		while self.peek().is_some_and(char::is_whitespace) {
+			self.pos += 1;
+		}
+		self.peek()
+	}
+
+	fn eat(&mut self, c: char) -> bool {
+		if self.peek() == Some(c) {
+			self.pos += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn eat_str(&mut self, s: &str) -> bool {
+		let len = s.chars().count();
+		if self.chars.len() >= self.pos + len && s.chars().eq(self.chars[self.pos..self.pos + len].iter().copied()) {
+			self.pos += len;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expect(&mut self, c: char) -> Result<()> {
+		if self.eat(c) {
+			Ok(())
+		} else {
+			Err(self.err(format!("expected '{c}'")))
+		}
+	}
+
+	fn err(&self, cause: impl Into<String>) -> JsonValueExtError {
+		JsonValueExtError::InvalidQuery {
+			query: self.query.to_string(),
+			cause: format!("{} at position {}", cause.into(), self.pos),
+		}
+	}
+}
+
+// endregion: --- Parser
@@ -0,0 +1,167 @@
+use crate::json::hash::stable_hash;
+use crate::json::json_path::{path_tokens, tokens_match};
+use serde_json::Value;
+
+/// The rules for `JsonValueExt::x_redact`, selecting the values to redact and their replacement.
+///
+/// ```rust
+/// let rules = RedactRules::default()
+///     .with_key("authorization")
+///     .with_key_glob("*token*")
+///     .with_path("/user/ssn");
+/// body.x_redact(&rules);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RedactRules {
+	pub rules: Vec<RedactRule>,
+	pub redaction: Redaction,
+}
+
+/// A rule matching the values to redact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactRule {
+	/// The properties with this key name (ASCII case-insensitive), at any depth.
+	Key(String),
+	/// The properties with a key name matching this glob (ASCII case-insensitive, with `*` for any chars, and `?` for one).
+	KeyGlob(String),
+	/// The value at this name or pointer path, where a `*` part matches any property or index (e.g., `/users/*/email`).
+	Path(String),
+}
+
+/// The replacement of the redacted values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+	/// A placeholder string (`"***"` by default).
+	Placeholder(String),
+	/// The `"hash:<16 hex digits>"` string of the value stable hash (see `JsonValueExt::x_hash`), so the equal values
+	/// can still be correlated in the logs.
+	Hash,
+}
+
+impl Default for Redaction {
+	fn default() -> Self {
+		Self::Placeholder("***".to_string())
+	}
+}
+
+impl RedactRules {
+	pub fn with_key(mut self, name: impl Into<String>) -> Self {
+		self.rules.push(RedactRule::Key(name.into()));
+		self
+	}
+
+	pub fn with_key_glob(mut self, glob: impl Into<String>) -> Self {
+		self.rules.push(RedactRule::KeyGlob(glob.into()));
+		self
+	}
+
+	pub fn with_path(mut self, name_or_pointer: impl Into<String>) -> Self {
+		self.rules.push(RedactRule::Path(name_or_pointer.into()));
+		self
+	}
+
+	pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+		self.redaction = redaction;
+		self
+	}
+}
+
+/// Replaces the values matching the `rules`, and returns the number of redacted values.
+pub(crate) fn redact(value: &mut Value, rules: &RedactRules) -> usize {
+	let paths: Vec<Vec<String>> = rules
+		.rules
+		.iter()
+		.filter_map(|rule| match rule {
+			RedactRule::Path(path) => Some(path_tokens(path)),
+			_ => None,
+		})
+		.collect();
+	redact_at(value, rules, &paths, &mut Vec::new())
+}
+
+// region:    --- Support
+
+fn redact_at(value: &mut Value, rules: &RedactRules, paths: &[Vec<String>], tokens: &mut Vec<String>) -> usize {
+	let mut count = 0;
+	match value {
+		Value::Object(map) => {
+			for (key, item) in map.iter_mut() {
+				tokens.push(key.clone());
+				if is_key_match(rules, key) || is_path_match(paths, tokens) {
+					*item = redacted(item, &rules.redaction);
+					count += 1;
+				} else {
+					count += redact_at(item, rules, paths, tokens);
+				}
+				tokens.pop();
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, item) in arr.iter_mut().enumerate() {
+				tokens.push(idx.to_string());
+				if is_path_match(paths, tokens) {
+					*item = redacted(item, &rules.redaction);
+					count += 1;
+				} else {
+					count += redact_at(item, rules, paths, tokens);
+				}
+				tokens.pop();
+			}
+		}
+		_ => (),
+	}
+	count
+}
+
+fn redacted(value: &Value, redaction: &Redaction) -> Value {
+	match redaction {
+		Redaction::Placeholder(placeholder) => Value::String(placeholder.clone()),
+		Redaction::Hash => Value::String(format!("hash:{:016x}", stable_hash(value))),
+	}
+}
+
+fn is_key_match(rules: &RedactRules, key: &str) -> bool {
+	rules.rules.iter().any(|rule| match rule {
+		RedactRule::Key(name) => name.eq_ignore_ascii_case(key),
+		RedactRule::KeyGlob(glob) => {
+			let (glob, key): (Vec<char>, Vec<char>) = (glob.chars().collect(), key.chars().collect());
+			glob_match(&glob, &key)
+		}
+		RedactRule::Path(_) => false,
+	})
+}
+
+fn is_path_match(paths: &[Vec<String>], tokens: &[String]) -> bool {
+	paths.iter().any(|path| tokens_match(path, tokens))
+}
+
+/// Returns `true` if the `text` matches the `glob` (ASCII case-insensitive, with `*` and `?`).
+fn glob_match(glob: &[char], text: &[char]) -> bool {
+	let (mut g, mut t) = (0, 0);
+	// the position after the last `*` in the glob, and the text position it matched up to
+	let mut star: Option<(usize, usize)> = None;
+	while t < text.len() {
+		match glob.get(g) {
+			Some('*') => {
+				star = Some((g + 1, t));
+				g += 1;
+			}
+			Some(c) if *c == '?' || c.eq_ignore_ascii_case(&text[t]) => {
+				g += 1;
+				t += 1;
+			}
+			_ => match star {
+				// backtrack, with the `*` matching one more char
+				Some((star_g, star_t)) => {
+					g = star_g;
+					t = star_t + 1;
+					star = Some((star_g, star_t + 1));
+				}
+				None => return false,
+			},
+		}
+	}
+	glob[g..].iter().all(|c| *c == '*')
+}
+
+// endregion: --- Support
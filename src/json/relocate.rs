@@ -0,0 +1,170 @@
+use crate::json::json_path::{escape_token, to_pointer, unescape_token};
+use crate::json::json_value_ext::{array_insert_index, check_insert, insert_value};
+use crate::json::order::{insert_key_at, remove_key};
+use crate::JsonValueExtError;
+use serde_json::{Map, Value};
+
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+/// What `JsonValueExt::x_copy_with` does when the destination already exists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CopyMode {
+	/// The existing value is replaced (like the JSON Patch `copy` operation).
+	#[default]
+	Overwrite,
+	/// A `PropertyAlreadyExists` error is returned.
+	ErrorIfExists,
+}
+
+/// Renames the key of the property at the name or pointer path to `new_key`, keeping its position in the parent object.
+pub(crate) fn rename_key(root: &mut Value, name_or_pointer: &str, new_key: &str) -> Result<()> {
+	let (parent_pointer, key) = split_parent(name_or_pointer);
+	match root.pointer(parent_pointer) {
+		Some(Value::Object(map)) if map.contains_key(&key) => (),
+		Some(Value::Array(_)) => {
+			return Err(JsonValueExtError::NotAnObject {
+				name: name_or_pointer.to_string(),
+				path: parent_pointer.to_string(),
+				actual: "array",
+			})
+		}
+		_ => return Err(JsonValueExtError::property_not_found(root, name_or_pointer)),
+	}
+	let Some(Value::Object(map)) = root.pointer_mut(parent_pointer) else {
+		return Err(JsonValueExtError::property_not_found(root, name_or_pointer));
+	};
+	if key == new_key {
+		return Ok(());
+	}
+	if map.contains_key(new_key) {
+		return Err(JsonValueExtError::PropertyAlreadyExists {
+			name: name_or_pointer.to_string(),
+			path: format!("{parent_pointer}/{}", escape_token(new_key)),
+		});
+	}
+
+	// rebuilt, to keep the position with the serde_json `preserve_order` feature
+	let renamed: Map<String, Value> = std::mem::take(map)
+		.into_iter()
+		.map(|(k, v)| if k == key { (new_key.to_string(), v) } else { (k, v) })
+		.collect();
+	*map = renamed;
+	Ok(())
+}
+
+/// Moves the value at `from` to `to` (JSON Patch `move`), leaving `root` unchanged on any error.
+pub(crate) fn move_value(root: &mut Value, from: &str, to: &str) -> Result<()> {
+	let (from_pointer, to_pointer) = (to_pointer(from), to_pointer(to));
+	if root.pointer(&from_pointer).is_none() {
+		return Err(JsonValueExtError::property_not_found(root, from));
+	}
+	if from_pointer == to_pointer {
+		return Ok(());
+	}
+	if to_pointer.starts_with(&format!("{from_pointer}/")) {
+		return Err(JsonValueExtError::InvalidPath {
+			name: to.to_string(),
+			cause: format!("cannot move '{from}' into one of its children"),
+		});
+	}
+
+	// -- Take the value, and put it back if it cannot be inserted (once removed, e.g., for the array indices)
+	let (value, restore) = take_value(root, &from_pointer)?;
+	if let Err(err) = check_add(root, to, &to_pointer) {
+		restore.put_back(root, value);
+		return Err(err);
+	}
+	add_value(root, to, &to_pointer, value)
+}
+
+/// Copies (deep clone) the value at `from` to `to`, leaving `root` unchanged on any error.
+pub(crate) fn copy_value(root: &mut Value, from: &str, to: &str, mode: CopyMode) -> Result<()> {
+	let (from_pointer, to_pointer) = (to_pointer(from), to_pointer(to));
+	let Some(value) = root.pointer(&from_pointer) else {
+		return Err(JsonValueExtError::property_not_found(root, from));
+	};
+	if mode == CopyMode::ErrorIfExists && root.pointer(&to_pointer).is_some() {
+		return Err(JsonValueExtError::PropertyAlreadyExists {
+			name: to.to_string(),
+			path: to_pointer,
+		});
+	}
+	check_add(root, to, &to_pointer)?;
+
+	let value = value.clone();
+	add_value(root, to, &to_pointer, value)
+}
+
+// region:    --- Support
+
+/// Adds the `value` at the pointer like the JSON Patch `add`: inserted at the index of an array parent (shifting the
+/// next elements), otherwise inserted like `x_insert` (creating the missing parents).
+fn add_value(root: &mut Value, name: &str, pointer: &str, value: Value) -> Result<()> {
+	let (parent_pointer, token) = split_parent(pointer);
+	if let Some(Value::Array(arr)) = root.pointer_mut(parent_pointer) {
+		let idx = array_insert_index(arr, &token, name, parent_pointer)?;
+		arr.insert(idx, value);
+		return Ok(());
+	}
+	insert_value(root, pointer, value)
+}
+
+/// Returns the error `add_value` would return, without changing `root`.
+fn check_add(root: &Value, name: &str, pointer: &str) -> Result<()> {
+	let (parent_pointer, token) = split_parent(pointer);
+	match root.pointer(parent_pointer) {
+		Some(Value::Array(arr)) => array_insert_index(arr, &token, name, parent_pointer).map(|_| ()),
+		_ => check_insert(root, pointer),
+	}
+}
+
+/// Where a taken value was, to put it back.
+struct Restore {
+	parent_pointer: String,
+	slot: Slot,
+}
+
+enum Slot {
+	/// The key, with its position in the object (for the `preserve_order` feature).
+	Key(String, usize),
+	Index(usize),
+}
+
+impl Restore {
+	fn put_back(self, root: &mut Value, value: Value) {
+		match (root.pointer_mut(&self.parent_pointer), self.slot) {
+			(Some(Value::Object(map)), Slot::Key(key, position)) => insert_key_at(map, position, key, value),
+			(Some(Value::Array(arr)), Slot::Index(idx)) if idx <= arr.len() => arr.insert(idx, value),
+			_ => (),
+		}
+	}
+}
+
+/// Removes the value at the (existing) pointer from its parent, and returns it with where it was.
+fn take_value(root: &mut Value, pointer: &str) -> Result<(Value, Restore)> {
+	let (parent_pointer, token) = split_parent(pointer);
+	let taken = match root.pointer_mut(parent_pointer) {
+		Some(Value::Object(map)) => {
+			let position = map.keys().position(|key| *key == token).unwrap_or(map.len());
+			remove_key(map, &token).map(|value| (value, Slot::Key(token, position)))
+		}
+		Some(Value::Array(arr)) => match token.parse::<usize>() {
+			Ok(idx) if idx < arr.len() => Some((arr.remove(idx), Slot::Index(idx))),
+			_ => None,
+		},
+		_ => None,
+	};
+	let (value, slot) = taken.ok_or_else(|| JsonValueExtError::property_not_found(root, pointer))?;
+	let parent_pointer = parent_pointer.to_string();
+	Ok((value, Restore { parent_pointer, slot }))
+}
+
+/// Returns the parent pointer and the (unescaped) last token of a name or pointer path (`""` is the root pointer).
+pub(crate) fn split_parent(name_or_pointer: &str) -> (&str, String) {
+	match name_or_pointer.strip_prefix('/').and(name_or_pointer.rsplit_once('/')) {
+		Some((parent_pointer, token)) => (parent_pointer, unescape_token(token)),
+		None => ("", name_or_pointer.to_string()),
+	}
+}
+
+// endregion: --- Support
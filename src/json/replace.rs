@@ -0,0 +1,44 @@
+use crate::json::walk::child_path;
+use serde_json::Value;
+
+/// Replaces (pre-order) the values matching `predicate` with the ones returned by `replacement`,
+/// and returns their pointer paths.
+pub(crate) fn replace_where<P, R>(value: &mut Value, predicate: &mut P, replacement: &mut R) -> Vec<String>
+where
+	P: FnMut(&str, &Value) -> bool,
+	R: FnMut(&str, &Value) -> Value,
+{
+	let mut paths = Vec::new();
+	replace_at(value, String::new(), predicate, replacement, &mut paths);
+	paths
+}
+
+// region:    --- Support
+
+fn replace_at<P, R>(value: &mut Value, path: String, predicate: &mut P, replacement: &mut R, paths: &mut Vec<String>)
+where
+	P: FnMut(&str, &Value) -> bool,
+	R: FnMut(&str, &Value) -> Value,
+{
+	// a replaced value is not walked into
+	if predicate(&path, value) {
+		*value = replacement(&path, value);
+		paths.push(path);
+		return;
+	}
+	match value {
+		Value::Object(map) => {
+			for (key, item) in map.iter_mut() {
+				replace_at(item, child_path(&path, key), predicate, replacement, paths);
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, item) in arr.iter_mut().enumerate() {
+				replace_at(item, child_path(&path, &idx.to_string()), predicate, replacement, paths);
+			}
+		}
+		_ => (),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,251 @@
+use crate::json::coerce::coerce_candidates;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+// region:    --- Infer
+
+/// Returns the JSON Schema (draft 2020-12 keywords, without `$schema`) describing the shape of `value`.
+pub(crate) fn infer_schema(value: &Value) -> Value {
+	let mut shape = Shape::default();
+	shape.add(value);
+	shape.into_schema()
+}
+
+/// The accumulated shape of one or more values (e.g., all the items of an array).
+#[derive(Default)]
+struct Shape {
+	is_null: bool,
+	is_bool: bool,
+	is_integer: bool,
+	is_number: bool,
+	is_string: bool,
+	/// The shape of the items of all the arrays seen, if any array was seen (`None` inside when they were all empty).
+	array_items: Option<Option<Box<Shape>>>,
+	/// The number of objects seen.
+	object_count: usize,
+	/// The shape of each property of the objects seen, with the number of objects having it.
+	properties: BTreeMap<String, (usize, Shape)>,
+}
+
+impl Shape {
+	fn add(&mut self, value: &Value) {
+		match value {
+			Value::Null => self.is_null = true,
+			Value::Bool(_) => self.is_bool = true,
+			Value::Number(n) if n.is_f64() => self.is_number = true,
+			Value::Number(_) => self.is_integer = true,
+			Value::String(_) => self.is_string = true,
+			Value::Array(arr) => {
+				let items = self.array_items.get_or_insert(None);
+				for item in arr {
+					items.get_or_insert_with(Box::default).add(item);
+				}
+			}
+			Value::Object(map) => {
+				self.object_count += 1;
+				for (key, value) in map {
+					let (count, shape) = self.properties.entry(key.clone()).or_default();
+					*count += 1;
+					shape.add(value);
+				}
+			}
+		}
+	}
+
+	fn into_schema(self) -> Value {
+		let mut types = Vec::new();
+		if self.object_count > 0 {
+			types.push("object");
+		}
+		if self.array_items.is_some() {
+			types.push("array");
+		}
+		if self.is_string {
+			types.push("string");
+		}
+		// an integer is a number, so only the more general one is reported
+		if self.is_number {
+			types.push("number");
+		} else if self.is_integer {
+			types.push("integer");
+		}
+		if self.is_bool {
+			types.push("boolean");
+		}
+		if self.is_null {
+			types.push("null");
+		}
+
+		let mut schema = Map::new();
+		match types.as_slice() {
+			[] => (),
+			[single] => {
+				schema.insert("type".to_string(), json!(single));
+			}
+			_ => {
+				schema.insert("type".to_string(), json!(types));
+			}
+		}
+
+		if self.object_count > 0 {
+			let object_count = self.object_count;
+			let mut properties = Map::new();
+			let mut required = Vec::new();
+			for (key, (count, shape)) in self.properties {
+				if count == object_count {
+					required.push(Value::String(key.clone()));
+				}
+				properties.insert(key, shape.into_schema());
+			}
+			schema.insert("properties".to_string(), Value::Object(properties));
+			if !required.is_empty() {
+				schema.insert("required".to_string(), Value::Array(required));
+			}
+		}
+
+		if let Some(Some(items)) = self.array_items {
+			schema.insert("items".to_string(), items.into_schema());
+		}
+
+		Value::Object(schema)
+	}
+}
+
+// endregion: --- Infer
+
+// region:    --- Coerce
+
+/// Coerces `value` in place to conform to `schema` (best effort), and returns the number of coerced values.
+pub(crate) fn coerce_to_schema(value: &mut Value, schema: &Value) -> usize {
+	coerce_at(value, schema, schema)
+}
+
+fn coerce_at(value: &mut Value, schema: &Value, root: &Value) -> usize {
+	let schema_value = resolve_ref(schema, root);
+	let Some(schema) = schema_value.as_object() else {
+		return 0;
+	};
+	let mut count = 0;
+
+	// -- Compositions
+	if let Some(sub_schemas) = schema.get("allOf").and_then(Value::as_array) {
+		for sub_schema in sub_schemas {
+			count += coerce_at(value, sub_schema, root);
+		}
+	}
+	let alternatives = schema
+		.get("anyOf")
+		.or_else(|| schema.get("oneOf"))
+		.and_then(Value::as_array);
+	if let Some(alternatives) = alternatives {
+		// the first alternative the value already matches, or else, the first one it can be coerced to
+		let matching = alternatives
+			.iter()
+			.find(|alt| schema_types(resolve_ref(alt, root)).map_or(true, |types| matches_any(value, &types)))
+			.or_else(|| {
+				alternatives.iter().find(|alt| {
+					let types = schema_types(resolve_ref(alt, root)).unwrap_or_default();
+					coerced_to_any(value, &types).is_some()
+				})
+			});
+		if let Some(alt) = matching {
+			count += coerce_at(value, alt, root);
+		}
+	}
+
+	// -- The value itself
+	if let Some(types) = schema_types(schema_value) {
+		if !matches_any(value, &types) {
+			if let Some(coerced) = coerced_to_any(value, &types) {
+				*value = coerced;
+				count += 1;
+			}
+		}
+	}
+
+	// -- The children
+	match value {
+		Value::Object(map) => {
+			let properties = schema.get("properties").and_then(Value::as_object);
+			let additional = schema.get("additionalProperties").filter(|s| s.is_object());
+			for (key, child) in map.iter_mut() {
+				if let Some(child_schema) = properties.and_then(|properties| properties.get(key)).or(additional) {
+					count += coerce_at(child, child_schema, root);
+				}
+			}
+		}
+		Value::Array(arr) => {
+			let prefix_items = schema.get("prefixItems").and_then(Value::as_array);
+			let items = schema.get("items").filter(|s| s.is_object());
+			for (idx, child) in arr.iter_mut().enumerate() {
+				if let Some(child_schema) = prefix_items.and_then(|prefix_items| prefix_items.get(idx)).or(items) {
+					count += coerce_at(child, child_schema, root);
+				}
+			}
+		}
+		_ => (),
+	}
+
+	count
+}
+
+/// Resolves a local `$ref` (e.g., `#/$defs/user`) against the root schema (or returns the schema as is).
+fn resolve_ref<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+	match schema.get("$ref").and_then(Value::as_str) {
+		Some(reference) => reference
+			.strip_prefix('#')
+			.and_then(|pointer| root.pointer(pointer))
+			.unwrap_or(schema),
+		None => schema,
+	}
+}
+
+/// Returns the `type` names of the schema (`None` when the schema does not constrain the type).
+fn schema_types(schema: &Value) -> Option<Vec<&str>> {
+	match schema.get("type")? {
+		Value::String(name) => Some(vec![name.as_str()]),
+		Value::Array(names) => Some(names.iter().filter_map(Value::as_str).collect()),
+		_ => None,
+	}
+}
+
+fn matches_any(value: &Value, types: &[&str]) -> bool {
+	types.iter().any(|name| matches_type(value, name))
+}
+
+fn matches_type(value: &Value, type_name: &str) -> bool {
+	match (type_name, value) {
+		("null", Value::Null) | ("boolean", Value::Bool(_)) | ("number", Value::Number(_)) => true,
+		("string", Value::String(_)) | ("array", Value::Array(_)) | ("object", Value::Object(_)) => true,
+		("integer", Value::Number(n)) => n.is_i64() || n.is_u64(),
+		_ => false,
+	}
+}
+
+/// Returns the value converted to the first of `types` it can be converted to.
+/// - Scalars use the `x_get_lenient` conversions (e.g., `"42"` -> `42`, `1` -> `true`, `3` -> `"3"`).
+/// - A string holding a JSON object or array is parsed (for `object` and `array`).
+/// - Any other non null value is wrapped in a one element array (for `array`).
+fn coerced_to_any(value: &Value, types: &[&str]) -> Option<Value> {
+	let candidates = coerce_candidates(value);
+
+	types.iter().find_map(|&type_name| match type_name {
+		"object" | "array" => {
+			let parsed = value
+				.as_str()
+				.and_then(|s| serde_json::from_str::<Value>(s).ok())
+				.filter(|parsed| matches_type(parsed, type_name));
+			match parsed {
+				Some(parsed) => Some(parsed),
+				None if type_name == "array" && !value.is_null() => Some(Value::Array(vec![value.clone()])),
+				None => None,
+			}
+		}
+		_ => candidates
+			.iter()
+			.find(|candidate| matches_type(candidate, type_name))
+			.cloned(),
+	})
+}
+
+// endregion: --- Coerce
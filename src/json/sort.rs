@@ -0,0 +1,87 @@
+use crate::json::array::array_mut;
+use crate::json::json_value_ext::get_value;
+use crate::{JsonKind, JsonValueExtError};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// The sort order of a key of `JsonValueExt::x_sort_array_by`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Order {
+	#[default]
+	Asc,
+	Desc,
+}
+
+/// Reorders (recursively) the keys of all the objects of `value` per `compare`.
+pub(crate) fn sort_keys_by<F>(value: &mut Value, compare: &mut F)
+where
+	F: FnMut(&str, &str) -> Ordering,
+{
+	match value {
+		Value::Object(map) => {
+			let mut entries: Vec<_> = std::mem::take(map).into_iter().collect();
+			entries.sort_by(|(a, _), (b, _)| compare(a, b));
+			for (key, mut item) in entries {
+				sort_keys_by(&mut item, compare);
+				map.insert(key, item);
+			}
+		}
+		Value::Array(arr) => {
+			for item in arr {
+				sort_keys_by(item, compare);
+			}
+		}
+		_ => (),
+	}
+}
+
+/// Sorts (stable) the elements of the array at `array_path` by their values at the key paths, the first key first.
+pub(crate) fn sort_array_by(
+	root: &mut Value,
+	array_path: &str,
+	keys: &[(&str, Order)],
+) -> Result<(), JsonValueExtError> {
+	let arr = array_mut(root, array_path, false)?;
+	arr.sort_by(|a, b| {
+		keys.iter()
+			.map(|(key_path, order)| {
+				let ordering = compare_values(get_value(a, key_path), get_value(b, key_path));
+				match order {
+					Order::Asc => ordering,
+					Order::Desc => ordering.reverse(),
+				}
+			})
+			.find(|ordering| ordering.is_ne())
+			.unwrap_or(Ordering::Equal)
+	});
+	Ok(())
+}
+
+// region:    --- Support
+
+/// Returns the ordering of two (eventually missing) values, the missing ones first, and then by `JsonKind`.
+/// - The numbers are compared as `f64`, the strings lexicographically, and the arrays element by element.
+/// - The objects are all equal.
+fn compare_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+	let (a, b) = match (a, b) {
+		(Some(a), Some(b)) => (a, b),
+		(a, b) => return a.is_some().cmp(&b.is_some()),
+	};
+	match (a, b) {
+		(Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+		(Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64()) {
+			(Some(a), Some(b)) => a.cmp(&b),
+			_ => a.as_f64().partial_cmp(&b.as_f64()).unwrap_or(Ordering::Equal),
+		},
+		(Value::String(a), Value::String(b)) => a.cmp(b),
+		(Value::Array(a), Value::Array(b)) => a
+			.iter()
+			.zip(b)
+			.map(|(a, b)| compare_values(Some(a), Some(b)))
+			.find(|ordering| ordering.is_ne())
+			.unwrap_or_else(|| a.len().cmp(&b.len())),
+		_ => JsonKind::of(a).cmp(&JsonKind::of(b)),
+	}
+}
+
+// endregion: --- Support
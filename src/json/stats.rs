@@ -0,0 +1,75 @@
+use serde_json::Value;
+use std::mem::size_of;
+
+/// The structural statistics of a JSON value tree (see `JsonValueExt::x_stats`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonStats {
+	// -- Node counts by kind
+	pub nulls: usize,
+	pub bools: usize,
+	pub numbers: usize,
+	pub strings: usize,
+	pub arrays: usize,
+	pub objects: usize,
+
+	/// The max number of nested arrays and objects (`0` for a scalar, `1` for `{"a": 1}`).
+	pub max_depth: usize,
+	/// The total length (in bytes) of the string values.
+	pub string_bytes: usize,
+	/// The total length (in bytes) of the object keys.
+	pub key_bytes: usize,
+	/// The length of the largest array.
+	pub max_array_len: usize,
+	/// The estimated heap size (in bytes) of the value tree (the string and array lengths, plus the map entries).
+	/// It does not account for the allocator overhead or the spare capacities.
+	pub estimated_heap_bytes: usize,
+}
+
+impl JsonStats {
+	/// Returns the total number of nodes (all kinds).
+	pub fn nodes(&self) -> usize {
+		self.nulls + self.bools + self.numbers + self.strings + self.arrays + self.objects
+	}
+}
+
+/// Returns the statistics of the `value` tree.
+pub(crate) fn stats(value: &Value) -> JsonStats {
+	let mut stats = JsonStats::default();
+	add_stats(&mut stats, value, 0);
+	stats
+}
+
+// region:    --- Support
+
+fn add_stats(stats: &mut JsonStats, value: &Value, depth: usize) {
+	match value {
+		Value::Null => stats.nulls += 1,
+		Value::Bool(_) => stats.bools += 1,
+		Value::Number(_) => stats.numbers += 1,
+		Value::String(s) => {
+			stats.strings += 1;
+			stats.string_bytes += s.len();
+			stats.estimated_heap_bytes += s.len();
+		}
+		Value::Array(arr) => {
+			stats.arrays += 1;
+			stats.max_depth = stats.max_depth.max(depth + 1);
+			stats.max_array_len = stats.max_array_len.max(arr.len());
+			stats.estimated_heap_bytes += arr.len() * size_of::<Value>();
+			for item in arr {
+				add_stats(stats, item, depth + 1);
+			}
+		}
+		Value::Object(map) => {
+			stats.objects += 1;
+			stats.max_depth = stats.max_depth.max(depth + 1);
+			for (key, item) in map {
+				stats.key_bytes += key.len();
+				stats.estimated_heap_bytes += size_of::<String>() + key.len() + size_of::<Value>();
+				add_stats(stats, item, depth + 1);
+			}
+		}
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,168 @@
+//! Extraction from JSON streams, without building the full `Value`.
+
+use crate::json::json_path::escape_token;
+use crate::json::pointer::token_index;
+use crate::{JsonPointer, JsonValueExtError};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use std::io::{BufReader, Read};
+
+/// Returns the value (as an owned type `T`, e.g., `Value`) at the JSON Pointer path of the JSON document read
+/// from `reader`, without building the full document.
+/// - The other subtrees are skipped (parsed, but not allocated), and the reading stops right after the target,
+///   so pulling one field out of a large file only reads it up to that field.
+/// - Returns `InvalidPath` for an invalid pointer, `PropertyNotFound` if the document has no value at the pointer,
+///   and `SerdeJson` for the read, syntax, and `T` deserialization errors.
+///
+/// ```rust
+/// let file = File::open("big.json")?;
+/// let items: Vec<Item> = stream::extract_pointer(file, "/data/items")?;
+/// ```
+pub fn extract_pointer<T: DeserializeOwned>(reader: impl Read, pointer: &str) -> Result<T, JsonValueExtError> {
+	let pointer = JsonPointer::parse(pointer)?;
+	let mut search = Search {
+		found: None,
+		depth: 0,
+		stopped: false,
+	};
+
+	let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+	let res = Seek {
+		tokens: pointer.tokens(),
+		search: &mut search,
+	}
+	.deserialize(&mut deserializer);
+
+	match (search.found, res) {
+		(Some(value), _) => Ok(value),
+		// the error is our early stop, when not found
+		(None, Err(_)) if search.stopped => Err(not_found(&pointer, search.depth)),
+		(None, Err(err)) => Err(err.into()),
+		// not reachable, the search always stops
+		(None, Ok(())) => Err(not_found(&pointer, search.depth)),
+	}
+}
+
+// region:    --- Support
+
+/// The message of the error stopping the parsing once the search is over.
+const STOP: &str = "value-ext stream search stopped";
+
+struct Search<T> {
+	found: Option<T>,
+	/// The number of tokens resolved.
+	depth: usize,
+	/// `true` once the search is over (found or not), the parsing being stopped with a `STOP` error.
+	stopped: bool,
+}
+
+impl<T> Search<T> {
+	fn stop<E: de::Error>(&mut self) -> E {
+		self.stopped = true;
+		E::custom(STOP)
+	}
+}
+
+/// Seeks the `tokens` path in the value being deserialized.
+struct Seek<'a, T> {
+	tokens: &'a [String],
+	search: &'a mut Search<T>,
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for Seek<'_, T> {
+	type Value = ();
+
+	fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+		if self.tokens.is_empty() {
+			self.search.found = Some(T::deserialize(deserializer)?);
+			return Err(self.search.stop());
+		}
+		deserializer.deserialize_any(self)
+	}
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for Seek<'_, T> {
+	type Value = ();
+
+	fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.write_str("a JSON value")
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+		while let Some(key) = map.next_key::<String>()? {
+			if key == self.tokens[0] {
+				self.search.depth += 1;
+				return map.next_value_seed(Seek {
+					tokens: &self.tokens[1..],
+					search: self.search,
+				});
+			}
+			map.next_value::<IgnoredAny>()?;
+		}
+		Err(self.search.stop())
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+		let Some(index) = token_index(&self.tokens[0]) else {
+			return Err(self.search.stop());
+		};
+		for _ in 0..index {
+			if seq.next_element::<IgnoredAny>()?.is_none() {
+				return Err(self.search.stop());
+			}
+		}
+		self.search.depth += 1;
+		let seek = Seek {
+			tokens: &self.tokens[1..],
+			search: &mut *self.search,
+		};
+		match seq.next_element_seed(seek)? {
+			Some(()) => Ok(()),
+			None => {
+				self.search.depth -= 1;
+				Err(self.search.stop())
+			}
+		}
+	}
+
+	// -- The scalars have no properties, so the search stops there.
+
+	fn visit_bool<E: de::Error>(self, _v: bool) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+
+	fn visit_i64<E: de::Error>(self, _v: i64) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+
+	fn visit_u64<E: de::Error>(self, _v: u64) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+
+	fn visit_f64<E: de::Error>(self, _v: f64) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+
+	fn visit_str<E: de::Error>(self, _v: &str) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+		Err(self.search.stop())
+	}
+}
+
+fn not_found(pointer: &JsonPointer, depth: usize) -> JsonValueExtError {
+	let tokens = pointer.tokens();
+	let resolved: String = tokens[..depth]
+		.iter()
+		.map(|token| format!("/{}", escape_token(token)))
+		.collect();
+	JsonValueExtError::PropertyNotFound {
+		name: pointer.to_string(),
+		resolved,
+		missing: tokens.get(depth).cloned().unwrap_or_default(),
+		suggestions: Vec::new(),
+	}
+}
+
+// endregion: --- Support
@@ -0,0 +1,167 @@
+use crate::json::json_value_ext::get_value;
+use crate::json::walk::child_path;
+use crate::JsonValueExtError;
+use serde_json::Value;
+
+/// Options for `JsonValueExt::x_render_template_with`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateOptions {
+	pub missing: MissingVar,
+}
+
+/// What to do with a `${...}` reference not found in the context.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingVar {
+	/// A `TemplateVarNotFound` error is returned (default).
+	#[default]
+	Error,
+	/// The reference is replaced with an empty string.
+	Empty,
+	/// The reference is kept as is (e.g., for a later render).
+	Keep,
+}
+
+impl TemplateOptions {
+	pub fn with_missing(mut self, missing: MissingVar) -> Self {
+		self.missing = missing;
+		self
+	}
+}
+
+/// Substitutes the `${path}` references of all the string leaves of `value` with their values in `ctx`,
+/// and returns the number of substituted references.
+/// - With `MissingVar::Error`, it renders a copy swapped in once all the references got resolved,
+///   so an error leaves `value` unchanged.
+pub(crate) fn render_template(
+	value: &mut Value,
+	ctx: &Value,
+	options: &TemplateOptions,
+) -> Result<usize, JsonValueExtError> {
+	let mut count = 0;
+	if options.missing == MissingVar::Error {
+		let mut rendered = value.clone();
+		render_at(&mut rendered, ctx, options, "", &mut count)?;
+		*value = rendered;
+	} else {
+		render_at(value, ctx, options, "", &mut count)?;
+	}
+	Ok(count)
+}
+
+/// A part of a string scanned for the `${...}` references.
+pub(crate) enum Segment<'a> {
+	Text(&'a str),
+	/// The content of a `${...}` reference.
+	Ref(&'a str),
+}
+
+/// Splits the `text` in its text and `${...}` reference segments, where `$${` is an escaped (literal) `${`,
+/// and an unterminated `${` is text.
+pub(crate) fn scan_refs(text: &str) -> Vec<Segment<'_>> {
+	let mut segments = Vec::new();
+	let mut rest = text;
+	while let Some(start) = rest.find("${") {
+		// `$${` is the literal `${`
+		if rest[..start].ends_with('$') {
+			segments.push(Segment::Text(&rest[..start - 1]));
+			segments.push(Segment::Text("${"));
+			rest = &rest[start + 2..];
+			continue;
+		}
+		let Some(len) = rest[start + 2..].find('}') else {
+			break;
+		};
+		if start > 0 {
+			segments.push(Segment::Text(&rest[..start]));
+		}
+		segments.push(Segment::Ref(&rest[start + 2..start + 2 + len]));
+		rest = &rest[start + 2 + len + 1..];
+	}
+	if !rest.is_empty() {
+		segments.push(Segment::Text(rest));
+	}
+	segments
+}
+
+// region:    --- Support
+
+fn render_at(
+	value: &mut Value,
+	ctx: &Value,
+	options: &TemplateOptions,
+	path: &str,
+	count: &mut usize,
+) -> Result<(), JsonValueExtError> {
+	match value {
+		Value::Object(map) => {
+			for (key, item) in map.iter_mut() {
+				render_at(item, ctx, options, &child_path(path, key), count)?;
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, item) in arr.iter_mut().enumerate() {
+				render_at(item, ctx, options, &child_path(path, &idx.to_string()), count)?;
+			}
+		}
+		Value::String(text) if text.contains("${") => {
+			if let Some(rendered) = render_string(text, ctx, options, path, count)? {
+				*value = rendered;
+			}
+		}
+		_ => (),
+	}
+	Ok(())
+}
+
+/// Returns the rendered value of the string leaf at `path`, `None` if unchanged.
+fn render_string(
+	text: &str,
+	ctx: &Value,
+	options: &TemplateOptions,
+	path: &str,
+	count: &mut usize,
+) -> Result<Option<Value>, JsonValueExtError> {
+	let segments = scan_refs(text);
+
+	// -- A string with only one reference gets the referenced value as is (e.g., a number)
+	if let [Segment::Ref(var)] = segments[..] {
+		if let Some(var_value) = get_value(ctx, var.trim()) {
+			*count += 1;
+			return Ok(Some(var_value.clone()));
+		}
+	}
+
+	let mut rendered = String::with_capacity(text.len());
+	for segment in segments {
+		match segment {
+			Segment::Text(text) => rendered.push_str(text),
+			Segment::Ref(var) => match get_value(ctx, var.trim()) {
+				Some(Value::String(var_value)) => {
+					*count += 1;
+					rendered.push_str(var_value);
+				}
+				Some(var_value) => {
+					*count += 1;
+					rendered.push_str(&var_value.to_string());
+				}
+				None => match options.missing {
+					MissingVar::Error => {
+						return Err(JsonValueExtError::TemplateVarNotFound {
+							path: path.to_string(),
+							var: var.trim().to_string(),
+						})
+					}
+					MissingVar::Empty => (),
+					MissingVar::Keep => {
+						rendered.push_str("${");
+						rendered.push_str(var);
+						rendered.push('}');
+					}
+				},
+			},
+		}
+	}
+	Ok((rendered != text).then_some(Value::String(rendered)))
+}
+
+// endregion: --- Support
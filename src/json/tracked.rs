@@ -0,0 +1,815 @@
+use crate::json::json_path::{escape_token, path_tokens, to_pointer};
+use crate::json::json_value_ext::{from_value_with_context, get_value, get_value_mut, remove_value};
+use crate::json::order::{insert_key_at, remove_key};
+use crate::json::patch::{apply_operation, apply_patch};
+use crate::json::pointer::token_index;
+use crate::json::relocate::split_parent;
+use crate::{
+	AsType, AsTypeMut, Case, CopyMode, DedupKeep, DiffOptions, DiffReport, Entries, EntriesMut, EnvOptions, EnvReport,
+	EqOptions, FlattenOptions, JsonEntry, JsonKind, JsonObjectBuilder, JsonPointer, JsonStats, JsonValueExt,
+	JsonValueExtError, JsonVisitor, Leaves, LeavesMut, MergeOptions, Order, Patch, PatchOperation, Paths,
+	PrettyOptions, PruneOptions, RedactRules, SaveOptions, TemplateOptions, WalkControl, WalkOrder,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+#[cfg(feature = "raw_value")]
+use serde_json::value::RawValue;
+use serde_json::{Map, Number, Value};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+#[cfg(feature = "tokio")]
+use std::future::Future;
+use std::hash::Hasher;
+use std::ops::Deref;
+use std::path::Path;
+
+type Result<T> = core::result::Result<T, JsonValueExtError>;
+
+/// A JSON value implementing `JsonValueExt`, and recording its mutations as a RFC 6902 JSON Patch (see `changes()`),
+/// e.g., for audit trails, or to replicate the edits.
+///
+/// The mutations are recorded as:
+/// - `x_insert`: a `replace` of an existing value, or an `add` of the new value (at the first created parent).
+/// - `x_take`: a `replace` with `null`. `x_remove`: a `remove`.
+/// - The other mutating methods (e.g., `x_merge`, `x_walk`): the `x_diff` of the value before and after the call.
+/// - The methods handing out `&mut` references (`x_entry`, `x_get_as_mut`, `x_get_or_insert_with`, `x_leaves_mut`,
+///   and `x_entries_mut`): the `x_diff` of their edits, recorded by the next `TrackedValue` call taking `&mut self`
+///   (e.g., `changes()`).
+///
+/// Notes:
+/// - The key order changes (e.g., `x_sort_keys`) are not recorded, as they do not change the value.
+/// - It derefs to `&Value`, but not to `&mut Value`, so all its mutations get recorded.
+///
+/// The mutations can be undone and redone (`undo()` / `redo()`), applying their inverse (or original) operations,
+/// up to the last `history_depth` ones (default `DEFAULT_HISTORY_DEPTH`, see `with_history_depth`).
+/// The undos and redos are recorded in the `changes()` too, so the replicas stay in sync.
+///
+/// ```rust
+/// let mut value = TrackedValue::new(json!({"user": {"name": "Jen"}}));
+/// value.x_insert("/user/name", "Mike")?;
+/// value.x_insert("/user/tags", json!(["admin"]))?;
+/// // [{"op": "replace", "path": "/user/name", "value": "Mike"}, {"op": "add", "path": "/user/tags", "value": ["admin"]}]
+/// let patch = value.take_changes();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedValue {
+	value: Value,
+	changes: Patch,
+	/// The undoable edits, the most recent last.
+	undos: VecDeque<Edit>,
+	/// The undone edits, the most recently undone last.
+	redos: Vec<Edit>,
+	history_depth: usize,
+	/// The value before a `&mut` reference got handed out, to record its edits on the next call.
+	pending: Option<Value>,
+}
+
+/// The default max number of undoable edits of a `TrackedValue`.
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+impl TrackedValue {
+	pub fn new(value: Value) -> Self {
+		Self {
+			value,
+			changes: Patch::default(),
+			undos: VecDeque::new(),
+			redos: Vec::new(),
+			history_depth: DEFAULT_HISTORY_DEPTH,
+			pending: None,
+		}
+	}
+
+	/// Sets the max number of undoable (and redoable) edits, the oldest ones being dropped past it, `0` to disable the undos.
+	pub fn with_history_depth(mut self, history_depth: usize) -> Self {
+		self.flush();
+		self.history_depth = history_depth;
+		while self.undos.len() > history_depth {
+			self.undos.pop_front();
+		}
+		// the first undone edits are the farthest ones to redo
+		let extra_redos = self.redos.len().saturating_sub(history_depth);
+		self.redos.drain(..extra_redos);
+		self
+	}
+
+	/// Returns the recorded changes, since the creation or the last `take_changes`.
+	pub fn changes(&mut self) -> &Patch {
+		self.flush();
+		&self.changes
+	}
+
+	/// Returns the recorded changes, and starts a new (empty) change log.
+	pub fn take_changes(&mut self) -> Patch {
+		self.flush();
+		std::mem::take(&mut self.changes)
+	}
+
+	/// Returns the value (the recorded changes are dropped).
+	pub fn into_inner(self) -> Value {
+		self.value
+	}
+
+	/// Returns `true` if there is an edit to undo.
+	pub fn can_undo(&self) -> bool {
+		!self.undos.is_empty() || (self.history_depth > 0 && self.has_pending_edit())
+	}
+
+	/// Returns `true` if there is an undone edit to redo.
+	pub fn can_redo(&self) -> bool {
+		!self.redos.is_empty() && !self.has_pending_edit()
+	}
+
+	/// Undoes the last edit (applying its inverse operations), and returns `false` if there was none.
+	pub fn undo(&mut self) -> Result<bool> {
+		self.flush();
+		let Some(edit) = self.undos.pop_back() else {
+			return Ok(false);
+		};
+		if let Err(err) = self.apply(&edit.inverses, edit.position) {
+			self.undos.push_back(edit);
+			return Err(err);
+		}
+		self.redos.push(edit);
+		Ok(true)
+	}
+
+	/// Redoes the last undone edit (applying its operations again), and returns `false` if there was none.
+	/// - A new edit (e.g., `x_insert`) clears the edits to redo.
+	pub fn redo(&mut self) -> Result<bool> {
+		self.flush();
+		let Some(edit) = self.redos.pop() else {
+			return Ok(false);
+		};
+		if let Err(err) = self.apply(&edit.operations, None) {
+			self.redos.push(edit);
+			return Err(err);
+		}
+		self.push_undo(edit);
+		Ok(true)
+	}
+
+	/// Records a new edit, in the changes and the undo history.
+	fn record(&mut self, edit: Edit) {
+		for operation in &edit.operations {
+			self.changes.push(operation.clone());
+		}
+		self.redos.clear();
+		self.push_undo(edit);
+	}
+
+	/// Runs the mutation `f` on the value, and records its diff.
+	fn record_with<R>(&mut self, f: impl FnOnce(&mut Value) -> R) -> R {
+		self.flush();
+		let before = self.value.clone();
+		let res = f(&mut self.value);
+		self.record_diff(before);
+		res
+	}
+
+	/// Records the diff from `before` to the current value, if any.
+	fn record_diff(&mut self, before: Value) {
+		let operations = before.x_diff(&self.value);
+		if operations.is_empty() {
+			return;
+		}
+		let inverses = self.value.x_diff(&before);
+		self.record(Edit {
+			operations,
+			inverses,
+			position: None,
+		});
+	}
+
+	/// Hands out the value to a method returning a `&mut` reference, its edits being recorded on the next `flush`.
+	fn lend(&mut self) -> &mut Value {
+		self.flush();
+		self.pending = Some(self.value.clone());
+		&mut self.value
+	}
+
+	/// Records the edits made through the last handed out `&mut` reference, if any.
+	fn flush(&mut self) {
+		if let Some(before) = self.pending.take() {
+			self.record_diff(before);
+		}
+	}
+
+	/// Returns `true` if the last handed out `&mut` reference changed the value.
+	fn has_pending_edit(&self) -> bool {
+		self.pending.as_ref().is_some_and(|before| *before != self.value)
+	}
+
+	/// Pushes an undoable edit, dropping the oldest one at the `history_depth`.
+	fn push_undo(&mut self, edit: Edit) {
+		if self.history_depth > 0 {
+			if self.undos.len() == self.history_depth {
+				self.undos.pop_front();
+			}
+			self.undos.push_back(edit);
+		}
+	}
+
+	/// Applies the undo or redo `operations` (recorded in the changes), with the key `position` of a re-added property.
+	fn apply(&mut self, operations: &Patch, position: Option<usize>) -> Result<()> {
+		match operations.operations() {
+			[operation] => apply_operation(&mut self.value, operation)?,
+			_ => apply_patch(&mut self.value, operations)?,
+		}
+		if let ([PatchOperation::Add { path, .. }], Some(position)) = (operations.operations(), position) {
+			let (parent_pointer, key) = split_parent(path);
+			if let Some(Value::Object(map)) = self.value.pointer_mut(parent_pointer) {
+				if let Some(value) = remove_key(map, &key) {
+					insert_key_at(map, position, key, value);
+				}
+			}
+		}
+		for operation in operations {
+			self.changes.push(operation.clone());
+		}
+		Ok(())
+	}
+}
+
+impl JsonValueExt for TrackedValue {
+	fn x_new_object() -> Value {
+		Value::x_new_object()
+	}
+
+	fn x_builder() -> JsonObjectBuilder {
+		Value::x_builder()
+	}
+
+	fn x_contains(&self, name_or_pointer: &str) -> bool {
+		self.value.x_contains(name_or_pointer)
+	}
+
+	fn x_is_null(&self, name_or_pointer: &str) -> bool {
+		self.value.x_is_null(name_or_pointer)
+	}
+
+	fn x_kind(&self) -> JsonKind {
+		self.value.x_kind()
+	}
+
+	fn x_len(&self) -> Option<usize> {
+		self.value.x_len()
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		self.value.x_get(name_or_pointer)
+	}
+
+	fn x_get_opt<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<Option<T>> {
+		self.value.x_get_opt(name_or_pointer)
+	}
+
+	fn x_get_lenient<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		self.value.x_get_lenient(name_or_pointer)
+	}
+
+	fn x_get_all<T: DeserializeOwned>(&self, pointer: &str) -> Result<Vec<(String, T)>> {
+		self.value.x_get_all(pointer)
+	}
+
+	#[cfg(feature = "raw_value")]
+	fn x_get_raw(&self, name_or_pointer: &str) -> Result<Box<RawValue>> {
+		self.value.x_get_raw(name_or_pointer)
+	}
+
+	fn x_get_pointer<T: DeserializeOwned>(&self, pointer: &JsonPointer) -> Result<T> {
+		self.value.x_get_pointer(pointer)
+	}
+
+	fn x_get_as<'a, T: AsType<'a>>(&'a self, name_or_pointer: &str) -> Result<T> {
+		self.value.x_get_as(name_or_pointer)
+	}
+
+	/// Recorded by the next `&mut self` call (see the `TrackedValue` doc).
+	fn x_get_as_mut<'a, T: AsTypeMut<'a>>(&'a mut self, name_or_pointer: &str) -> Result<T> {
+		self.lend().x_get_as_mut(name_or_pointer)
+	}
+
+	/// Recorded as a `replace` with `null`.
+	/// - A type error leaves the value unchanged, and records nothing.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		self.flush();
+		let value = get_value(&self.value, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(&self.value, name_or_pointer))?;
+		let taken: T = from_value_with_context(name_or_pointer, value)?;
+
+		let value = get_value_mut(&mut self.value, name_or_pointer)
+			.map(Value::take)
+			.unwrap_or_default();
+		let path = to_pointer(name_or_pointer);
+		self.record(Edit {
+			operations: Patch::new(vec![PatchOperation::Replace {
+				path: path.clone(),
+				value: Value::Null,
+			}]),
+			inverses: Patch::new(vec![PatchOperation::Replace { path, value }]),
+			position: None,
+		});
+		Ok(taken)
+	}
+
+	fn x_take_opt<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<Option<T>> {
+		self.record_with(|value| value.x_take_opt(name_or_pointer))
+	}
+
+	fn x_take_pointer<T: DeserializeOwned>(&mut self, pointer: &JsonPointer) -> Result<T> {
+		self.record_with(|value| value.x_take_pointer(pointer))
+	}
+
+	/// Recorded as a `remove`.
+	/// - A type error leaves the value unchanged, and records nothing.
+	fn x_remove<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		self.flush();
+		let value = get_value(&self.value, name_or_pointer)
+			.ok_or_else(|| JsonValueExtError::property_not_found(&self.value, name_or_pointer))?;
+		let removed: T = from_value_with_context(name_or_pointer, value)?;
+
+		let path = to_pointer(name_or_pointer);
+		let position = key_position(&self.value, &path);
+		let value = remove_value(&mut self.value, name_or_pointer).unwrap_or_default();
+		self.record(Edit {
+			operations: Patch::new(vec![PatchOperation::Remove { path: path.clone() }]),
+			inverses: Patch::new(vec![PatchOperation::Add { path, value }]),
+			position,
+		});
+		Ok(removed)
+	}
+
+	fn x_take_prune<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		self.record_with(|value| value.x_take_prune(name_or_pointer))
+	}
+
+	fn x_rename(&mut self, name_or_pointer: &str, new_key: &str) -> Result<()> {
+		self.record_with(|value| value.x_rename(name_or_pointer, new_key))
+	}
+
+	fn x_move(&mut self, from: &str, to: &str) -> Result<()> {
+		self.record_with(|value| value.x_move(from, to))
+	}
+
+	fn x_copy_with(&mut self, from: &str, to: &str, mode: CopyMode) -> Result<()> {
+		self.record_with(|value| value.x_copy_with(from, to, mode))
+	}
+
+	#[cfg(feature = "preserve_order")]
+	fn x_insert_at_position<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()> {
+		self.record_with(|root| root.x_insert_at_position(name_or_pointer, index, value))
+	}
+
+	#[cfg(feature = "preserve_order")]
+	fn x_move_key_before(&mut self, name_or_pointer: &str, before: &str) -> Result<()> {
+		self.record_with(|value| value.x_move_key_before(name_or_pointer, before))
+	}
+
+	/// Recorded as a `replace` of an existing value, or an `add` of the new value (at the first created parent,
+	/// with the created subtree, when the parents are missing).
+	/// - The array appends (`/-`) are recorded with the actual index.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		self.flush();
+		let new_value = serde_json::to_value(value)?;
+		let added_pointer = added_pointer(&self.value, name_or_pointer);
+		let old_value = match added_pointer {
+			Some(_) => None,
+			None => self.value.pointer(&to_pointer(name_or_pointer)).cloned(),
+		};
+		self.value.x_insert(name_or_pointer, new_value)?;
+
+		let edit = match (added_pointer, old_value) {
+			(Some(path), _) => Edit {
+				operations: Patch::new(vec![PatchOperation::Add {
+					value: self.value.pointer(&path).cloned().unwrap_or_default(),
+					path: path.clone(),
+				}]),
+				inverses: Patch::new(vec![PatchOperation::Remove { path }]),
+				position: None,
+			},
+			(None, old_value) => {
+				let path = to_pointer(name_or_pointer);
+				Edit {
+					operations: Patch::new(vec![PatchOperation::Replace {
+						value: self.value.pointer(&path).cloned().unwrap_or_default(),
+						path: path.clone(),
+					}]),
+					inverses: Patch::new(vec![PatchOperation::Replace {
+						path,
+						value: old_value.unwrap_or_default(),
+					}]),
+					position: None,
+				}
+			}
+		};
+		self.record(edit);
+		Ok(())
+	}
+
+	fn x_push<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		self.record_with(|root| root.x_push(name_or_pointer, value))
+	}
+
+	fn x_group_by(&self, array_path: &str, key_path: &str) -> Result<Map<String, Value>> {
+		self.value.x_group_by(array_path, key_path)
+	}
+
+	fn x_dedup_by(&mut self, array_path: &str, key_path: &str, keep: DedupKeep) -> Result<usize> {
+		self.record_with(|value| value.x_dedup_by(array_path, key_path, keep))
+	}
+
+	fn x_extend<T: Serialize>(&mut self, name_or_pointer: &str, items: impl IntoIterator<Item = T>) -> Result<()> {
+		self.record_with(|value| value.x_extend(name_or_pointer, items))
+	}
+
+	fn x_insert_at<T: Serialize>(&mut self, name_or_pointer: &str, index: usize, value: T) -> Result<()> {
+		self.record_with(|root| root.x_insert_at(name_or_pointer, index, value))
+	}
+
+	fn x_remove_at<T: DeserializeOwned>(&mut self, name_or_pointer: &str, index: usize) -> Result<T> {
+		self.record_with(|value| value.x_remove_at(name_or_pointer, index))
+	}
+
+	/// Recorded by the next `&mut self` call (see the `TrackedValue` doc).
+	fn x_entry(&mut self, name_or_pointer: &str) -> JsonEntry<'_> {
+		self.lend().x_entry(name_or_pointer)
+	}
+
+	fn x_insert_pointer<T: Serialize>(&mut self, pointer: &JsonPointer, value: T) -> Result<()> {
+		self.record_with(|root| root.x_insert_pointer(pointer, value))
+	}
+
+	fn x_merge(&mut self, other: Value, options: MergeOptions) {
+		self.record_with(|value| value.x_merge(other, options))
+	}
+
+	fn x_diff_with(&self, other: &Value, options: DiffOptions) -> Patch {
+		self.value.x_diff_with(other, options)
+	}
+
+	fn x_apply_patch(&mut self, patch: &Patch) -> Result<()> {
+		self.record_with(|value| value.x_apply_patch(patch))
+	}
+
+	fn x_diff_report(&self, other: &Value) -> DiffReport {
+		self.value.x_diff_report(other)
+	}
+
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		self.record_with(|value| value.x_walk(callback))
+	}
+
+	fn x_walk_with_path<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Map<String, Value>, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		self.record_with(|value| value.x_walk_with_path(callback))
+	}
+
+	fn x_walk_mut_with<F, R>(&mut self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &mut Value) -> R,
+		R: Into<WalkControl>,
+	{
+		self.record_with(|value| value.x_walk_mut_with(order, callback))
+	}
+
+	fn x_walk_ref_with<F, R>(&self, order: WalkOrder, callback: F) -> bool
+	where
+		F: FnMut(&str, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		self.value.x_walk_ref_with(order, callback)
+	}
+
+	fn x_query(&self, query: &str) -> Result<Vec<&Value>> {
+		self.value.x_query(query)
+	}
+
+	fn x_query_mut<F>(&mut self, query: &str, callback: F) -> Result<usize>
+	where
+		F: FnMut(&mut Value),
+	{
+		self.record_with(|value| value.x_query_mut(query, callback))
+	}
+
+	#[cfg(feature = "rayon")]
+	fn x_par_walk_ref<F, R>(&self, callback: F) -> bool
+	where
+		F: Fn(&str, &Value) -> R + Sync,
+		R: Into<WalkControl>,
+	{
+		self.value.x_par_walk_ref(callback)
+	}
+
+	#[cfg(feature = "rayon")]
+	fn x_par_leaves(&self) -> Vec<(String, &Value)> {
+		self.value.x_par_leaves()
+	}
+
+	fn x_accept<V: JsonVisitor + ?Sized>(&self, visitor: &mut V) -> bool {
+		self.value.x_accept(visitor)
+	}
+
+	fn x_leaves(&self) -> Leaves<'_> {
+		self.value.x_leaves()
+	}
+
+	/// Recorded by the next `&mut self` call (see the `TrackedValue` doc).
+	fn x_leaves_mut(&mut self) -> LeavesMut<'_> {
+		self.lend().x_leaves_mut()
+	}
+
+	fn x_map_strings<F>(&mut self, f: F)
+	where
+		F: FnMut(&str, String) -> Value,
+	{
+		self.record_with(|value| value.x_map_strings(f))
+	}
+
+	fn x_map_numbers<F>(&mut self, f: F)
+	where
+		F: FnMut(&str, Number) -> Value,
+	{
+		self.record_with(|value| value.x_map_numbers(f))
+	}
+
+	fn x_replace_where<P, R>(&mut self, predicate: P, replacement: R) -> Vec<String>
+	where
+		P: FnMut(&str, &Value) -> bool,
+		R: FnMut(&str, &Value) -> Value,
+	{
+		self.record_with(|value| value.x_replace_where(predicate, replacement))
+	}
+
+	fn x_paths(&self) -> Paths<'_> {
+		self.value.x_paths()
+	}
+
+	fn x_find_first<F>(&self, predicate: F) -> Option<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool,
+	{
+		self.value.x_find_first(predicate)
+	}
+
+	fn x_find_all<F>(&self, predicate: F) -> Vec<(String, &Value)>
+	where
+		F: FnMut(&str, &Value) -> bool,
+	{
+		self.value.x_find_all(predicate)
+	}
+
+	fn x_entries(&self) -> Entries<'_> {
+		self.value.x_entries()
+	}
+
+	/// Recorded by the next `&mut self` call (see the `TrackedValue` doc).
+	fn x_entries_mut(&mut self) -> EntriesMut<'_> {
+		self.lend().x_entries_mut()
+	}
+
+	fn x_infer_schema(&self) -> Value {
+		self.value.x_infer_schema()
+	}
+
+	fn x_coerce_to_schema(&mut self, schema: &Value) -> usize {
+		self.record_with(|value| value.x_coerce_to_schema(schema))
+	}
+
+	fn x_equals(&self, other: &Value, options: EqOptions) -> bool {
+		self.value.x_equals(other, options)
+	}
+
+	fn x_contains_subset(&self, pattern: &Value) -> bool {
+		self.value.x_contains_subset(pattern)
+	}
+
+	fn x_flatten_with(&self, options: FlattenOptions) -> Map<String, Value> {
+		self.value.x_flatten_with(options)
+	}
+
+	fn x_unflatten(flat: Map<String, Value>) -> Result<Value> {
+		Value::x_unflatten(flat)
+	}
+
+	fn x_rename_keys_with(&mut self, case: Case, exclude: &[&str]) {
+		self.record_with(|value| value.x_rename_keys_with(case, exclude))
+	}
+
+	fn x_prune(&mut self, options: PruneOptions) -> usize {
+		self.record_with(|value| value.x_prune(options))
+	}
+
+	fn x_redact(&mut self, rules: &RedactRules) -> usize {
+		self.record_with(|value| value.x_redact(rules))
+	}
+
+	fn x_sort_keys_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&str, &str) -> Ordering,
+	{
+		self.record_with(|value| value.x_sort_keys_by(compare))
+	}
+
+	fn x_sort_array_by_keys(&mut self, array_path: &str, keys: &[(&str, Order)]) -> Result<()> {
+		self.record_with(|value| value.x_sort_array_by_keys(array_path, keys))
+	}
+
+	fn x_pick(&self, paths: &[&str]) -> Value {
+		self.value.x_pick(paths)
+	}
+
+	fn x_omit(&self, paths: &[&str]) -> Value {
+		self.value.x_omit(paths)
+	}
+
+	fn x_project(&self, mapping: &[(&str, &str)]) -> Result<Value> {
+		self.value.x_project(mapping)
+	}
+
+	fn x_render_template_with(&mut self, ctx: &Value, options: TemplateOptions) -> Result<usize> {
+		self.record_with(|value| value.x_render_template_with(ctx, options))
+	}
+
+	fn x_expand_env_with(&mut self, options: EnvOptions) -> EnvReport {
+		self.record_with(|value| value.x_expand_env_with(options))
+	}
+
+	fn x_stats(&self) -> JsonStats {
+		self.value.x_stats()
+	}
+
+	fn x_canonical(&self) -> String {
+		self.value.x_canonical()
+	}
+
+	fn x_hash(&self) -> u64 {
+		self.value.x_hash()
+	}
+
+	fn x_hash_with<H: Hasher>(&self, hasher: &mut H) {
+		self.value.x_hash_with(hasher)
+	}
+
+	#[cfg(feature = "toml")]
+	fn x_to_toml(&self) -> core::result::Result<toml::Value, crate::TomlValueExtError> {
+		self.value.x_to_toml()
+	}
+
+	#[cfg(feature = "toml")]
+	fn x_from_toml(value: &toml::Value) -> core::result::Result<Value, crate::TomlValueExtError> {
+		Value::x_from_toml(value)
+	}
+
+	#[cfg(feature = "yaml")]
+	fn x_to_yaml(&self) -> serde_yaml::Value {
+		self.value.x_to_yaml()
+	}
+
+	#[cfg(feature = "yaml")]
+	fn x_from_yaml(value: &serde_yaml::Value) -> core::result::Result<Value, crate::YamlValueExtError> {
+		Value::x_from_yaml(value)
+	}
+
+	fn x_pretty(&self) -> Result<String> {
+		self.value.x_pretty()
+	}
+
+	fn x_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()> {
+		self.value.x_to_writer(writer)
+	}
+
+	fn x_pretty_to_writer<W: std::io::Write>(&self, writer: W) -> Result<()> {
+		self.value.x_pretty_to_writer(writer)
+	}
+
+	fn x_append_jsonl(&self, path: impl AsRef<Path>) -> Result<()> {
+		self.value.x_append_jsonl(path)
+	}
+
+	fn x_load_file(path: impl AsRef<Path>) -> Result<Value> {
+		Value::x_load_file(path)
+	}
+
+	fn x_save_file_with(&self, path: impl AsRef<Path>, options: SaveOptions) -> Result<()> {
+		self.value.x_save_file_with(path, options)
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_load_file_async(path: impl AsRef<Path>) -> impl Future<Output = Result<Value>> + Send {
+		Value::x_load_file_async(path)
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_save_file_with_async(
+		&self,
+		path: impl AsRef<Path>,
+		options: SaveOptions,
+	) -> impl Future<Output = Result<()>> + Send {
+		self.value.x_save_file_with_async(path, options)
+	}
+
+	#[cfg(feature = "tokio")]
+	fn x_to_async_writer<W>(&self, writer: W) -> impl Future<Output = Result<()>> + Send
+	where
+		W: tokio::io::AsyncWrite + Unpin + Send,
+	{
+		self.value.x_to_async_writer(writer)
+	}
+
+	fn x_preview(&self, max_bytes: usize) -> String {
+		self.value.x_preview(max_bytes)
+	}
+
+	fn x_pretty_with(&self, options: PrettyOptions) -> String {
+		self.value.x_pretty_with(options)
+	}
+
+	#[cfg(feature = "color")]
+	fn x_pretty_colored(&self) -> String {
+		self.value.x_pretty_colored()
+	}
+}
+
+impl Default for TrackedValue {
+	fn default() -> Self {
+		Self::new(Value::default())
+	}
+}
+
+// region:    --- Froms & Deref
+
+impl Deref for TrackedValue {
+	type Target = Value;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl From<Value> for TrackedValue {
+	fn from(value: Value) -> Self {
+		Self::new(value)
+	}
+}
+
+impl From<TrackedValue> for Value {
+	fn from(tracked: TrackedValue) -> Self {
+		tracked.value
+	}
+}
+
+// endregion: --- Froms & Deref
+
+// region:    --- Support
+
+/// A recorded mutation, with its inverse operations.
+#[derive(Debug, Clone, PartialEq)]
+struct Edit {
+	operations: Patch,
+	/// The operations undoing the edit, applied in order.
+	inverses: Patch,
+	/// The position of the removed object property, to re-add it there on undo (with the `preserve_order` feature).
+	position: Option<usize>,
+}
+
+/// Returns the position of the property at the pointer in its parent object, `None` if not in an object.
+fn key_position(root: &Value, pointer: &str) -> Option<usize> {
+	let (parent_pointer, key) = split_parent(pointer);
+	root.pointer(parent_pointer)?
+		.as_object()?
+		.keys()
+		.position(|k| *k == key)
+}
+
+/// Returns the pointer of the first missing value of the path (the one `x_insert` adds), `None` if the path exists.
+/// A `-` (append) token is returned as the array len.
+fn added_pointer(root: &Value, name_or_pointer: &str) -> Option<String> {
+	let mut current = root;
+	let mut pointer = String::new();
+	for token in path_tokens(name_or_pointer) {
+		let next = match current {
+			Value::Object(map) => map.get(&token),
+			Value::Array(arr) => token_index(&token).and_then(|idx| arr.get(idx)),
+			_ => None,
+		};
+		pointer.push('/');
+		match current {
+			Value::Array(arr) if token == "-" => pointer.push_str(&arr.len().to_string()),
+			_ => pointer.push_str(&escape_token(&token)),
+		}
+		let Some(next) = next else {
+			return Some(pointer);
+		};
+		current = next;
+	}
+	None
+}
+
+// endregion: --- Support
@@ -0,0 +1,99 @@
+use crate::json::walk::child_path;
+use crate::WalkControl;
+use serde_json::{Map, Value};
+
+/// A visitor over a JSON value tree, driven by `JsonValueExt::x_accept`.
+///
+/// All hooks have a default implementation returning `WalkControl::Continue`, so an implementation
+/// only needs to override the hooks it cares about.
+///
+/// For each value (depth-first, in document order):
+/// - Object: `enter_object`, then for each property `visit_object_entry` (and the property value), then `leave_object`.
+/// - Array: `enter_array`, then for each element `visit_array_element` (and the element value), then `leave_array`.
+/// - Scalar: `visit_scalar`.
+///
+/// Returning `WalkControl::SkipChildren` from `enter_*` skips the entries/elements (the `leave_*` hook is still called),
+/// and from `visit_object_entry` / `visit_array_element` skips that value. `WalkControl::Stop` stops the traversal.
+///
+/// The `path` arguments are JSON pointer paths (empty for the root value).
+pub trait JsonVisitor {
+	fn enter_object(&mut self, _path: &str, _map: &Map<String, Value>) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	fn leave_object(&mut self, _path: &str, _map: &Map<String, Value>) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	fn enter_array(&mut self, _path: &str, _array: &[Value]) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	fn leave_array(&mut self, _path: &str, _array: &[Value]) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	/// Called for each property of an object, where `path` is the path of the property value.
+	fn visit_object_entry(&mut self, _path: &str, _key: &str, _value: &Value) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	/// Called for each element of an array, where `path` is the path of the element.
+	fn visit_array_element(&mut self, _path: &str, _index: usize, _value: &Value) -> WalkControl {
+		WalkControl::Continue
+	}
+
+	/// Called for each `Null`, `Bool`, `Number`, and `String` value.
+	fn visit_scalar(&mut self, _path: &str, _value: &Value) -> WalkControl {
+		WalkControl::Continue
+	}
+}
+
+/// Drives the visitor over the value. Returns `false` if the visitor stopped the traversal.
+pub(crate) fn accept<V: JsonVisitor + ?Sized>(path: &str, value: &Value, visitor: &mut V) -> bool {
+	match value {
+		Value::Object(map) => {
+			match visitor.enter_object(path, map) {
+				WalkControl::Stop => return false,
+				WalkControl::SkipChildren => (),
+				WalkControl::Continue => {
+					for (key, child) in map {
+						let entry_path = child_path(path, key);
+						match visitor.visit_object_entry(&entry_path, key, child) {
+							WalkControl::Stop => return false,
+							WalkControl::SkipChildren => (),
+							WalkControl::Continue => {
+								if !accept(&entry_path, child, visitor) {
+									return false;
+								}
+							}
+						}
+					}
+				}
+			}
+			visitor.leave_object(path, map) != WalkControl::Stop
+		}
+		Value::Array(arr) => {
+			match visitor.enter_array(path, arr) {
+				WalkControl::Stop => return false,
+				WalkControl::SkipChildren => (),
+				WalkControl::Continue => {
+					for (idx, child) in arr.iter().enumerate() {
+						let element_path = format!("{path}/{idx}");
+						match visitor.visit_array_element(&element_path, idx, child) {
+							WalkControl::Stop => return false,
+							WalkControl::SkipChildren => (),
+							WalkControl::Continue => {
+								if !accept(&element_path, child, visitor) {
+									return false;
+								}
+							}
+						}
+					}
+				}
+			}
+			visitor.leave_array(path, arr) != WalkControl::Stop
+		}
+		scalar => visitor.visit_scalar(path, scalar) != WalkControl::Stop,
+	}
+}
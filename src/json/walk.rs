@@ -0,0 +1,186 @@
+use crate::json::json_path::escape_token;
+use serde_json::{Map, Value};
+use std::collections::VecDeque;
+
+/// The traversal flow returned by the walk callbacks.
+///
+/// Callbacks can also return a `bool` (`true` for `Continue`, and `false` for `Stop`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalkControl {
+	/// Continue the traversal, including the children of the current value.
+	#[default]
+	Continue,
+	/// Continue the traversal, but do not visit the children of the current value.
+	/// (no effect in `WalkOrder::PostOrder`, as the children are already visited)
+	SkipChildren,
+	/// Stop the traversal.
+	Stop,
+}
+
+impl From<bool> for WalkControl {
+	fn from(continue_walk: bool) -> Self {
+		if continue_walk {
+			WalkControl::Continue
+		} else {
+			WalkControl::Stop
+		}
+	}
+}
+
+/// The depth-first traversal order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalkOrder {
+	/// A value is visited before its children.
+	#[default]
+	PreOrder,
+	/// A value is visited after its children (e.g., to safely collapse or remove subtrees from the bottom up).
+	PostOrder,
+}
+
+/// Breadth-first walk over all the properties, calling `callback(property_path, parent_map, property_name)`.
+///
+/// Returns `false` if the traversal was stopped early by the callback.
+pub(crate) fn walk_with_path<F, R>(root: &mut Value, mut callback: F) -> bool
+where
+	F: FnMut(&str, &mut Map<String, Value>, &str) -> R,
+	R: Into<WalkControl>,
+{
+	let mut queue = VecDeque::new();
+	queue.push_back((String::new(), root));
+
+	while let Some((path, current)) = queue.pop_front() {
+		match current {
+			Value::Object(map) => {
+				// Call the callback for each property name in the current map
+				let mut skipped_keys = Vec::new();
+				for key in map.keys().cloned().collect::<Vec<_>>() {
+					let property_path = child_path(&path, &key);
+					match callback(&property_path, map, &key).into() {
+						WalkControl::Continue => (),
+						WalkControl::SkipChildren => skipped_keys.push(key),
+						WalkControl::Stop => return false,
+					}
+				}
+
+				// Add all nested objects and arrays to the queue for further processing
+				for (key, value) in map.iter_mut() {
+					if (value.is_object() || value.is_array()) && !skipped_keys.contains(key) {
+						queue.push_back((child_path(&path, key), value));
+					}
+				}
+			}
+			Value::Array(arr) => {
+				for (idx, value) in arr.iter_mut().enumerate() {
+					if value.is_object() || value.is_array() {
+						queue.push_back((format!("{path}/{idx}"), value));
+					}
+				}
+			}
+			_ => (),
+		}
+	}
+
+	true
+}
+
+/// Depth-first walk over all the values (root included, with an empty path), calling `callback(path, value)`.
+/// - In `PreOrder`, the callback is called before visiting the (possibly replaced) value children.
+/// - In `PostOrder`, the callback is called after visiting the value children.
+///
+/// Returns `false` if the traversal was stopped early by the callback.
+pub(crate) fn walk_mut<F, R>(root: &mut Value, order: WalkOrder, mut callback: F) -> bool
+where
+	F: FnMut(&str, &mut Value) -> R,
+	R: Into<WalkControl>,
+{
+	walk_mut_at("", root, order, &mut callback)
+}
+
+fn walk_mut_at<F, R>(path: &str, value: &mut Value, order: WalkOrder, callback: &mut F) -> bool
+where
+	F: FnMut(&str, &mut Value) -> R,
+	R: Into<WalkControl>,
+{
+	if order == WalkOrder::PreOrder {
+		match callback(path, value).into() {
+			WalkControl::Continue => (),
+			WalkControl::SkipChildren => return true,
+			WalkControl::Stop => return false,
+		}
+	}
+
+	match value {
+		Value::Object(map) => {
+			for (key, child) in map.iter_mut() {
+				if !walk_mut_at(&child_path(path, key), child, order, callback) {
+					return false;
+				}
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, child) in arr.iter_mut().enumerate() {
+				if !walk_mut_at(&format!("{path}/{idx}"), child, order, callback) {
+					return false;
+				}
+			}
+		}
+		_ => (),
+	}
+
+	if order == WalkOrder::PostOrder {
+		return callback(path, value).into() != WalkControl::Stop;
+	}
+
+	true
+}
+
+/// Same as `walk_mut`, but over shared references.
+pub(crate) fn walk_ref<F, R>(root: &Value, order: WalkOrder, mut callback: F) -> bool
+where
+	F: FnMut(&str, &Value) -> R,
+	R: Into<WalkControl>,
+{
+	walk_ref_at("", root, order, &mut callback)
+}
+
+fn walk_ref_at<F, R>(path: &str, value: &Value, order: WalkOrder, callback: &mut F) -> bool
+where
+	F: FnMut(&str, &Value) -> R,
+	R: Into<WalkControl>,
+{
+	if order == WalkOrder::PreOrder {
+		match callback(path, value).into() {
+			WalkControl::Continue => (),
+			WalkControl::SkipChildren => return true,
+			WalkControl::Stop => return false,
+		}
+	}
+
+	match value {
+		Value::Object(map) => {
+			for (key, child) in map {
+				if !walk_ref_at(&child_path(path, key), child, order, callback) {
+					return false;
+				}
+			}
+		}
+		Value::Array(arr) => {
+			for (idx, child) in arr.iter().enumerate() {
+				if !walk_ref_at(&format!("{path}/{idx}"), child, order, callback) {
+					return false;
+				}
+			}
+		}
+		_ => (),
+	}
+
+	if order == WalkOrder::PostOrder {
+		return callback(path, value).into() != WalkControl::Stop;
+	}
+
+	true
+}
+
+pub(crate) fn child_path(path: &str, key: &str) -> String {
+	format!("{path}/{}", escape_token(key))
+}
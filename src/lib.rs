@@ -1,4 +1,63 @@
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "cbor")]
+mod cbor;
 mod json;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "sonic")]
+mod sonic;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
 
 // -- flatten
+#[cfg(feature = "bson")]
+pub use crate::bson::*;
+#[cfg(feature = "toml")]
+pub use crate::toml::*;
+#[cfg(feature = "cbor")]
+pub use cbor::*;
 pub use json::*;
+#[cfg(feature = "msgpack")]
+pub use msgpack::*;
+#[cfg(feature = "sonic")]
+pub use sonic::*;
+#[cfg(feature = "yaml")]
+pub use yaml::*;
+
+// -- Derive macros
+#[cfg(feature = "derive")]
+pub use value_ext_derive::AsType;
+
+/// Support for the derive macros (not public API).
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+	use crate::{AsType, JsonValueExtError};
+	use serde_json::Map;
+	pub use serde_json::Value;
+
+	static NULL: Value = Value::Null;
+
+	pub fn as_object(value: &Value) -> Result<&Map<String, Value>, JsonValueExtError> {
+		value.as_object().ok_or(JsonValueExtError::ValueNotOfType("object"))
+	}
+
+	/// Extracts the property as `T`, where a missing property is extracted from `Null`.
+	pub fn from_object_property<'a, T: AsType<'a>>(
+		map: &'a Map<String, Value>,
+		name: &str,
+	) -> Result<T, JsonValueExtError> {
+		match map.get(name) {
+			Some(value) => T::from_value(value).map_err(|err| err.with_property_context(name, value)),
+			None => T::from_value(&NULL).map_err(|_| JsonValueExtError::PropertyNotFound {
+				name: name.to_string(),
+				resolved: String::new(),
+				missing: name.to_string(),
+				suggestions: crate::json::suggest_keys(map.keys(), name),
+			}),
+		}
+	}
+}
@@ -0,0 +1,7 @@
+// region:    --- Modules
+
+mod msgpack_value_ext;
+
+pub use msgpack_value_ext::*;
+
+// endregion: --- Modules
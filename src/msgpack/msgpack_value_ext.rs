@@ -0,0 +1,385 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use rmpv::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// A MessagePack map, as the list of its `(key, value)` entries.
+pub type MsgpackMap = Vec<(Value, Value)>;
+
+/// (feature `msgpack`) Extension trait for the MessagePack `rmpv::Value`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/items/0/name`),
+/// so `JsonPath::dot(..)` also works.
+///
+/// A path token matches a map key that is a string equal to it, a binary key with the same bytes,
+/// or an integer key rendering to it (e.g., `/codes/404` matches the `404` integer key).
+///
+/// # Provided Methods
+///
+/// - **`x_new_map`**: Returns an empty `Value::Map`.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_take`**: Takes a value using a specified name or pointer path, replacing it with `Nil`.
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing maps (or arrays) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each map and key.
+pub trait MsgpackValueExt {
+	fn x_new_map() -> Value;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Takes the value at the specified name or pointer path and replaces it with `Nil`.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - An existing key matching the path token is replaced (even when a binary or integer key), otherwise a string key is added.
+	/// - Missing maps are created along the way (or arrays, when the next path part is an index).
+	/// - Array indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_map, key) -> WalkControl` (or `bool`),
+	///   where `key` is a `Value`, as MessagePack keys are not necessarily strings.
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut MsgpackMap, &Value) -> R,
+		R: Into<WalkControl>;
+}
+
+impl MsgpackValueExt for Value {
+	fn x_new_map() -> Value {
+		Value::Map(Vec::new())
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| MsgpackValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value.clone())
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value = match get_value_mut(self, name_or_pointer) {
+			Some(value) => std::mem::replace(value, Value::Nil),
+			None => return Err(MsgpackValueExtError::property_not_found(self, name_or_pointer)),
+		};
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = rmpv::ext::to_value(value)?;
+		let parts = path_tokens(name_or_pointer);
+		let last_idx = parts.len() - 1;
+
+		let mut current = self;
+		// the pointer of `current`, for the errors
+		let mut path = String::new();
+
+		// -- Add the eventual missing parents
+		for (i, part) in parts[..last_idx].iter().enumerate() {
+			let new_container = if is_array_token(&parts[i + 1]) {
+				Value::Array(Vec::new())
+			} else {
+				Value::Map(Vec::new())
+			};
+			current = match current {
+				Value::Map(map) => {
+					let idx = match entry_index(map, part) {
+						Some(idx) => idx,
+						None => {
+							map.push((Value::from(part.as_str()), new_container));
+							map.len() - 1
+						}
+					};
+					&mut map[idx].1
+				}
+				Value::Array(arr) => {
+					let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+					if idx == arr.len() {
+						arr.push(new_container);
+					}
+					&mut arr[idx]
+				}
+				other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+			};
+			path.push('/');
+			path.push_str(&escape_token(part));
+		}
+
+		// -- Set the value at the last element
+		let last_part = &parts[last_idx];
+		match current {
+			Value::Map(map) => {
+				match entry_index(map, last_part) {
+					Some(idx) => map[idx].1 = new_value,
+					None => map.push((Value::from(last_part.as_str()), new_value)),
+				}
+				Ok(())
+			}
+			Value::Array(arr) => {
+				let idx = array_insert_index(arr, last_part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_value);
+				} else {
+					arr[idx] = new_value;
+				}
+				Ok(())
+			}
+			other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+		}
+	}
+
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut MsgpackMap, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		let mut queue = VecDeque::new();
+		queue.push_back(self);
+
+		while let Some(current) = queue.pop_front() {
+			match current {
+				Value::Map(map) => {
+					let mut skipped_keys = Vec::new();
+					for key in map.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>() {
+						match callback(map, &key).into() {
+							WalkControl::Continue => (),
+							WalkControl::SkipChildren => skipped_keys.push(key),
+							WalkControl::Stop => return false,
+						}
+					}
+
+					for (key, value) in map.iter_mut() {
+						if (value.is_map() || value.is_array()) && !skipped_keys.contains(key) {
+							queue.push_back(value);
+						}
+					}
+				}
+				Value::Array(arr) => {
+					for value in arr.iter_mut() {
+						if value.is_map() || value.is_array() {
+							queue.push_back(value);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		true
+	}
+}
+
+// region:    --- Support
+
+/// Returns `true` if the map key matches the path token (string, binary, or rendered integer key).
+fn key_matches(key: &Value, token: &str) -> bool {
+	match key {
+		Value::String(s) => s.as_bytes() == token.as_bytes(),
+		Value::Binary(bytes) => bytes.as_slice() == token.as_bytes(),
+		Value::Integer(n) => n.to_string() == token,
+		Value::Boolean(b) => b.to_string() == token,
+		_ => false,
+	}
+}
+
+/// Returns the index of the map entry whose key matches the path token, if any.
+fn entry_index(map: &MsgpackMap, token: &str) -> Option<usize> {
+	map.iter().position(|(key, _)| key_matches(key, token))
+}
+
+fn child<'a>(value: &'a Value, token: &str) -> Option<&'a Value> {
+	match value {
+		Value::Map(map) => entry_index(map, token).map(|idx| &map[idx].1),
+		Value::Array(arr) => token.parse::<usize>().ok().and_then(|idx| arr.get(idx)),
+		_ => None,
+	}
+}
+
+fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| child(current, token))
+}
+
+fn get_value_mut<'a>(value: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| match current {
+			Value::Map(map) => {
+				let idx = entry_index(map, token)?;
+				Some(&mut map[idx].1)
+			}
+			Value::Array(arr) => arr.get_mut(token.parse::<usize>().ok()?),
+			_ => None,
+		})
+}
+
+/// Returns the index to set or append in `arr` (at `path`, for the requested `name`) for the token
+/// (`-` or `arr.len()` means append).
+fn array_insert_index(arr: &[Value], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		arr.len()
+	} else {
+		token.parse::<usize>().map_err(|_| MsgpackValueExtError::NotAMap {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > arr.len() {
+		return Err(MsgpackValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: arr.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Value, token: &str, name: &str, path: &str) -> MsgpackValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), msgpack_kind_name(value));
+	if is_array_token(token) {
+		MsgpackValueExtError::NotAnArray { name, path, actual }
+	} else {
+		MsgpackValueExtError::NotAMap { name, path, actual }
+	}
+}
+
+fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: Value) -> Result<T> {
+	let actual = msgpack_kind_name(&value);
+	rmpv::ext::from_value(value).map_err(|cause| MsgpackValueExtError::PropertyValueNotDeserializable {
+		name: name_or_pointer.to_string(),
+		not_of_type: std::any::type_name::<T>(),
+		actual,
+		cause,
+	})
+}
+
+/// Returns the MessagePack kind name of the value (e.g., `"string"`, `"map"`), as reported in the errors.
+fn msgpack_kind_name(value: &Value) -> &'static str {
+	match value {
+		Value::Nil => "nil",
+		Value::Boolean(_) => "boolean",
+		Value::Integer(_) => "integer",
+		Value::F32(_) | Value::F64(_) => "float",
+		Value::String(_) => "string",
+		Value::Binary(_) => "binary",
+		Value::Array(_) => "array",
+		Value::Map(_) => "map",
+		Value::Ext(_, _) => "ext",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, MsgpackValueExtError>;
+
+/// The `MsgpackValueExt` error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum MsgpackValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` (of MessagePack kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: rmpv::ext::Error,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of MessagePack kind `actual`) is not a map, while the requested `name` needs it to be one.
+	NotAMap {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of MessagePack kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	#[from]
+	Rmpv(rmpv::ext::Error),
+}
+
+impl MsgpackValueExtError {
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		let mut missing = String::new();
+		for token in path_tokens(name_or_pointer) {
+			match child(current, &token) {
+				Some(next) => {
+					resolved.push('/');
+					resolved.push_str(&escape_token(&token));
+					current = next;
+				}
+				None => {
+					missing = token;
+					break;
+				}
+			}
+		}
+
+		let suggestions = match current {
+			Value::Map(map) if !missing.is_empty() => {
+				let keys: Vec<String> = map
+					.iter()
+					.filter_map(|(key, _)| key.as_str().map(str::to_string))
+					.collect();
+				suggest_keys(&keys, &missing)
+			}
+			_ => Vec::new(),
+		};
+
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for MsgpackValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for MsgpackValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
@@ -0,0 +1,7 @@
+// region:    --- Modules
+
+mod sonic_value_ext;
+
+pub use sonic_value_ext::*;
+
+// endregion: --- Modules
@@ -0,0 +1,481 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sonic_rs::{
+	FastStr, JsonContainerTrait, JsonType, JsonValueMutTrait, JsonValueTrait, LazyValue, Object, PointerNode, Value,
+};
+use std::collections::VecDeque;
+
+/// (feature `sonic`) Extension trait for the sonic-rs `sonic_rs::Value`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/items/0/name`),
+/// so `JsonPath::dot(..)` also works.
+///
+/// # Provided Methods
+///
+/// - **`x_new_object`**: Returns an empty object `Value`.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_take`**: Takes a value using a specified name or pointer path, replacing it with `null`.
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing objects (or arrays) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each object and key.
+pub trait SonicValueExt {
+	fn x_new_object() -> Value;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Takes the value at the specified name or pointer path and replaces it with `null`.
+	/// - The value is deserialized before being taken, so a type error leaves it in place.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - Missing objects are created along the way (or arrays, when the next path part is an index).
+	/// - Array indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_object, property_name) -> WalkControl` (or `bool`).
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Object, &str) -> R,
+		R: Into<WalkControl>;
+}
+
+/// (feature `sonic`) Extension trait for the sonic-rs lazy (borrowed) document `sonic_rs::LazyValue`,
+/// with the read-only methods of `SonicValueExt`.
+///
+/// The lazy value is the raw JSON text of the document, so only the sub-document at the path gets parsed
+/// (the other ones are skipped).
+///
+/// ```rust
+/// let doc: LazyValue = sonic_rs::from_str(r#"{"user": {"name": "Jen"}, "logs": [...]}"#)?;
+/// let name: String = doc.x_get("/user/name")?;
+/// ```
+pub trait SonicLazyValueExt {
+	/// Returns an owned type `T` for a given name or pointer path, parsing only the value at the path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Returns the lazy value at the name or pointer path (borrowing its raw JSON text, not parsed).
+	fn x_get_lazy(&self, name_or_pointer: &str) -> Result<LazyValue<'_>>;
+}
+
+impl SonicValueExt for Value {
+	fn x_new_object() -> Value {
+		Value::new_object()
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| SonicValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| SonicValueExtError::property_not_found(self, name_or_pointer))?;
+		let value: T = from_value_with_context(name_or_pointer, value)?;
+
+		if let Some(taken) = get_value_mut(self, name_or_pointer) {
+			taken.take();
+		}
+		Ok(value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = sonic_rs::to_value(&value)?;
+		let parts = path_tokens(name_or_pointer);
+		let last_idx = parts.len() - 1;
+
+		let mut current = self;
+		// the pointer of `current`, for the errors
+		let mut path = String::new();
+
+		// -- Add the eventual missing parents
+		for (i, part) in parts[..last_idx].iter().enumerate() {
+			let new_container = if is_array_token(&parts[i + 1]) {
+				Value::new_array()
+			} else {
+				Value::new_object()
+			};
+			current = child_or_insert(current, part, new_container, name_or_pointer, &path)?;
+			path.push('/');
+			path.push_str(&escape_token(part));
+		}
+
+		// -- Set the value at the last element
+		let last_part = &parts[last_idx];
+		let actual = sonic_kind_name(current);
+		match current.get_type() {
+			JsonType::Object => {
+				if let Some(map) = current.as_object_mut() {
+					map.insert(last_part, new_value);
+				}
+				Ok(())
+			}
+			JsonType::Array => {
+				let arr = current
+					.as_array_mut()
+					.ok_or_else(|| not_a_container(actual, last_part, name_or_pointer, &path))?;
+				let idx = array_insert_index(arr.len(), last_part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_value);
+				} else {
+					arr[idx] = new_value;
+				}
+				Ok(())
+			}
+			_ => Err(not_a_container(actual, last_part, name_or_pointer, &path)),
+		}
+	}
+
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut Object, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		let mut queue = VecDeque::new();
+		queue.push_back(self);
+
+		while let Some(current) = queue.pop_front() {
+			match current.get_type() {
+				JsonType::Object => {
+					// the keys in their document order (before `as_object_mut`, which may reorder them)
+					let keys: Vec<String> = current
+						.as_object()
+						.map(|map| map.iter().map(|(key, _)| key.to_string()).collect())
+						.unwrap_or_default();
+					let Some(map) = current.as_object_mut() else {
+						continue;
+					};
+					let mut skipped_keys = Vec::new();
+					for key in &keys {
+						match callback(map, key).into() {
+							WalkControl::Continue => (),
+							WalkControl::SkipChildren => skipped_keys.push(key.as_str()),
+							WalkControl::Stop => return false,
+						}
+					}
+
+					let mut children: Vec<(usize, &mut Value)> = map
+						.iter_mut()
+						.filter(|(key, value)| (value.is_object() || value.is_array()) && !skipped_keys.contains(key))
+						.map(|(key, value)| (keys.iter().position(|k| k == key).unwrap_or(usize::MAX), value))
+						.collect();
+					children.sort_by_key(|(position, _)| *position);
+					queue.extend(children.into_iter().map(|(_, value)| value));
+				}
+				JsonType::Array => {
+					let Some(arr) = current.as_array_mut() else {
+						continue;
+					};
+					for value in arr.iter_mut() {
+						if value.is_object() || value.is_array() {
+							queue.push_back(value);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		true
+	}
+}
+
+impl SonicLazyValueExt for LazyValue<'_> {
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = self.x_get_lazy(name_or_pointer)?;
+
+		sonic_rs::from_str(value.as_raw_str()).map_err(|cause| SonicValueExtError::PropertyValueNotDeserializable {
+			name: name_or_pointer.to_string(),
+			not_of_type: std::any::type_name::<T>(),
+			actual: kind_name(value.get_type()),
+			cause,
+		})
+	}
+
+	fn x_get_lazy(&self, name_or_pointer: &str) -> Result<LazyValue<'_>> {
+		let tokens = path_tokens(name_or_pointer);
+		// the pointer nodes of the tokens resolved so far (an index or a key, per the parent kind)
+		let mut nodes: Vec<PointerNode> = Vec::with_capacity(tokens.len());
+		let mut current = self.clone();
+
+		for (i, token) in tokens.iter().enumerate() {
+			let node = match current.get_type() {
+				JsonType::Array => token.parse::<usize>().ok().map(PointerNode::Index),
+				JsonType::Object => Some(PointerNode::Key(FastStr::new(token))),
+				_ => None,
+			};
+			let next = node.and_then(|node| {
+				nodes.push(node);
+				self.pointer(&nodes)
+			});
+			let Some(next) = next else {
+				return Err(lazy_not_found(&current, name_or_pointer, &tokens[..i], token));
+			};
+			current = next;
+		}
+
+		Ok(current)
+	}
+}
+
+// region:    --- Support
+
+fn child<'a>(value: &'a Value, token: &str) -> Option<&'a Value> {
+	match value.get_type() {
+		JsonType::Object => value.as_object()?.get(&token),
+		JsonType::Array => value.as_array()?.get(token.parse::<usize>().ok()?),
+		_ => None,
+	}
+}
+
+fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| child(current, token))
+}
+
+fn get_value_mut<'a>(value: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| match current.get_type() {
+			JsonType::Object => current.as_object_mut()?.get_mut(token),
+			JsonType::Array => {
+				let idx = token.parse::<usize>().ok()?;
+				current.as_array_mut()?.get_mut(idx)
+			}
+			_ => None,
+		})
+}
+
+/// Returns the child of `current` (at `path`, for the requested `name`) for the token, adding the `new_container`
+/// when missing.
+fn child_or_insert<'a>(
+	current: &'a mut Value,
+	token: &str,
+	new_container: Value,
+	name: &str,
+	path: &str,
+) -> Result<&'a mut Value> {
+	let actual = sonic_kind_name(current);
+	match current.get_type() {
+		JsonType::Object => {
+			let map = current
+				.as_object_mut()
+				.ok_or_else(|| not_a_container(actual, token, name, path))?;
+			if !map.contains_key(&token) {
+				map.insert(token, new_container);
+			}
+			map.get_mut(&token)
+				.ok_or_else(|| not_a_container(actual, token, name, path))
+		}
+		JsonType::Array => {
+			let arr = current
+				.as_array_mut()
+				.ok_or_else(|| not_a_container(actual, token, name, path))?;
+			let idx = array_insert_index(arr.len(), token, name, path)?;
+			if idx == arr.len() {
+				arr.push(new_container);
+			}
+			arr.get_mut(idx)
+				.ok_or_else(|| not_a_container(actual, token, name, path))
+		}
+		_ => Err(not_a_container(actual, token, name, path)),
+	}
+}
+
+/// Returns the index to set or append in an array of length `len` (at `path`, for the requested `name`)
+/// for the token (`-` or `len` means append).
+fn array_insert_index(len: usize, token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		len
+	} else {
+		token.parse::<usize>().map_err(|_| SonicValueExtError::NotAnObject {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > len {
+		return Err(SonicValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len,
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar (of kind `actual`, at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(actual: &'static str, token: &str, name: &str, path: &str) -> SonicValueExtError {
+	let (name, path) = (name.to_string(), path.to_string());
+	if is_array_token(token) {
+		SonicValueExtError::NotAnArray { name, path, actual }
+	} else {
+		SonicValueExtError::NotAnObject { name, path, actual }
+	}
+}
+
+/// Returns the `PropertyNotFound` error of a lazy value, where `current` (at the `resolved` tokens) has no `missing` child.
+fn lazy_not_found(
+	current: &LazyValue,
+	name_or_pointer: &str,
+	resolved: &[String],
+	missing: &str,
+) -> SonicValueExtError {
+	let suggestions = if current.is_object() {
+		let keys: Vec<String> = sonic_rs::to_object_iter(current.as_raw_str())
+			.filter_map(|entry| entry.ok())
+			.map(|(key, _)| key.to_string())
+			.collect();
+		suggest_keys(&keys, missing)
+	} else {
+		Vec::new()
+	};
+
+	SonicValueExtError::PropertyNotFound {
+		name: name_or_pointer.to_string(),
+		resolved: resolved
+			.iter()
+			.map(|token| format!("/{}", escape_token(token)))
+			.collect(),
+		missing: missing.to_string(),
+		suggestions,
+	}
+}
+
+fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: &Value) -> Result<T> {
+	sonic_rs::from_value(value).map_err(|cause| SonicValueExtError::PropertyValueNotDeserializable {
+		name: name_or_pointer.to_string(),
+		not_of_type: std::any::type_name::<T>(),
+		actual: sonic_kind_name(value),
+		cause,
+	})
+}
+
+/// Returns the JSON kind name of the value (e.g., `"string"`, `"object"`), as reported in the errors.
+fn sonic_kind_name(value: &Value) -> &'static str {
+	kind_name(value.get_type())
+}
+
+fn kind_name(json_type: JsonType) -> &'static str {
+	match json_type {
+		JsonType::Null => "null",
+		JsonType::Boolean => "boolean",
+		JsonType::Number => "number",
+		JsonType::String => "string",
+		JsonType::Object => "object",
+		JsonType::Array => "array",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, SonicValueExtError>;
+
+/// The `SonicValueExt` (and `SonicLazyValueExt`) error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum SonicValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` (of JSON kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: sonic_rs::Error,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of JSON kind `actual`) is not an object, while the requested `name` needs it to be one.
+	NotAnObject {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of JSON kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	#[from]
+	Sonic(sonic_rs::Error),
+}
+
+impl SonicValueExtError {
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		let mut missing = String::new();
+		for token in path_tokens(name_or_pointer) {
+			match child(current, &token) {
+				Some(next) => {
+					resolved.push('/');
+					resolved.push_str(&escape_token(&token));
+					current = next;
+				}
+				None => {
+					missing = token;
+					break;
+				}
+			}
+		}
+
+		let suggestions = match current.as_object() {
+			Some(map) if !missing.is_empty() => {
+				let keys: Vec<String> = map.iter().map(|(key, _)| key.to_string()).collect();
+				suggest_keys(&keys, &missing)
+			}
+			_ => Vec::new(),
+		};
+
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for SonicValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for SonicValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
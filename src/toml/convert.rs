@@ -0,0 +1,141 @@
+use crate::json::escape_token;
+use crate::TomlValueExtError;
+use serde_json::{Map, Number, Value};
+use toml::value::Datetime;
+use toml::Table;
+
+/// A value (at the JSON pointer `path`) that has no representation in the target format of a JSON/TOML conversion.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TomlConversionIssue {
+	/// A JSON `null` (TOML has no null).
+	Null { path: String },
+
+	/// A JSON integer above `i64::MAX` (TOML integers are 64 bit signed).
+	IntegerOutOfRange { path: String, value: u64 },
+
+	/// A TOML `nan` or `inf` float (JSON numbers are finite).
+	NonFiniteFloat { path: String, value: f64 },
+}
+
+/// Converts the JSON `value` to TOML, reporting all the values that cannot be represented.
+pub(crate) fn json_to_toml(value: &Value) -> Result<toml::Value, TomlValueExtError> {
+	let mut issues = Vec::new();
+	let res = json_to_toml_at(value, &mut String::new(), &mut issues);
+	match res {
+		Some(toml_value) if issues.is_empty() => Ok(toml_value),
+		_ => Err(TomlValueExtError::NotRepresentable { issues }),
+	}
+}
+
+/// Converts the TOML `value` to JSON, reporting all the values that cannot be represented.
+pub(crate) fn toml_to_json(value: &toml::Value) -> Result<Value, TomlValueExtError> {
+	let mut issues = Vec::new();
+	let json_value = toml_to_json_at(value, &mut String::new(), &mut issues);
+	if issues.is_empty() {
+		Ok(json_value)
+	} else {
+		Err(TomlValueExtError::NotRepresentable { issues })
+	}
+}
+
+// region:    --- Support
+
+fn json_to_toml_at(value: &Value, path: &mut String, issues: &mut Vec<TomlConversionIssue>) -> Option<toml::Value> {
+	let toml_value = match value {
+		Value::Null => {
+			issues.push(TomlConversionIssue::Null { path: path.clone() });
+			return None;
+		}
+		Value::Bool(b) => toml::Value::Boolean(*b),
+		Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+			(Some(i), _, _) => toml::Value::Integer(i),
+			(None, Some(u), _) => {
+				issues.push(TomlConversionIssue::IntegerOutOfRange {
+					path: path.clone(),
+					value: u,
+				});
+				return None;
+			}
+			(None, None, f) => toml::Value::Float(f.unwrap_or_default()),
+		},
+		Value::String(s) => match as_datetime(s) {
+			Some(datetime) => toml::Value::Datetime(datetime),
+			None => toml::Value::String(s.clone()),
+		},
+		Value::Array(arr) => {
+			let mut items = Vec::with_capacity(arr.len());
+			for (idx, item) in arr.iter().enumerate() {
+				let depth = push_token(path, &idx.to_string());
+				items.extend(json_to_toml_at(item, path, issues));
+				path.truncate(depth);
+			}
+			toml::Value::Array(items)
+		}
+		Value::Object(map) => {
+			let mut table = Table::new();
+			for (key, item) in map {
+				let depth = push_token(path, key);
+				if let Some(item) = json_to_toml_at(item, path, issues) {
+					table.insert(key.clone(), item);
+				}
+				path.truncate(depth);
+			}
+			toml::Value::Table(table)
+		}
+	};
+	Some(toml_value)
+}
+
+fn toml_to_json_at(value: &toml::Value, path: &mut String, issues: &mut Vec<TomlConversionIssue>) -> Value {
+	match value {
+		toml::Value::String(s) => Value::String(s.clone()),
+		toml::Value::Integer(i) => Value::from(*i),
+		toml::Value::Float(f) => match Number::from_f64(*f) {
+			Some(n) => Value::Number(n),
+			None => {
+				issues.push(TomlConversionIssue::NonFiniteFloat {
+					path: path.clone(),
+					value: *f,
+				});
+				Value::Null
+			}
+		},
+		toml::Value::Boolean(b) => Value::Bool(*b),
+		toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+		toml::Value::Array(arr) => {
+			let mut items = Vec::with_capacity(arr.len());
+			for (idx, item) in arr.iter().enumerate() {
+				let depth = push_token(path, &idx.to_string());
+				items.push(toml_to_json_at(item, path, issues));
+				path.truncate(depth);
+			}
+			Value::Array(items)
+		}
+		toml::Value::Table(table) => {
+			let mut map = Map::new();
+			for (key, item) in table {
+				let depth = push_token(path, key);
+				map.insert(key.clone(), toml_to_json_at(item, path, issues));
+				path.truncate(depth);
+			}
+			Value::Object(map)
+		}
+	}
+}
+
+/// Returns the TOML datetime for a string that is one, and displays back as the same string
+/// (so the conversion back to JSON is exact).
+fn as_datetime(s: &str) -> Option<Datetime> {
+	s.parse::<Datetime>().ok().filter(|datetime| datetime.to_string() == s)
+}
+
+/// Appends the escaped `token` to the `path`, and returns the previous length (for the truncate).
+fn push_token(path: &mut String, token: &str) -> usize {
+	let depth = path.len();
+	path.push('/');
+	path.push_str(&escape_token(token));
+	depth
+}
+
+// endregion: --- Support
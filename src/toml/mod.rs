@@ -0,0 +1,9 @@
+// region:    --- Modules
+
+mod convert;
+mod toml_value_ext;
+
+pub use convert::*;
+pub use toml_value_ext::*;
+
+// endregion: --- Modules
@@ -0,0 +1,372 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::VecDeque;
+use toml::{Table, Value};
+
+/// (feature `toml`) Extension trait for `toml::Value`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/package/name`, with
+/// array indices like `/bin/0/name`), so `JsonPath::dot(..)` also works.
+///
+/// # Provided Methods
+///
+/// - **`x_new_table`**: Returns an empty `Value::Table`.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_take`**: Removes a value from its parent table (or array) and returns it as type `T` (TOML has no null).
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing tables (or arrays) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each table and property name.
+/// - **`x_pretty`**: Returns a pretty-printed TOML string representation of the value (must be a table).
+pub trait TomlValueExt {
+	fn x_new_table() -> Value;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Removes the value at the specified name or pointer path from its parent table (or array), and returns it as type `T`.
+	/// - As TOML has no null, nothing is left behind (unlike `JsonValueExt::x_take`).
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - Missing tables are created along the way (or arrays, when the next path part is an index).
+	/// - Array indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_table, property_name) -> WalkControl` (or `bool`).
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Table, &str) -> R,
+		R: Into<WalkControl>;
+
+	/// Returns a pretty-printed TOML string representation of the value.
+	fn x_pretty(&self) -> Result<String>;
+}
+
+impl TomlValueExt for Value {
+	fn x_new_table() -> Value {
+		Value::Table(Table::new())
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| TomlValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value.clone())
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value = match remove_value(self, name_or_pointer) {
+			Some(value) => value,
+			None => return Err(TomlValueExtError::property_not_found(self, name_or_pointer)),
+		};
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = Value::try_from(value)?;
+		let parts = path_tokens(name_or_pointer);
+		let last_idx = parts.len() - 1;
+
+		let mut current = self;
+		// the pointer of `current`, for the errors
+		let mut path = String::new();
+
+		// -- Add the eventual missing parents
+		for (i, part) in parts[..last_idx].iter().enumerate() {
+			let next_is_index = is_array_token(&parts[i + 1]);
+			let new_container = || {
+				if next_is_index {
+					Value::Array(Vec::new())
+				} else {
+					Value::Table(Table::new())
+				}
+			};
+			current = match current {
+				Value::Table(table) => table.entry(part.as_str()).or_insert_with(new_container),
+				Value::Array(arr) => {
+					let idx = array_insert_index(arr, part, name_or_pointer, &path)?;
+					if idx == arr.len() {
+						arr.push(new_container());
+					}
+					&mut arr[idx]
+				}
+				other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+			};
+			path.push('/');
+			path.push_str(&escape_token(part));
+		}
+
+		// -- Set the value at the last element
+		let last_part = &parts[last_idx];
+		match current {
+			Value::Table(table) => {
+				table.insert(last_part.to_string(), new_value);
+				Ok(())
+			}
+			Value::Array(arr) => {
+				let idx = array_insert_index(arr, last_part, name_or_pointer, &path)?;
+				if idx == arr.len() {
+					arr.push(new_value);
+				} else {
+					arr[idx] = new_value;
+				}
+				Ok(())
+			}
+			other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+		}
+	}
+
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut Table, &str) -> R,
+		R: Into<WalkControl>,
+	{
+		let mut queue = VecDeque::new();
+		queue.push_back(self);
+
+		while let Some(current) = queue.pop_front() {
+			match current {
+				Value::Table(table) => {
+					let mut skipped_keys = Vec::new();
+					for key in table.keys().cloned().collect::<Vec<_>>() {
+						match callback(table, &key).into() {
+							WalkControl::Continue => (),
+							WalkControl::SkipChildren => skipped_keys.push(key),
+							WalkControl::Stop => return false,
+						}
+					}
+
+					for (key, value) in table.iter_mut() {
+						if (value.is_table() || value.is_array()) && !skipped_keys.contains(key) {
+							queue.push_back(value);
+						}
+					}
+				}
+				Value::Array(arr) => {
+					for value in arr.iter_mut() {
+						if value.is_table() || value.is_array() {
+							queue.push_back(value);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		true
+	}
+
+	fn x_pretty(&self) -> Result<String> {
+		let content = toml::to_string_pretty(self)?;
+		Ok(content)
+	}
+}
+
+// region:    --- Support
+
+fn child<'a>(value: &'a Value, token: &str) -> Option<&'a Value> {
+	match value {
+		Value::Table(table) => table.get(token),
+		Value::Array(arr) => token.parse::<usize>().ok().and_then(|idx| arr.get(idx)),
+		_ => None,
+	}
+}
+
+fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| child(current, token))
+}
+
+/// Removes the value for a direct name or a pointer path from its parent table or array.
+fn remove_value(value: &mut Value, name_or_pointer: &str) -> Option<Value> {
+	let tokens = path_tokens(name_or_pointer);
+	let (last, parents) = tokens.split_last()?;
+
+	let mut parent = value;
+	for token in parents {
+		parent = match parent {
+			Value::Table(table) => table.get_mut(token)?,
+			Value::Array(arr) => arr.get_mut(token.parse::<usize>().ok()?)?,
+			_ => return None,
+		};
+	}
+
+	match parent {
+		Value::Table(table) => table.remove(last),
+		Value::Array(arr) => {
+			let idx = last.parse::<usize>().ok().filter(|idx| *idx < arr.len())?;
+			Some(arr.remove(idx))
+		}
+		_ => None,
+	}
+}
+
+/// Returns the index to set or append in `arr` (at `path`, for the requested `name`) for the token
+/// (`-` or `arr.len()` means append).
+fn array_insert_index(arr: &[Value], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		arr.len()
+	} else {
+		token.parse::<usize>().map_err(|_| TomlValueExtError::NotATable {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "array",
+		})?
+	};
+	if idx > arr.len() {
+		return Err(TomlValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: arr.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Value, token: &str, name: &str, path: &str) -> TomlValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), toml_kind_name(value));
+	if is_array_token(token) {
+		TomlValueExtError::NotAnArray { name, path, actual }
+	} else {
+		TomlValueExtError::NotATable { name, path, actual }
+	}
+}
+
+fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: Value) -> Result<T> {
+	let actual = toml_kind_name(&value);
+	value
+		.try_into::<T>()
+		.map_err(|cause| TomlValueExtError::PropertyValueNotDeserializable {
+			name: name_or_pointer.to_string(),
+			not_of_type: std::any::type_name::<T>(),
+			actual,
+			cause: Box::new(cause),
+		})
+}
+
+/// Returns the TOML kind name of the value (e.g., `"string"`, `"table"`), as reported in the errors.
+fn toml_kind_name(value: &Value) -> &'static str {
+	match value {
+		Value::String(_) => "string",
+		Value::Integer(_) => "integer",
+		Value::Float(_) => "float",
+		Value::Boolean(_) => "boolean",
+		Value::Datetime(_) => "datetime",
+		Value::Array(_) => "array",
+		Value::Table(_) => "table",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, TomlValueExtError>;
+
+/// The `TomlValueExt` error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum TomlValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` (of TOML kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: Box<toml::de::Error>,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of TOML kind `actual`) is not a table, while the requested `name` needs it to be one.
+	NotATable {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of TOML kind `actual`) is not an array, while the requested `name` needs it to be one.
+	NotAnArray {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the array of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	// -- Conversion errors
+	/// Some values have no representation in the target format of a JSON/TOML conversion
+	/// (see `JsonValueExt::x_to_toml` and `JsonValueExt::x_from_toml`).
+	NotRepresentable { issues: Vec<crate::TomlConversionIssue> },
+
+	#[from]
+	TomlSer(toml::ser::Error),
+}
+
+impl TomlValueExtError {
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		let mut missing = String::new();
+		for token in path_tokens(name_or_pointer) {
+			match child(current, &token) {
+				Some(next) => {
+					resolved.push('/');
+					resolved.push_str(&escape_token(&token));
+					current = next;
+				}
+				None => {
+					missing = token;
+					break;
+				}
+			}
+		}
+
+		let suggestions = match current {
+			Value::Table(table) if !missing.is_empty() => suggest_keys(table.keys(), &missing),
+			_ => Vec::new(),
+		};
+
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for TomlValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for TomlValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
@@ -0,0 +1,133 @@
+use crate::json::escape_token;
+use crate::yaml::yaml_value_ext::yaml_kind_name;
+use crate::YamlValueExtError;
+use serde_json::{Map, Number, Value};
+use serde_yaml::Mapping;
+
+/// The YAML merge key (`<<: *base`), which serde_yaml keeps as a regular key unless `apply_merge` is called.
+const MERGE_KEY: &str = "<<";
+
+/// A YAML construct (at the JSON pointer `path`) that has no JSON representation.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum YamlConversionIssue {
+	/// A mapping key that is not a string, of YAML kind `actual` (e.g., `"number"` for `404: ...`).
+	NonStringKey { path: String, actual: &'static str },
+
+	/// A tagged value (e.g., `!Point {x: 1}`), with its `tag`.
+	Tagged { path: String, tag: String },
+
+	/// A merge key (`<<`), to apply first with `serde_yaml::Value::apply_merge`.
+	MergeKey { path: String },
+
+	/// A `.nan` or `.inf` float (JSON numbers are finite).
+	NonFiniteFloat { path: String, value: f64 },
+}
+
+/// Converts the JSON `value` to YAML (every JSON value has a YAML representation).
+pub(crate) fn json_to_yaml(value: &Value) -> serde_yaml::Value {
+	match value {
+		Value::Null => serde_yaml::Value::Null,
+		Value::Bool(b) => serde_yaml::Value::Bool(*b),
+		Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+			(Some(i), _, _) => serde_yaml::Value::from(i),
+			(None, Some(u), _) => serde_yaml::Value::from(u),
+			(None, None, f) => serde_yaml::Value::from(f.unwrap_or_default()),
+		},
+		Value::String(s) => serde_yaml::Value::String(s.clone()),
+		Value::Array(arr) => serde_yaml::Value::Sequence(arr.iter().map(json_to_yaml).collect()),
+		Value::Object(map) => {
+			let mapping: Mapping = map
+				.iter()
+				.map(|(key, item)| (serde_yaml::Value::String(key.clone()), json_to_yaml(item)))
+				.collect();
+			serde_yaml::Value::Mapping(mapping)
+		}
+	}
+}
+
+/// Converts the YAML `value` to JSON, reporting all the constructs that cannot be represented.
+pub(crate) fn yaml_to_json(value: &serde_yaml::Value) -> Result<Value, YamlValueExtError> {
+	let mut issues = Vec::new();
+	let json_value = yaml_to_json_at(value, &mut String::new(), &mut issues);
+	if issues.is_empty() {
+		Ok(json_value)
+	} else {
+		Err(YamlValueExtError::NotRepresentable { issues })
+	}
+}
+
+// region:    --- Support
+
+fn yaml_to_json_at(value: &serde_yaml::Value, path: &mut String, issues: &mut Vec<YamlConversionIssue>) -> Value {
+	match value {
+		serde_yaml::Value::Null => Value::Null,
+		serde_yaml::Value::Bool(b) => Value::Bool(*b),
+		serde_yaml::Value::Number(n) => {
+			if let Some(i) = n.as_i64() {
+				Value::from(i)
+			} else if let Some(u) = n.as_u64() {
+				Value::from(u)
+			} else {
+				let f = n.as_f64().unwrap_or_default();
+				match Number::from_f64(f) {
+					Some(n) => Value::Number(n),
+					None => {
+						issues.push(YamlConversionIssue::NonFiniteFloat {
+							path: path.clone(),
+							value: f,
+						});
+						Value::Null
+					}
+				}
+			}
+		}
+		serde_yaml::Value::String(s) => Value::String(s.clone()),
+		serde_yaml::Value::Sequence(seq) => {
+			let mut items = Vec::with_capacity(seq.len());
+			for (idx, item) in seq.iter().enumerate() {
+				let depth = push_token(path, &idx.to_string());
+				items.push(yaml_to_json_at(item, path, issues));
+				path.truncate(depth);
+			}
+			Value::Array(items)
+		}
+		serde_yaml::Value::Mapping(mapping) => {
+			let mut map = Map::new();
+			for (key, item) in mapping {
+				let Some(key) = key.as_str() else {
+					issues.push(YamlConversionIssue::NonStringKey {
+						path: path.clone(),
+						actual: yaml_kind_name(key),
+					});
+					continue;
+				};
+				let depth = push_token(path, key);
+				if key == MERGE_KEY {
+					issues.push(YamlConversionIssue::MergeKey { path: path.clone() });
+				} else {
+					map.insert(key.to_string(), yaml_to_json_at(item, path, issues));
+				}
+				path.truncate(depth);
+			}
+			Value::Object(map)
+		}
+		serde_yaml::Value::Tagged(tagged) => {
+			issues.push(YamlConversionIssue::Tagged {
+				path: path.clone(),
+				tag: tagged.tag.to_string(),
+			});
+			Value::Null
+		}
+	}
+}
+
+/// Appends the escaped `token` to the `path`, and returns the previous length (for the truncate).
+fn push_token(path: &mut String, token: &str) -> usize {
+	let depth = path.len();
+	path.push('/');
+	path.push_str(&escape_token(token));
+	depth
+}
+
+// endregion: --- Support
@@ -0,0 +1,9 @@
+// region:    --- Modules
+
+mod convert;
+mod yaml_value_ext;
+
+pub use convert::*;
+pub use yaml_value_ext::*;
+
+// endregion: --- Modules
@@ -0,0 +1,419 @@
+use crate::json::{escape_token, is_array_token, path_tokens, suggest_keys};
+use crate::WalkControl;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+use std::collections::VecDeque;
+
+/// (feature `yaml`) Extension trait for `serde_yaml::Value`, mirroring `JsonValueExt`.
+///
+/// Uses the same path syntax: a direct name, or a pointer path (if it starts with '/', e.g., `/server/ports/0`),
+/// so `JsonPath::dot(..)` also works.
+///
+/// A path token matches a mapping key that is a string equal to it, or a non-string scalar key (number, bool, null)
+/// rendering to it (e.g., `/codes/404` matches the `404:` integer key). Tagged values (e.g., `!Tag value`) are
+/// navigated transparently.
+///
+/// # Provided Methods
+///
+/// - **`x_new_mapping`**: Returns an empty `Value::Mapping`.
+/// - **`x_get`**: Returns a value of a specified type `T` using either a direct name or a pointer path.
+/// - **`x_take`**: Takes a value using a specified name or pointer path, replacing it with `Null`.
+/// - **`x_insert`**: Inserts a value of type `T` at the specified name or pointer path, creating any missing mappings (or sequences) along the way.
+/// - **`x_walk`**: Traverses all properties in the value tree, calling a callback with each mapping and key.
+/// - **`x_pretty`**: Returns the YAML string representation of the value.
+pub trait YamlValueExt {
+	fn x_new_mapping() -> Value;
+
+	/// Returns an owned type `T` for a given name or pointer path.
+	/// - `name_or_pointer`: Can be a direct name or a pointer path (if it starts with '/').
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T>;
+
+	/// Takes the value at the specified name or pointer path and replaces it with `Null`.
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T>;
+
+	/// Inserts a new value of type `T` at the specified name or pointer path.
+	/// - An existing key matching the path token is replaced (even when not a string key), otherwise a string key is inserted.
+	/// - Missing mappings are created along the way (or sequences, when the next path part is an index).
+	/// - Sequence indices are supported, with `-` appending at the end.
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()>;
+
+	/// Walks through all properties in the value tree (breadth-first) and calls the callback function on each.
+	/// - The callback signature is `(parent_mapping, key) -> WalkControl` (or `bool`),
+	///   where `key` is a `Value`, as YAML keys are not necessarily strings.
+	///
+	/// Returns `false` if the traversal is stopped early because the callback returned `Stop`.
+	fn x_walk<F, R>(&mut self, callback: F) -> bool
+	where
+		F: FnMut(&mut Mapping, &Value) -> R,
+		R: Into<WalkControl>;
+
+	/// Returns the YAML string representation of the value.
+	fn x_pretty(&self) -> Result<String>;
+}
+
+impl YamlValueExt for Value {
+	fn x_new_mapping() -> Value {
+		Value::Mapping(Mapping::new())
+	}
+
+	fn x_get<T: DeserializeOwned>(&self, name_or_pointer: &str) -> Result<T> {
+		let value = get_value(self, name_or_pointer)
+			.ok_or_else(|| YamlValueExtError::property_not_found(self, name_or_pointer))?;
+
+		from_value_with_context(name_or_pointer, value.clone())
+	}
+
+	fn x_take<T: DeserializeOwned>(&mut self, name_or_pointer: &str) -> Result<T> {
+		let value = match get_value_mut(self, name_or_pointer) {
+			Some(value) => std::mem::replace(value, Value::Null),
+			None => return Err(YamlValueExtError::property_not_found(self, name_or_pointer)),
+		};
+
+		from_value_with_context(name_or_pointer, value)
+	}
+
+	fn x_insert<T: Serialize>(&mut self, name_or_pointer: &str, value: T) -> Result<()> {
+		let new_value = serde_yaml::to_value(value)?;
+		let parts = path_tokens(name_or_pointer);
+		let last_idx = parts.len() - 1;
+
+		let mut current = self;
+		// the pointer of `current`, for the errors
+		let mut path = String::new();
+
+		// -- Add the eventual missing parents
+		for (i, part) in parts[..last_idx].iter().enumerate() {
+			let next_is_index = is_array_token(&parts[i + 1]);
+			current = match untag_mut(current) {
+				Value::Mapping(mapping) => {
+					let key = matching_key(mapping, part).unwrap_or_else(|| Value::String(part.to_string()));
+					mapping.entry(key).or_insert_with(|| {
+						if next_is_index {
+							Value::Sequence(Vec::new())
+						} else {
+							Value::Mapping(Mapping::new())
+						}
+					})
+				}
+				Value::Sequence(seq) => {
+					let idx = sequence_insert_index(seq, part, name_or_pointer, &path)?;
+					if idx == seq.len() {
+						seq.push(if next_is_index {
+							Value::Sequence(Vec::new())
+						} else {
+							Value::Mapping(Mapping::new())
+						});
+					}
+					&mut seq[idx]
+				}
+				other => return Err(not_a_container(other, part, name_or_pointer, &path)),
+			};
+			path.push('/');
+			path.push_str(&escape_token(part));
+		}
+
+		// -- Set the value at the last element
+		let last_part = &parts[last_idx];
+		match untag_mut(current) {
+			Value::Mapping(mapping) => {
+				let key = matching_key(mapping, last_part).unwrap_or_else(|| Value::String(last_part.to_string()));
+				mapping.insert(key, new_value);
+				Ok(())
+			}
+			Value::Sequence(seq) => {
+				let idx = sequence_insert_index(seq, last_part, name_or_pointer, &path)?;
+				if idx == seq.len() {
+					seq.push(new_value);
+				} else {
+					seq[idx] = new_value;
+				}
+				Ok(())
+			}
+			other => Err(not_a_container(other, last_part, name_or_pointer, &path)),
+		}
+	}
+
+	fn x_walk<F, R>(&mut self, mut callback: F) -> bool
+	where
+		F: FnMut(&mut Mapping, &Value) -> R,
+		R: Into<WalkControl>,
+	{
+		let mut queue = VecDeque::new();
+		queue.push_back(self);
+
+		while let Some(current) = queue.pop_front() {
+			match untag_mut(current) {
+				Value::Mapping(mapping) => {
+					let mut skipped_keys = Vec::new();
+					for key in mapping.keys().cloned().collect::<Vec<_>>() {
+						match callback(mapping, &key).into() {
+							WalkControl::Continue => (),
+							WalkControl::SkipChildren => skipped_keys.push(key),
+							WalkControl::Stop => return false,
+						}
+					}
+
+					for (key, value) in mapping.iter_mut() {
+						if is_container(value) && !skipped_keys.contains(key) {
+							queue.push_back(value);
+						}
+					}
+				}
+				Value::Sequence(seq) => {
+					for value in seq.iter_mut() {
+						if is_container(value) {
+							queue.push_back(value);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+
+		true
+	}
+
+	fn x_pretty(&self) -> Result<String> {
+		let content = serde_yaml::to_string(self)?;
+		Ok(content)
+	}
+}
+
+// region:    --- Support
+
+fn untag(mut value: &Value) -> &Value {
+	while let Value::Tagged(tagged) = value {
+		value = &tagged.value;
+	}
+	value
+}
+
+fn untag_mut(mut value: &mut Value) -> &mut Value {
+	while let Value::Tagged(tagged) = value {
+		value = &mut tagged.value;
+	}
+	value
+}
+
+fn is_container(value: &Value) -> bool {
+	matches!(untag(value), Value::Mapping(_) | Value::Sequence(_))
+}
+
+/// Returns `true` if the mapping key matches the path token (string key, or rendered non-string scalar key).
+fn key_matches(key: &Value, token: &str) -> bool {
+	match untag(key) {
+		Value::String(s) => s == token,
+		Value::Number(n) => n.to_string() == token,
+		Value::Bool(b) => b.to_string() == token,
+		Value::Null => token == "null" || token == "~",
+		_ => false,
+	}
+}
+
+/// Returns the (cloned) key of the mapping matching the path token, if any.
+fn matching_key(mapping: &Mapping, token: &str) -> Option<Value> {
+	if mapping.contains_key(token) {
+		return Some(Value::String(token.to_string()));
+	}
+	mapping.keys().find(|key| key_matches(key, token)).cloned()
+}
+
+fn child<'a>(value: &'a Value, token: &str) -> Option<&'a Value> {
+	match untag(value) {
+		Value::Mapping(mapping) => match mapping.get(token) {
+			Some(value) => Some(value),
+			None => mapping
+				.iter()
+				.find(|(key, _)| key_matches(key, token))
+				.map(|(_, value)| value),
+		},
+		Value::Sequence(seq) => token.parse::<usize>().ok().and_then(|idx| seq.get(idx)),
+		_ => None,
+	}
+}
+
+fn get_value<'a>(value: &'a Value, name_or_pointer: &str) -> Option<&'a Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| child(current, token))
+}
+
+fn get_value_mut<'a>(value: &'a mut Value, name_or_pointer: &str) -> Option<&'a mut Value> {
+	path_tokens(name_or_pointer)
+		.iter()
+		.try_fold(value, |current, token| match untag_mut(current) {
+			Value::Mapping(mapping) => {
+				let key = matching_key(mapping, token)?;
+				mapping.get_mut(&key)
+			}
+			Value::Sequence(seq) => seq.get_mut(token.parse::<usize>().ok()?),
+			_ => None,
+		})
+}
+
+/// Returns the index to set or append in `seq` (at `path`, for the requested `name`) for the token
+/// (`-` or `seq.len()` means append).
+fn sequence_insert_index(seq: &[Value], token: &str, name: &str, path: &str) -> Result<usize> {
+	let idx = if token == "-" {
+		seq.len()
+	} else {
+		token.parse::<usize>().map_err(|_| YamlValueExtError::NotAMapping {
+			name: name.to_string(),
+			path: path.to_string(),
+			actual: "sequence",
+		})?
+	};
+	if idx > seq.len() {
+		return Err(YamlValueExtError::IndexOutOfBounds {
+			name: name.to_string(),
+			path: path.to_string(),
+			index: idx,
+			len: seq.len(),
+		});
+	}
+	Ok(idx)
+}
+
+/// Returns the error for a scalar `value` (at `path`, for the requested `name`) that cannot hold the `token`.
+fn not_a_container(value: &Value, token: &str, name: &str, path: &str) -> YamlValueExtError {
+	let (name, path, actual) = (name.to_string(), path.to_string(), yaml_kind_name(value));
+	if is_array_token(token) {
+		YamlValueExtError::NotASequence { name, path, actual }
+	} else {
+		YamlValueExtError::NotAMapping { name, path, actual }
+	}
+}
+
+fn from_value_with_context<T: DeserializeOwned>(name_or_pointer: &str, value: Value) -> Result<T> {
+	let actual = yaml_kind_name(&value);
+	serde_yaml::from_value(value).map_err(|cause| YamlValueExtError::PropertyValueNotDeserializable {
+		name: name_or_pointer.to_string(),
+		not_of_type: std::any::type_name::<T>(),
+		actual,
+		cause,
+	})
+}
+
+/// Returns the YAML kind name of the value (e.g., `"string"`, `"mapping"`), as reported in the errors.
+pub(crate) fn yaml_kind_name(value: &Value) -> &'static str {
+	match value {
+		Value::Null => "null",
+		Value::Bool(_) => "bool",
+		Value::Number(_) => "number",
+		Value::String(_) => "string",
+		Value::Sequence(_) => "sequence",
+		Value::Mapping(_) => "mapping",
+		Value::Tagged(_) => "tagged",
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Error
+type Result<T> = core::result::Result<T, YamlValueExtError>;
+
+/// The `YamlValueExt` error, following the `JsonValueExtError` design.
+#[derive(Debug, derive_more::From)]
+#[non_exhaustive]
+pub enum YamlValueExtError {
+	/// The property at `name` does not exist (see `JsonValueExtError::PropertyNotFound`).
+	PropertyNotFound {
+		name: String,
+		resolved: String,
+		missing: String,
+		suggestions: Vec<String>,
+	},
+
+	/// The value at `name` (of YAML kind `actual`) could not be deserialized into `not_of_type`.
+	PropertyValueNotDeserializable {
+		name: String,
+		not_of_type: &'static str,
+		actual: &'static str,
+		cause: serde_yaml::Error,
+	},
+
+	// -- Structural errors
+	/// The value at `path` (of YAML kind `actual`) is not a mapping, while the requested `name` needs it to be one.
+	NotAMapping {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The value at `path` (of YAML kind `actual`) is not a sequence, while the requested `name` needs it to be one.
+	NotASequence {
+		name: String,
+		path: String,
+		actual: &'static str,
+	},
+
+	/// The `index` is out of the bounds of the sequence of length `len` at `path`.
+	IndexOutOfBounds {
+		name: String,
+		path: String,
+		index: usize,
+		len: usize,
+	},
+
+	// -- Conversion errors
+	/// Some YAML constructs have no JSON representation (see `JsonValueExt::x_from_yaml`).
+	NotRepresentable { issues: Vec<crate::YamlConversionIssue> },
+
+	#[from]
+	SerdeYaml(serde_yaml::Error),
+}
+
+impl YamlValueExtError {
+	/// Builds the `PropertyNotFound` error for `name_or_pointer`, resolving it as far as possible from `root`.
+	fn property_not_found(root: &Value, name_or_pointer: &str) -> Self {
+		let mut current = root;
+		let mut resolved = String::new();
+		let mut missing = String::new();
+		for token in path_tokens(name_or_pointer) {
+			match child(current, &token) {
+				Some(next) => {
+					resolved.push('/');
+					resolved.push_str(&escape_token(&token));
+					current = next;
+				}
+				None => {
+					missing = token;
+					break;
+				}
+			}
+		}
+
+		let suggestions = match untag(current) {
+			Value::Mapping(mapping) if !missing.is_empty() => {
+				let keys: Vec<String> = mapping
+					.keys()
+					.filter_map(|key| key.as_str().map(str::to_string))
+					.collect();
+				suggest_keys(&keys, &missing)
+			}
+			_ => Vec::new(),
+		};
+
+		Self::PropertyNotFound {
+			name: name_or_pointer.to_string(),
+			resolved,
+			missing,
+			suggestions,
+		}
+	}
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for YamlValueExtError {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+		write!(fmt, "{self:?}")
+	}
+}
+
+impl std::error::Error for YamlValueExtError {}
+
+// endregion: --- Error Boilerplate
+
+// endregion: --- Error
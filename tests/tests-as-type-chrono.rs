@@ -0,0 +1,58 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_as_type_chrono_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"created": "2024-05-01T10:30:00+02:00",
+		"epoch": 1714552200,
+		"day": "2024-05-01",
+		"local": "2024-05-01T10:30:00.5",
+		"bad": "yesterday"
+	});
+
+	// -- Exec
+	let created: DateTime<Utc> = value.x_get_as("created")?;
+	let epoch: DateTime<Utc> = value.x_get_as("epoch")?;
+	let day: NaiveDate = value.x_get_as("day")?;
+	let local: NaiveDateTime = value.x_get_as("local")?;
+	let bad: Option<DateTime<Utc>> = value.x_get_as("bad")?;
+
+	// -- Check
+	assert_eq!(created.to_rfc3339(), "2024-05-01T08:30:00+00:00");
+	assert_eq!(epoch, created);
+	assert_eq!(day, NaiveDate::from_ymd_opt(2024, 5, 1).ok_or("date")?);
+	assert_eq!(local.and_utc().timestamp_subsec_millis(), 500);
+	assert!(bad.is_none());
+	assert!(value.x_get_as::<NaiveDate>("bad").is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_chrono_float_epoch_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"before": -1.5,
+		"near": 1.9999999999,
+		"half": 0.5
+	});
+
+	// -- Exec
+	let before: DateTime<Utc> = value.x_get_as("before")?;
+	let near: DateTime<Utc> = value.x_get_as("near")?;
+	let half: DateTime<Utc> = value.x_get_as("half")?;
+
+	// -- Check
+	assert_eq!(before.to_rfc3339(), "1969-12-31T23:59:58.500+00:00");
+	assert_eq!(near, DateTime::from_timestamp(2, 0).ok_or("timestamp")?);
+	assert_eq!(half, DateTime::from_timestamp(0, 500_000_000).ok_or("timestamp")?);
+
+	Ok(())
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "uuid")]
+
+use serde_json::json;
+use uuid::Uuid;
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_as_type_uuid_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+		"simple": "67e5504410b1426f9247bb680e5fe0c8",
+		"bad": "not-a-uuid"
+	});
+
+	// -- Exec
+	let id: Uuid = value.x_get_as("/id")?;
+	let simple: Uuid = value.x_get_as("simple")?;
+	let bad: Option<Uuid> = value.x_get_as("bad")?;
+
+	// -- Check
+	assert_eq!(id, simple);
+	assert_eq!(id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+	assert!(bad.is_none());
+	assert!(value.x_get_as::<Uuid>("bad").is_err());
+
+	Ok(())
+}
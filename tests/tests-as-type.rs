@@ -0,0 +1,307 @@
+use serde_json::{json, Map, Number, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use value_ext::{JsonValueExt, JsonValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_as_type_integers_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"big": 5_000_000_000u64, "small": 200, "neg": -100, "huge_neg": -40_000});
+
+	// -- Exec & Check
+	assert_eq!(value.x_get_as::<u64>("big")?, 5_000_000_000);
+	assert_eq!(value.x_get_as::<usize>("small")?, 200);
+	assert_eq!(value.x_get_as::<u16>("small")?, 200);
+	assert_eq!(value.x_get_as::<u8>("small")?, 200);
+	assert_eq!(value.x_get_as::<i16>("neg")?, -100);
+	assert_eq!(value.x_get_as::<i8>("neg")?, -100);
+	assert_eq!(value.x_get_as::<isize>("huge_neg")?, -40_000);
+
+	// checked narrowing
+	assert!(value.x_get_as::<u8>("big").is_err());
+	assert!(value.x_get_as::<i8>("huge_neg").is_err());
+	assert!(value.x_get_as::<u64>("neg").is_err());
+	assert_eq!(value.x_get_as::<Option<i16>>("huge_neg")?, None);
+	assert_eq!(value.x_get_as::<Option<u16>>("small")?, Some(200));
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_f32_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"ratio": 0.5, "int": 3, "too_big": 1e300});
+
+	// -- Exec & Check
+	assert_eq!(value.x_get_as::<f32>("ratio")?, 0.5);
+	assert_eq!(value.x_get_as::<f32>("int")?, 3.0);
+	assert!(value.x_get_as::<f32>("too_big").is_err());
+	assert_eq!(value.x_get_as::<Option<f32>>("too_big")?, None);
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_cow_str_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"name": "Jen", "age": 32, "admin": true, "tags": []});
+
+	// -- Exec
+	let name: Cow<str> = value.x_get_as("name")?;
+	let age: Cow<str> = value.x_get_as("age")?;
+	let admin: Cow<str> = value.x_get_as("admin")?;
+
+	// -- Check
+	assert!(matches!(name, Cow::Borrowed("Jen")));
+	assert_eq!(age, "32");
+	assert_eq!(admin, "true");
+	assert!(value.x_get_as::<Cow<str>>("tags").is_err());
+	assert_eq!(value.x_get_as::<Option<Cow<str>>>("tags")?, None);
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_map_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"config": {"port": 8080, "host": "localhost"}, "name": "app"});
+
+	// -- Exec
+	let config: &Map<String, Value> = value.x_get_as("config")?;
+	let not_map: Option<&Map<String, Value>> = value.x_get_as("name")?;
+
+	// -- Check
+	assert_eq!(config.len(), 2);
+	assert_eq!(config.get("port"), Some(&json!(8080)));
+	assert!(not_map.is_none());
+	assert!(value.x_get_as::<&Map<String, Value>>("name").is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_slice_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"items": [1, "two", null], "name": "app"});
+
+	// -- Exec
+	let items: &[Value] = value.x_get_as("items")?;
+	let not_array: Option<&[Value]> = value.x_get_as("name")?;
+
+	// -- Check
+	assert_eq!(items, &[json!(1), json!("two"), Value::Null]);
+	assert!(not_array.is_none());
+	assert!(value.x_get_as::<&[Value]>("name").is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_vec_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"ints": [1, 2, 3], "floats": [1.5, 2], "flags": [true, false], "mixed": [1, "two", 3]});
+
+	// -- Exec
+	let ints: Vec<i64> = value.x_get_as("ints")?;
+	let ids: Vec<u32> = value.x_get_as("ints")?;
+	let floats: Vec<f64> = value.x_get_as("floats")?;
+	let flags: Vec<bool> = value.x_get_as("flags")?;
+	let mixed_err = value.x_get_as::<Vec<i64>>("mixed");
+	let mixed_opt: Option<Vec<i64>> = value.x_get_as("mixed")?;
+
+	// -- Check
+	assert_eq!(ints, vec![1, 2, 3]);
+	assert_eq!(ids, vec![1, 2, 3]);
+	assert_eq!(floats, vec![1.5, 2.0]);
+	assert_eq!(flags, vec![true, false]);
+	assert!(matches!(
+		mixed_err,
+		Err(JsonValueExtError::ArrayElementNotOfType {
+			index: 1,
+			not_of_type: "i64"
+		})
+	));
+	assert!(mixed_opt.is_none());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_vec_string_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"tags": ["a", "b"], "bad": ["a", 1]});
+
+	// -- Exec
+	let tags: Vec<String> = value.x_get_as("tags")?;
+	let borrowed: Vec<&str> = value.x_get_as("tags")?;
+	let opt_tags: Option<Vec<String>> = value.x_get_as("tags")?;
+	let opt_bad: Option<Vec<String>> = value.x_get_as("bad")?;
+
+	// -- Check
+	assert_eq!(tags, vec!["a".to_string(), "b".to_string()]);
+	assert_eq!(borrowed, vec!["a", "b"]);
+	assert_eq!(opt_tags, Some(tags));
+	assert!(opt_bad.is_none());
+	assert!(value.x_get_as::<Vec<String>>("bad").is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_hashmap_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"labels": {"app": "web", "tier": "front"}, "mixed": {"a": "x", "b": 2}});
+
+	// -- Exec
+	let labels: HashMap<&str, &str> = value.x_get_as("labels")?;
+	let mixed_values: HashMap<&str, &Value> = value.x_get_as("mixed")?;
+	let mixed_err = value.x_get_as::<HashMap<&str, &str>>("mixed");
+
+	// -- Check
+	assert_eq!(labels.get("app"), Some(&"web"));
+	assert_eq!(labels.len(), 2);
+	assert_eq!(mixed_values.get("b"), Some(&&json!(2)));
+	assert!(matches!(mixed_err, Err(JsonValueExtError::PropertyValueNotOfType { name, .. }) if name == "b"));
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_number_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"int": 42, "float": 4.2, "big": u64::MAX, "name": "x"});
+
+	// -- Exec
+	let int: &Number = value.x_get_as("int")?;
+	let float: &Number = value.x_get_as("float")?;
+	let big: &Number = value.x_get_as("big")?;
+	let not_number: Option<&Number> = value.x_get_as("name")?;
+
+	// -- Check
+	assert!(int.is_i64());
+	assert!(float.is_f64());
+	assert_eq!(big.as_u64(), Some(u64::MAX));
+	assert!(not_number.is_none());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_fixed_array_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"position": [1.0, 2.5, -3], "short": [1.0, 2.0], "bad": [1.0, "y", 3.0]});
+
+	// -- Exec
+	let position: [f64; 3] = value.x_get_as("position")?;
+	let short_err = value.x_get_as::<[f64; 3]>("short");
+	let bad_err = value.x_get_as::<[f64; 3]>("bad");
+
+	// -- Check
+	assert_eq!(position, [1.0, 2.5, -3.0]);
+	assert!(matches!(
+		short_err,
+		Err(JsonValueExtError::ArrayLengthMismatch { expected: 3, actual: 2 })
+	));
+	assert!(matches!(
+		bad_err,
+		Err(JsonValueExtError::ArrayElementNotOfType { index: 1, .. })
+	));
+	assert_eq!(value.x_get_as::<Option<[f64; 3]>>("short")?, None);
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_tuple_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"pair": ["key", 42], "triple": [1, 2.5, true], "quad": ["a", "b", null, 4], "bad": [42, "key"]});
+
+	// -- Exec
+	let pair: (&str, i64) = value.x_get_as("pair")?;
+	let triple: (i32, f64, bool) = value.x_get_as("triple")?;
+	let quad: (&str, String, Option<i64>, u8) = value.x_get_as("quad")?;
+	let single_err = value.x_get_as::<(&str,)>("pair");
+	let bad_err = value.x_get_as::<(&str, i64)>("bad");
+
+	// -- Check
+	assert_eq!(pair, ("key", 42));
+	assert_eq!(triple, (1, 2.5, true));
+	assert_eq!(quad, ("a", "b".to_string(), None, 4));
+	assert!(matches!(
+		single_err,
+		Err(JsonValueExtError::ArrayLengthMismatch { expected: 1, actual: 2 })
+	));
+	assert!(matches!(
+		bad_err,
+		Err(JsonValueExtError::ArrayElementNotOfType { index: 0, .. })
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_char_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"delim": ",", "emoji": "é", "empty": "", "long": "ab", "num": 1});
+
+	// -- Exec
+	let delim: char = value.x_get_as("delim")?;
+	let emoji: Option<char> = value.x_get_as("emoji")?;
+	let empty: Option<char> = value.x_get_as("empty")?;
+	let long_err = value.x_get_as::<char>("long");
+	let num_err = value.x_get_as::<char>("num");
+
+	// -- Check
+	assert_eq!(delim, ',');
+	assert_eq!(emoji, Some('é'));
+	assert!(empty.is_none());
+	assert!(long_err.is_err());
+	assert!(num_err.is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_as_type_mut_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"doc": {"title": "Hello", "tags": ["a"], "meta": {}}});
+
+	// -- Exec
+	value.x_get_as_mut::<&mut String>("/doc/title")?.push_str(" World");
+	value.x_get_as_mut::<&mut Vec<Value>>("/doc/tags")?.push(json!("b"));
+	value
+		.x_get_as_mut::<&mut Map<String, Value>>("/doc/meta")?
+		.insert("v".into(), json!(1));
+	let not_string: Option<&mut String> = value.x_get_as_mut("/doc/tags")?;
+	let not_string = not_string.is_none();
+	let missing_err = value
+		.x_get_as_mut::<&mut String>("/doc/titl")
+		.err()
+		.ok_or("should fail")?;
+	let index_err = value
+		.x_get_as_mut::<&mut Value>("/doc/tags/5")
+		.err()
+		.ok_or("should fail")?;
+	let err = value.x_get_as_mut::<&mut Vec<Value>>("/doc/title");
+
+	// -- Check
+	assert!(not_string);
+	assert!(matches!(err, Err(JsonValueExtError::PropertyValueNotOfType { .. })));
+	assert!(matches!(
+		missing_err,
+		JsonValueExtError::PropertyNotFound { resolved, missing, suggestions, .. }
+			if resolved == "/doc" && missing == "titl" && suggestions == ["title"]
+	));
+	assert!(matches!(
+		index_err,
+		JsonValueExtError::PropertyNotFound { resolved, missing, .. } if resolved == "/doc/tags" && missing == "5"
+	));
+	assert_eq!(
+		value,
+		json!({"doc": {"title": "Hello World", "tags": ["a", "b"], "meta": {"v": 1}}})
+	);
+
+	Ok(())
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "bson")]
+
+use bson::oid::ObjectId;
+use bson::{doc, Bson, DateTime, Document};
+use value_ext::{BsonValueExt, BsonValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_bson_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let oid = ObjectId::new();
+	let mut value = Bson::Document(doc! {
+		"_id": oid,
+		"owner_id": oid.to_hex(),
+		"name": "Jen",
+		"created": DateTime::from_millis(1_700_000_000_000),
+		"updated": "2023-11-14T22:13:20Z",
+		"items": [1, 2],
+	});
+
+	// -- Exec
+	let id = value.x_get_object_id("_id")?;
+	let owner_id = value.x_get_object_id("owner_id")?;
+	let created = value.x_get_datetime("created")?;
+	let updated = value.x_get_datetime("updated")?;
+	let name: String = value.x_take("name")?;
+	value.x_insert("/items/-", 3)?;
+	value.x_insert("/meta/tags/0", "a")?;
+	let oid_err = value.x_get_object_id("items").unwrap_err();
+
+	// -- Check
+	assert_eq!(id, oid);
+	assert_eq!(owner_id, oid);
+	assert_eq!(created.timestamp_millis(), 1_700_000_000_000);
+	assert_eq!(updated, created);
+	assert_eq!(name, "Jen");
+	assert_eq!(value.x_get::<Option<String>>("name")?, None);
+	assert_eq!(value.x_get::<Vec<i32>>("items")?, [1, 2, 3]);
+	assert_eq!(value.x_get::<String>("/meta/tags/0")?, "a");
+	assert!(matches!(
+		oid_err,
+		BsonValueExtError::PropertyValueNotOfType { actual: "array", .. }
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_bson_document_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut document = Document::x_new_document();
+	document.x_insert("/user/name", "Jen")?;
+
+	// -- Exec
+	let name: String = document.x_get("/user/name")?;
+	let err = document.x_get::<String>("/user/nam").unwrap_err();
+	let mut keys = Vec::new();
+	document.x_walk(|_doc, key| {
+		keys.push(key.to_string());
+		true
+	});
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(keys, ["user", "name"]);
+	let BsonValueExtError::PropertyNotFound {
+		resolved,
+		missing,
+		suggestions,
+		..
+	} = err
+	else {
+		panic!("Expected PropertyNotFound, got {err:?}");
+	};
+	assert_eq!(resolved, "/user");
+	assert_eq!(missing, "nam");
+	assert_eq!(suggestions, ["name"]);
+
+	Ok(())
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "cbor")]
+
+use ciborium::value::Value;
+use value_ext::{CborValueExt, CborValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_cbor_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = Value::Map(vec![
+		(Value::Text("name".into()), Value::Text("Jen".into())),
+		(
+			Value::Text("created".into()),
+			Value::Tag(1, Box::new(Value::Integer(1_700_000_000.into()))),
+		),
+		(Value::Text("key".into()), Value::Bytes(vec![1, 2, 3])),
+		(Value::Integer(7.into()), Value::Text("seven".into())),
+		(
+			Value::Text("items".into()),
+			Value::Array(vec![Value::Integer(1.into())]),
+		),
+	]);
+
+	// -- Exec
+	let name: String = value.x_get("name")?;
+	let created: i64 = value.x_get("created")?;
+	let (tag, _) = value.x_get_tag("created")?;
+	let key_bytes = value.x_get_bytes("key")?.to_vec();
+	let key_vec: Vec<u8> = value.x_get("key")?;
+	let seven: String = value.x_get("/7")?;
+	let taken: String = value.x_take("/7")?;
+	value.x_insert("/items/-", 2)?;
+	value.x_insert("/meta/tags/0", "a")?;
+	let bytes_err = value.x_get_bytes("items").unwrap_err();
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(created, 1_700_000_000);
+	assert_eq!(tag, 1);
+	assert_eq!(key_bytes, [1, 2, 3]);
+	assert_eq!(key_vec, [1, 2, 3]);
+	assert_eq!(seven, "seven");
+	assert_eq!(taken, "seven");
+	assert!(value.x_get::<Option<String>>("/7")?.is_none());
+	assert_eq!(value.x_get::<Vec<i32>>("items")?, [1, 2]);
+	assert_eq!(value.x_get::<String>("/meta/tags/0")?, "a");
+	assert!(matches!(
+		bytes_err,
+		CborValueExtError::PropertyValueNotOfType { actual: "array", .. }
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_cbor_walk_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = Value::Map(vec![
+		(Value::Text("a".into()), Value::Integer(1.into())),
+		(
+			Value::Text("b".into()),
+			Value::Tag(
+				55799,
+				Box::new(Value::Map(vec![(Value::Text("c".into()), Value::Null)])),
+			),
+		),
+	]);
+
+	// -- Exec
+	let mut keys = Vec::new();
+	value.x_walk(|_map, key| {
+		keys.push(key.as_text().unwrap_or_default().to_string());
+		true
+	});
+
+	// -- Check
+	assert_eq!(keys, ["a", "b", "c"]);
+
+	Ok(())
+}
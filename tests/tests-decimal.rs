@@ -0,0 +1,40 @@
+#![cfg(feature = "decimal")]
+
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use value_ext::{DecimalFormat, DecimalValue, JsonValueExt};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_decimal_get_and_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"price": 12.34, "amount": "1234567890.123456789", "sci": "1.5e3", "name": "x"});
+
+	// -- Exec
+	let price: Decimal = value.x_get_as("price")?;
+	let amount: Decimal = value.x_get_as("amount")?;
+	let sci: Decimal = value.x_get_as("sci")?;
+	let not_decimal: Option<Decimal> = value.x_get_as("name")?;
+	value.x_insert("/total/as_string", DecimalValue::from(amount))?;
+	value.x_insert("/total/as_number", DecimalValue::number(Decimal::from_str("42.50")?))?;
+	value.x_insert(
+		"/total/items",
+		[
+			DecimalValue::new(Decimal::from_str("0.10")?, DecimalFormat::String),
+			DecimalValue::number(Decimal::from(3)),
+		],
+	)?;
+
+	// -- Check
+	assert_eq!(price, Decimal::from_str("12.34")?);
+	assert_eq!(amount.to_string(), "1234567890.123456789");
+	assert_eq!(sci, Decimal::from(1500));
+	assert!(not_decimal.is_none());
+	assert_eq!(value.x_get_str("/total/as_string")?, "1234567890.123456789");
+	assert_eq!(value.x_get_f64("/total/as_number")?, 42.5);
+	assert_eq!(value.x_get::<Value>("/total/items")?, json!(["0.10", 3]));
+
+	Ok(())
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "derive")]
+
+use serde_json::json;
+use value_ext::{AsType, JsonValueExt, JsonValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[derive(Debug, AsType)]
+struct UserView<'a> {
+	name: &'a str,
+	age: i64,
+	#[as_type(rename = "nickName")]
+	nick_name: Option<&'a str>,
+	admin: Option<bool>,
+}
+
+#[derive(Debug, AsType)]
+struct Point {
+	x: f64,
+	y: f64,
+}
+
+#[derive(Debug, AsType)]
+struct Kind<'a> {
+	r#type: &'a str,
+}
+
+#[test]
+fn test_derive_as_type_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"user": {"name": "Jen", "age": 32, "nickName": "J"},
+		"point": {"x": 1.5, "y": -2.0}
+	});
+
+	// -- Exec
+	let user: UserView = value.x_get_as("user")?;
+	let point: Point = value.x_get_as("/point")?;
+
+	// -- Check
+	assert_eq!(user.name, "Jen");
+	assert_eq!(user.age, 32);
+	assert_eq!(user.nick_name, Some("J"));
+	assert_eq!(user.admin, None);
+	assert_eq!((point.x, point.y), (1.5, -2.0));
+
+	Ok(())
+}
+
+#[test]
+fn test_derive_as_type_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"missing_age": {"name": "Jen"}, "bad_age": {"name": "Jen", "age": "32"}});
+
+	// -- Exec
+	let missing = value.x_get_as::<UserView>("missing_age");
+	let bad = value.x_get_as::<UserView>("bad_age");
+
+	// -- Check
+	assert!(matches!(missing, Err(JsonValueExtError::PropertyNotFound { name, .. }) if name == "age"));
+	assert!(matches!(bad, Err(JsonValueExtError::PropertyValueNotOfType { name, .. }) if name == "age"));
+
+	Ok(())
+}
+
+#[test]
+fn test_derive_as_type_raw_ident_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"kind": {"type": "a"}});
+
+	// -- Exec
+	let kind: Kind = value.x_get_as("kind")?;
+
+	// -- Check
+	assert_eq!(kind.r#type, "a");
+
+	Ok(())
+}
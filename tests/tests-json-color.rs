@@ -0,0 +1,28 @@
+#![cfg(feature = "color")]
+
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_json_pretty_colored_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "Jen", "age": 3, "ok": true, "none": null});
+
+	// -- Exec
+	std::env::remove_var("NO_COLOR");
+	let colored = fx_value.x_pretty_colored();
+	std::env::set_var("NO_COLOR", "1");
+	let no_color = fx_value.x_pretty_colored();
+	std::env::remove_var("NO_COLOR");
+
+	// -- Check
+	assert!(colored.contains("\x1b[34m\"name\"\x1b[0m: \x1b[32m\"Jen\"\x1b[0m"));
+	assert!(colored.contains("\x1b[36m3\x1b[0m"));
+	assert!(colored.contains("\x1b[33mtrue\x1b[0m"));
+	assert!(colored.contains("\x1b[90mnull\x1b[0m"));
+	assert_eq!(no_color, fx_value.x_pretty()?);
+
+	Ok(())
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "tokio")]
+
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use value_ext::{JsonValueExt, JsonValueExtError, PrettyOptions, SaveOptions};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+fn fx_path(name: &str) -> PathBuf {
+	std::env::temp_dir().join(format!("value-ext-test-async-{}-{name}", std::process::id()))
+}
+
+#[tokio::test]
+async fn test_json_save_load_file_async_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "Jen", "tags": ["a", "b"]});
+	let (compact_path, pretty_path) = (fx_path("compact.json"), fx_path("pretty.json"));
+
+	// -- Exec
+	fx_value.x_save_file_async(&compact_path).await?;
+	fx_value
+		.x_save_file_with_async(
+			&pretty_path,
+			SaveOptions::default()
+				.with_pretty(PrettyOptions::default())
+				.with_atomic(false),
+		)
+		.await?;
+
+	// -- Check
+	let pretty = std::fs::read_to_string(&pretty_path)?;
+	assert_eq!(Value::x_load_file_async(&compact_path).await?, fx_value);
+	assert_eq!(Value::x_load_file_async(&pretty_path).await?, fx_value);
+	std::fs::remove_file(&compact_path)?;
+	std::fs::remove_file(&pretty_path)?;
+	assert_eq!(pretty, fx_value.x_pretty()?);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_json_load_file_async_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let (invalid_path, missing_path) = (fx_path("invalid.json"), fx_path("missing.json"));
+	std::fs::write(&invalid_path, "{\"name\": ")?;
+
+	// -- Exec
+	let invalid = Value::x_load_file_async(&invalid_path).await;
+	let missing = Value::x_load_file_async(&missing_path).await;
+	std::fs::remove_file(&invalid_path)?;
+
+	// -- Check
+	assert!(matches!(invalid, Err(JsonValueExtError::FileNotParsable { path, .. }) if path == invalid_path));
+	assert!(matches!(missing, Err(JsonValueExtError::FileIo { path, .. }) if path == missing_path));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_json_to_async_writer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let items: Vec<Value> = (0..2_000)
+		.map(|idx| json!({"id": idx, "name": format!("item \"{idx}\""), "tags": [], "meta": {}}))
+		.collect();
+	let fx_values = [
+		json!({"items": items, "empty": [], "nested": [[1, [2]], {"a": {"b": null}}], "ok": true}),
+		json!("just a string"),
+		json!([]),
+	];
+
+	for fx_value in fx_values {
+		// -- Exec
+		let mut buf: Vec<u8> = Vec::new();
+		fx_value.x_to_async_writer(&mut buf).await?;
+
+		// -- Check
+		assert_eq!(String::from_utf8(buf)?, serde_json::to_string(&fx_value)?);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,98 @@
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use value_ext::{JsonValueExt, JsonValueExtError, PrettyOptions, SaveOptions};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+fn fx_path(name: &str) -> PathBuf {
+	std::env::temp_dir().join(format!("value-ext-test-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn test_json_save_load_file_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "Jen", "tags": ["a", "b"]});
+	let (compact_path, pretty_path) = (fx_path("compact.json"), fx_path("pretty.json"));
+
+	// -- Exec
+	fx_value.x_save_file(&compact_path)?;
+	fx_value.x_save_file_with(
+		&pretty_path,
+		SaveOptions::default()
+			.with_pretty(PrettyOptions::default().with_trailing_newline(true))
+			.with_atomic(false),
+	)?;
+
+	// -- Check
+	let compact = std::fs::read_to_string(&compact_path)?;
+	let pretty = std::fs::read_to_string(&pretty_path)?;
+	assert_eq!(Value::x_load_file(&compact_path)?, fx_value);
+	assert_eq!(Value::x_load_file(&pretty_path)?, fx_value);
+	std::fs::remove_file(&compact_path)?;
+	std::fs::remove_file(&pretty_path)?;
+	assert!(!compact.contains('\n'));
+	assert_eq!(pretty, format!("{}\n", fx_value.x_pretty()?));
+	// no temporary file left
+	let file_name = compact_path
+		.file_name()
+		.and_then(|name| name.to_str())
+		.unwrap_or_default();
+	let leftovers = std::fs::read_dir(std::env::temp_dir())?
+		.filter_map(|entry| entry.ok())
+		.filter(|entry| {
+			entry
+				.file_name()
+				.to_string_lossy()
+				.starts_with(&format!(".{file_name}"))
+		})
+		.count();
+	assert_eq!(leftovers, 0);
+
+	Ok(())
+}
+
+#[test]
+fn test_json_load_file_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let (invalid_path, missing_path) = (fx_path("invalid.json"), fx_path("missing.json"));
+	std::fs::write(&invalid_path, "{\"name\": ")?;
+
+	// -- Exec
+	let invalid = Value::x_load_file(&invalid_path);
+	let missing = Value::x_load_file(&missing_path);
+	std::fs::remove_file(&invalid_path)?;
+
+	// -- Check
+	assert!(matches!(invalid, Err(JsonValueExtError::FileNotParsable { path, .. }) if path == invalid_path));
+	assert!(matches!(missing, Err(JsonValueExtError::FileIo { path, .. }) if path == missing_path));
+
+	Ok(())
+}
+
+#[test]
+fn test_json_save_file_concurrent_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let path = fx_path("concurrent.json");
+
+	// -- Exec
+	let results: Vec<bool> = std::thread::scope(|scope| {
+		let handles: Vec<_> = (0..8)
+			.map(|idx| {
+				let path = &path;
+				scope.spawn(move || json!({"writer": idx}).x_save_file(path).is_ok())
+			})
+			.collect();
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap_or(false))
+			.collect()
+	});
+
+	// -- Check
+	assert!(results.iter().all(|ok| *ok));
+	let writer: i64 = Value::x_load_file(&path)?.x_get("writer")?;
+	std::fs::remove_file(&path)?;
+	assert!((0..8).contains(&writer));
+
+	Ok(())
+}
@@ -0,0 +1,65 @@
+use serde_json::{json, Value};
+use value_ext::{lines, JsonValueExt, JsonValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_lines_read_jsonl_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_jsonl = "{\"id\":1}\n\n  [1, 2]  \r\nnot json\n\"last\"";
+
+	// -- Exec
+	let res: Vec<_> = lines::read_jsonl(fx_jsonl.as_bytes()).collect();
+
+	// -- Check
+	assert_eq!(res.len(), 4);
+	assert_eq!(res[0].as_ref().ok(), Some(&json!({"id": 1})));
+	assert_eq!(res[1].as_ref().ok(), Some(&json!([1, 2])));
+	assert!(matches!(
+		res[2],
+		Err(JsonValueExtError::InvalidJsonLine { line: 4, .. })
+	));
+	assert_eq!(res[3].as_ref().ok(), Some(&json!("last")));
+
+	Ok(())
+}
+
+#[test]
+fn test_lines_write_jsonl_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_values = [json!({"text": "multi\nline"}), json!(null), json!([1, {"a": true}])];
+
+	// -- Exec
+	let mut buf = Vec::new();
+	let count = lines::write_jsonl(&mut buf, &fx_values)?;
+
+	// -- Check
+	assert_eq!(count, 3);
+	let text = String::from_utf8(buf)?;
+	assert_eq!(text, "{\"text\":\"multi\\nline\"}\nnull\n[1,{\"a\":true}]\n");
+	let read_back = lines::read_jsonl(text.as_bytes()).collect::<core::result::Result<Vec<Value>, _>>()?;
+	assert_eq!(read_back, fx_values);
+
+	Ok(())
+}
+
+#[test]
+fn test_lines_append_jsonl_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let path = std::env::temp_dir().join(format!("value-ext-test-append-{}.jsonl", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+
+	// -- Exec
+	json!({"event": "start"}).x_append_jsonl(&path)?;
+	json!({"event": "stop"}).x_append_jsonl(&path)?;
+
+	// -- Check
+	let content = std::fs::read_to_string(&path)?;
+	std::fs::remove_file(&path)?;
+	assert_eq!(content, "{\"event\":\"start\"}\n{\"event\":\"stop\"}\n");
+	let dir_path = std::env::temp_dir();
+	let dir_err = json!({"event": "start"}).x_append_jsonl(&dir_path);
+	assert!(matches!(dir_err, Err(JsonValueExtError::FileIo { path, .. }) if path == dir_path));
+
+	Ok(())
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "preserve_order")]
+
+use serde_json::{json, Value};
+use value_ext::{JsonValueExt, JsonValueExtError, TrackedValue};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+fn keys(value: &Value) -> Vec<&str> {
+	value
+		.as_object()
+		.map(|map| map.keys().map(String::as_str).collect())
+		.unwrap_or_default()
+}
+
+#[test]
+fn test_order_insert_at_position_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"server": {"port": 80, "tls": false}});
+
+	// -- Exec
+	value.x_insert_at_position("/server/host", 0, "localhost")?;
+	value.x_insert_at_position("/server/tls", 1, true)?;
+	value.x_insert_at_position("/server/name", 3, "main")?;
+	value.x_insert_at_position("/db/url", 0, "pg://")?;
+	let out_of_bounds = value.x_insert_at_position("/server/extra", 5, 1);
+	let array_parent = json!({"items": [1]}).x_insert_at_position("/items/0", 0, 2);
+
+	// -- Check
+	assert_eq!(keys(&value["server"]), ["host", "tls", "port", "name"]);
+	assert_eq!(value["server"]["tls"], json!(true));
+	assert_eq!(keys(&value), ["server", "db"]);
+	assert!(matches!(
+		out_of_bounds,
+		Err(JsonValueExtError::IndexOutOfBounds { index: 5, len: 4, .. })
+	));
+	assert!(value.x_get::<Value>("/server/extra").is_err());
+	assert!(matches!(array_parent, Err(JsonValueExtError::NotAnObject { .. })));
+
+	Ok(())
+}
+
+#[test]
+fn test_order_move_key_before_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"server": {"host": "localhost", "tls": true, "port": 80}});
+
+	// -- Exec
+	value.x_move_key_before("/server/port", "/server/tls")?;
+	value.x_move_key_before("/server/host", "/server/host")?;
+	let not_sibling = value.x_move_key_before("/server/port", "server");
+	let missing = value.x_move_key_before("/server/port", "/server/nope");
+
+	// -- Check
+	assert_eq!(keys(&value["server"]), ["host", "port", "tls"]);
+	assert!(matches!(not_sibling, Err(JsonValueExtError::InvalidPath { .. })));
+	assert!(matches!(missing, Err(JsonValueExtError::PropertyNotFound { .. })));
+
+	Ok(())
+}
+
+#[test]
+fn test_order_removals_keep_order() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"c": 1, "a": 2, "d": 3, "b": 4, "arr": [1]});
+
+	// -- Exec
+	value.x_remove::<Value>("a")?;
+	value.x_move("/d", "/e")?;
+	// fails (a scalar parent), so `b` is put back at its position
+	let failed_move = value.x_move("/b", "/c/x");
+
+	// -- Check
+	assert!(failed_move.is_err());
+	assert_eq!(keys(&value), ["c", "b", "arr", "e"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_order_tracked_undo_remove_keeps_position() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = TrackedValue::new(json!({"c": 1, "a": 2, "b": 3}));
+
+	// -- Exec
+	value.x_remove::<Value>("a")?;
+	value.undo()?;
+
+	// -- Check
+	assert_eq!(keys(&value), ["c", "a", "b"]);
+
+	Ok(())
+}
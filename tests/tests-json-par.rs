@@ -0,0 +1,31 @@
+#![cfg(feature = "rayon")]
+
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_par_walk_and_leaves_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let items: Vec<Value> = (0..100).map(|i| json!({"id": i, "tags": ["a", "b"]})).collect();
+	let root_value = json!({"items": items, "name": "big"});
+
+	// -- Exec
+	let node_count = AtomicUsize::new(0);
+	let completed = root_value.x_par_walk_ref(|_path, _value| {
+		node_count.fetch_add(1, Ordering::Relaxed);
+		true
+	});
+	let par_leaves = root_value.x_par_leaves();
+	let leaves: Vec<(String, &Value)> = root_value.x_leaves().collect();
+
+	// -- Check
+	assert!(completed);
+	// root + items array + name + 100 * (object + id + tags array + 2 tags)
+	assert_eq!(node_count.load(Ordering::Relaxed), 3 + 100 * 5);
+	assert_eq!(par_leaves, leaves);
+
+	Ok(())
+}
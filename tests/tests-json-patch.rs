@@ -0,0 +1,149 @@
+use serde_json::json;
+use value_ext::{DiffChange, DiffOptions, JsonValueExt, JsonValueExtError, Patch, PatchOperation};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_patch_diff_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_from = json!({"name": "app", "old": true, "port": 80, "tags": ["a", "b", "c"]});
+	let fx_to = json!({"name": "app", "port": 8080, "tags": ["a"], "new": {"x": 1}});
+
+	// -- Exec
+	let patch = fx_from.x_diff(&fx_to);
+
+	// -- Check
+	let patch_json = serde_json::to_value(&patch)?;
+	assert_eq!(
+		patch_json,
+		json!([
+			{"op": "remove", "path": "/old"},
+			{"op": "replace", "path": "/port", "value": 8080},
+			{"op": "remove", "path": "/tags/2"},
+			{"op": "remove", "path": "/tags/1"},
+			{"op": "add", "path": "/new", "value": {"x": 1}}
+		])
+	);
+	assert!(fx_from.x_diff(&fx_from).is_empty());
+
+	Ok(())
+}
+
+#[test]
+fn test_patch_diff_with_options_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_from = json!({"list": ["a", "b", "c"], "v": 1});
+	let fx_to = json!({"list": ["c", "a", "b"], "v": 2});
+	let options = DiffOptions::default()
+		.with_prefer_replace(false)
+		.with_detect_moves(true);
+
+	// -- Exec
+	let patch = fx_from.x_diff_with(&fx_to, options);
+
+	// -- Check
+	assert_eq!(
+		patch.operations(),
+		&[
+			PatchOperation::Move {
+				from: "/list/2".to_string(),
+				path: "/list/0".to_string()
+			},
+			PatchOperation::Remove { path: "/v".to_string() },
+			PatchOperation::Add {
+				path: "/v".to_string(),
+				value: json!(2)
+			},
+		]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_patch_diff_report_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_old = json!({"name": "app", "old": true, "port": 80, "tags": ["a", "b"]});
+	let fx_new = json!({"extra": {"x": 1}, "name": "app", "port": "80", "tags": ["a"]});
+
+	// -- Exec
+	let report = fx_old.x_diff_report(&fx_new);
+
+	// -- Check
+	assert_eq!(report.added().collect::<Vec<_>>(), vec![("/extra", &json!({"x": 1}))]);
+	assert_eq!(
+		report.removed().collect::<Vec<_>>(),
+		vec![("/old", &json!(true)), ("/tags/1", &json!("b"))]
+	);
+	assert_eq!(
+		report.changed().collect::<Vec<_>>(),
+		vec![("/port", &json!(80), &json!("80"))]
+	);
+	assert!(matches!(&report.changes()[0], DiffChange::Removed { path, .. } if path == "/old"));
+	assert!(fx_old.x_diff_report(&fx_old).is_empty());
+
+	Ok(())
+}
+
+#[test]
+fn test_patch_diff_apply_roundtrip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_pairs = [
+		(
+			json!({"name": "app", "old": true, "port": 80, "tags": ["a", "b", "c"]}),
+			json!({"name": "app", "port": 8080, "tags": ["a"], "new": {"x": 1}}),
+		),
+		(
+			json!({"list": ["a", "b", "c"], "v": 1}),
+			json!({"list": ["c", "a", "b"], "v": 2}),
+		),
+		(
+			json!({"list": [1, 2, 3, 4, 5], "nested": {"a": [{"x": 1}, {"y": 2}]}}),
+			json!({"list": [5, 3, 1, 6], "nested": {"a": [{"y": 2}, {"x": 1}, {"z": 3}]}}),
+		),
+		(json!({"a/b": {"c~d": 1}}), json!({"a/b": {"c~d": 2, "e": [true]}})),
+		(json!([1, 2]), json!({"not": "an array"})),
+	];
+
+	for (fx_from, fx_to) in fx_pairs {
+		for (prefer_replace, detect_moves) in [(true, false), (false, false), (true, true), (false, true)] {
+			let options = DiffOptions::default()
+				.with_prefer_replace(prefer_replace)
+				.with_detect_moves(detect_moves);
+
+			// -- Exec
+			let patch = fx_from.x_diff_with(&fx_to, options);
+			let mut value = fx_from.clone();
+			value.x_apply_patch(&patch)?;
+
+			// -- Check
+			assert_eq!(value, fx_to, "patch {patch:?} (detect_moves: {detect_moves})");
+		}
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_patch_apply_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"a": 1, "b": [1]});
+	let patch: Patch = serde_json::from_value(json!([
+		{"op": "replace", "path": "/a", "value": 2},
+		{"op": "test", "path": "/b/0", "value": 2}
+	]))?;
+	let mut value = fx_value.clone();
+
+	// -- Exec
+	let res = value.x_apply_patch(&patch);
+
+	// -- Check
+	assert!(matches!(
+		res,
+		Err(JsonValueExtError::PatchTestFailed { path, expected, actual_snippet })
+			if path == "/b/0" && expected == json!(2) && actual_snippet == "1"
+	));
+	assert_eq!(value, fx_value);
+
+	Ok(())
+}
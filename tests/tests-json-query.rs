@@ -0,0 +1,64 @@
+use serde_json::{json, Value};
+use value_ext::{JsonQuery, JsonValueExt};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+fn fx_store() -> Value {
+	json!({
+		"store": {
+			"book": [
+				{"title": "Sayings", "price": 8.95, "category": "reference"},
+				{"title": "Sword", "price": 12.99, "category": "fiction"},
+				{"title": "Moby Dick", "price": 8.99, "category": "fiction", "isbn": "0-553"},
+				{"title": "The Lord", "price": 22.99, "category": "fiction", "isbn": "0-395"}
+			],
+			"bicycle": {"color": "red", "price": 19.95}
+		}
+	})
+}
+
+#[test]
+fn test_query_filter_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = fx_store();
+
+	// -- Exec
+	let titles = value.x_query("$.store.book[?(@.price < 10)].title")?;
+	let fiction_isbn = value.x_query("$..book[?(@.category == 'fiction' && @.isbn)].title")?;
+	let prices = value.x_query("$.store..price")?;
+	let last = value.x_query("$.store.book[-1].title")?;
+	let slice = value.x_query("$.store.book[1:3].title")?;
+	let groups_and = value.x_query("$.store.book[?(@.price < 10) && (@.category == 'fiction')].title")?;
+	let groups_or = value.x_query("$.store.book[?(@.price > 20) || (@.category == 'reference')].title")?;
+
+	// -- Check
+	assert_eq!(titles, vec![&json!("Sayings"), &json!("Moby Dick")]);
+	assert_eq!(fiction_isbn, vec![&json!("Moby Dick"), &json!("The Lord")]);
+	assert_eq!(prices.len(), 5);
+	assert_eq!(last, vec![&json!("The Lord")]);
+	assert_eq!(slice, vec![&json!("Sword"), &json!("Moby Dick")]);
+	assert_eq!(groups_and, vec![&json!("Moby Dick")]);
+	assert_eq!(groups_or, vec![&json!("Sayings"), &json!("The Lord")]);
+
+	Ok(())
+}
+
+#[test]
+fn test_query_compiled_reuse_and_mut_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = fx_store();
+	let other = json!({"store": {"book": [{"title": "Other", "price": 1}]}});
+	let query = JsonQuery::compile("$.store.book[*].price")?;
+
+	// -- Exec
+	let count = value.x_query_mut("$.store.book[*].price", |price| *price = json!(0))?;
+	let paths = query.find_paths(&other);
+
+	// -- Check
+	assert_eq!(count, 4);
+	assert!(query.find(&value).iter().all(|p| **p == json!(0)));
+	assert_eq!(paths, vec!["/store/book/0/price".to_string()]);
+	assert!(JsonQuery::compile("store.book").is_err());
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "raw_value")]
+
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_json_get_raw_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"payload": {"items": [1, "two", {"three": null}], "ok": true}, "other": 1});
+
+	// -- Exec
+	let raw = fx_value.x_get_raw("/payload/items")?;
+	let missing = fx_value.x_get_raw("/payload/nope");
+
+	// -- Check
+	assert_eq!(raw.get(), r#"[1,"two",{"three":null}]"#);
+	assert!(missing.is_err());
+
+	Ok(())
+}
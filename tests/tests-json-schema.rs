@@ -0,0 +1,90 @@
+use serde_json::json;
+use value_ext::JsonValueExt;
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_json_schema_infer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"name": "Jen",
+		"age": 42,
+		"tags": ["a", "b"],
+		"items": [{"id": 1, "price": 2.5}, {"id": 2, "price": 3, "note": null}],
+		"empty": []
+	});
+
+	// -- Exec
+	let schema = value.x_infer_schema();
+
+	// -- Check
+	let expected = json!({
+		"type": "object",
+		"properties": {
+			"age": {"type": "integer"},
+			"empty": {"type": "array"},
+			"items": {
+				"type": "array",
+				"items": {
+					"type": "object",
+					"properties": {
+						"id": {"type": "integer"},
+						"note": {"type": "null"},
+						"price": {"type": "number"}
+					},
+					"required": ["id", "price"]
+				}
+			},
+			"name": {"type": "string"},
+			"tags": {"type": "array", "items": {"type": "string"}}
+		},
+		"required": ["age", "empty", "items", "name", "tags"]
+	});
+	assert_eq!(schema, expected);
+
+	Ok(())
+}
+
+#[test]
+fn test_json_schema_coerce_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let schema = json!({
+		"type": "object",
+		"properties": {
+			"id": {"type": "integer"},
+			"active": {"type": "boolean"},
+			"code": {"type": "string"},
+			"tags": {"type": "array", "items": {"type": "string"}},
+			"owner": {"$ref": "#/$defs/user"},
+			"note": {"type": ["number", "null"]}
+		},
+		"$defs": {"user": {"type": "object", "properties": {"age": {"type": "integer"}}}}
+	});
+	let mut value = json!({
+		"id": "42",
+		"active": "true",
+		"code": 7,
+		"tags": "solo",
+		"owner": "{\"age\": \"30\"}",
+		"note": null,
+		"extra": "1"
+	});
+
+	// -- Exec
+	let count = value.x_coerce_to_schema(&schema);
+
+	// -- Check
+	let expected = json!({
+		"id": 42,
+		"active": true,
+		"code": "7",
+		"tags": ["solo"],
+		"owner": {"age": 30},
+		"note": null,
+		"extra": "1"
+	});
+	assert_eq!(value, expected);
+	assert_eq!(count, 6);
+
+	Ok(())
+}
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use value_ext::{stream, JsonValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Item {
+	id: u32,
+	name: String,
+}
+
+#[test]
+fn test_stream_extract_pointer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_json = r#"{
+		"meta": {"count": 2, "tags": ["a", {"b": [1, 2]}]},
+		"data": {"items": [{"id": 1, "name": "one"}, {"id": 2, "name": "two"}], "next": null}
+	}"#;
+
+	// -- Exec
+	let items: Vec<Item> = stream::extract_pointer(fx_json.as_bytes(), "/data/items")?;
+	let name: String = stream::extract_pointer(fx_json.as_bytes(), "/data/items/1/name")?;
+	let next: Value = stream::extract_pointer(fx_json.as_bytes(), "/data/next")?;
+	let root: Value = stream::extract_pointer(fx_json.as_bytes(), "")?;
+
+	// -- Check
+	assert_eq!(
+		items,
+		vec![
+			Item {
+				id: 1,
+				name: "one".into()
+			},
+			Item {
+				id: 2,
+				name: "two".into()
+			}
+		]
+	);
+	assert_eq!(name, "two");
+	assert_eq!(next, Value::Null);
+	assert_eq!(root.pointer("/meta/count"), Some(&json!(2)));
+
+	Ok(())
+}
+
+#[test]
+fn test_stream_extract_pointer_stops_after_target() -> Result<()> {
+	// -- Setup & Fixtures
+	// the document is truncated (and invalid) after the target
+	let fx_json = r#"{"skip": {"big": [1, 2, 3]}, "target": {"ok": true}, "rest": [1, 2, !!!"#;
+
+	// -- Exec
+	let target: Value = stream::extract_pointer(fx_json.as_bytes(), "/target")?;
+	let rest = stream::extract_pointer::<Value>(fx_json.as_bytes(), "/rest");
+
+	// -- Check
+	assert_eq!(target, json!({"ok": true}));
+	assert!(matches!(rest, Err(JsonValueExtError::SerdeJson(_))));
+
+	Ok(())
+}
+
+#[test]
+fn test_stream_extract_pointer_not_found() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_json = r#"{"data": {"items": [{"id": 1}], "count": 1}}"#;
+
+	// -- Exec & Check
+	for (pointer, fx_resolved, fx_missing) in [
+		("/data/nope", "/data", "nope"),
+		("/data/items/3", "/data/items", "3"),
+		("/data/count/value", "/data/count", "value"),
+		("/data/items/first", "/data/items", "first"),
+	] {
+		match stream::extract_pointer::<Value>(fx_json.as_bytes(), pointer) {
+			Err(JsonValueExtError::PropertyNotFound {
+				name,
+				resolved,
+				missing,
+				..
+			}) => {
+				assert_eq!(name, pointer);
+				assert_eq!(resolved, fx_resolved, "pointer: {pointer}");
+				assert_eq!(missing, fx_missing, "pointer: {pointer}");
+			}
+			other => panic!("should be PropertyNotFound for {pointer}, but was {other:?}"),
+		}
+	}
+	assert!(matches!(
+		stream::extract_pointer::<Value>(fx_json.as_bytes(), "data"),
+		Err(JsonValueExtError::InvalidPath { .. })
+	));
+
+	Ok(())
+}
@@ -0,0 +1,177 @@
+use serde_json::{json, Value};
+use value_ext::{JsonValueExt, MergeOptions, Patch, TrackedValue};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_tracked_changes_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = TrackedValue::new(json!({"user": {"name": "Jen", "tags": ["a"]}, "tmp": 1, "token": "x"}));
+
+	// -- Exec
+	value.x_insert("/user/name", "Mike")?;
+	value.x_insert("/user/tags/-", "b")?;
+	value.x_insert("/user/address/city", "Paris")?;
+	let token: String = value.x_take("token")?;
+	let tmp: i64 = value.x_remove("/tmp")?;
+	let failed = value.x_insert("/user/name/first", "Mike");
+	let take_failed = value.x_take::<u32>("/user/name");
+	let remove_failed = value.x_remove::<u32>("/user/tags");
+
+	// -- Check
+	assert_eq!((token.as_str(), tmp), ("x", 1));
+	assert!(failed.is_err());
+	assert!(take_failed.is_err() && remove_failed.is_err());
+	assert_eq!(value.x_get::<String>("/user/address/city")?, "Paris");
+	let fx_changes: Patch = serde_json::from_value(json!([
+		{"op": "replace", "path": "/user/name", "value": "Mike"},
+		{"op": "add", "path": "/user/tags/1", "value": "b"},
+		{"op": "add", "path": "/user/address", "value": {"city": "Paris"}},
+		{"op": "replace", "path": "/token", "value": null},
+		{"op": "remove", "path": "/tmp"}
+	]))?;
+	assert_eq!(value.changes(), &fx_changes);
+	assert_eq!(value.take_changes(), fx_changes);
+	assert!(value.changes().is_empty());
+	assert_eq!(
+		Value::from(value),
+		json!({"user": {"name": "Mike", "tags": ["a", "b"], "address": {"city": "Paris"}}, "token": null})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_tracked_undo_redo_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"a": 1, "b": {"c": [1, 2]}, "d": "x"});
+	let mut value = TrackedValue::new(fx_value.clone());
+
+	// -- Exec
+	value.x_insert("/b/c/-", 3)?;
+	value.x_insert("/a", 10)?;
+	value.x_insert("/e/f", true)?;
+	value.x_take::<Value>("/d")?;
+	value.x_remove::<Value>("/b")?;
+	let edited = (*value).clone();
+	let mut undos = 0;
+	while value.undo()? {
+		undos += 1;
+	}
+	let undone = (*value).clone();
+	value.redo()?;
+	value.redo()?;
+
+	// -- Check
+	assert_eq!(undos, 5);
+	assert_eq!(undone, fx_value);
+	assert_eq!(*value, json!({"a": 10, "b": {"c": [1, 2, 3]}, "d": "x"}));
+	assert!(value.can_undo() && value.can_redo());
+	// a new edit clears the redos
+	value.x_insert("/g", 1)?;
+	assert!(!value.can_redo());
+	assert!(!value.redo()?);
+	// the undos and redos are in the changes, so replaying them gives the same value
+	let mut replica = fx_value.clone();
+	for operation in value.changes().operations() {
+		let mut tracked = TrackedValue::new(replica);
+		match operation {
+			value_ext::PatchOperation::Add { path, value } | value_ext::PatchOperation::Replace { path, value } => {
+				tracked.x_insert(path, value)?
+			}
+			value_ext::PatchOperation::Remove { path } => tracked.x_remove::<Value>(path).map(|_| ())?,
+			other => panic!("unexpected operation {other:?}"),
+		}
+		replica = tracked.into_inner();
+	}
+	assert_ne!(edited, *value);
+	assert_eq!(replica, *value);
+
+	Ok(())
+}
+
+#[test]
+fn test_tracked_trait_mutators_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"user": {"name": "Jen"}, "hits": 1});
+	let mut value = TrackedValue::new(fx_value.clone());
+
+	// -- Exec
+	value.x_merge(json!({"user": {"age": 42}}), MergeOptions::default());
+	value.x_rename("/user/name", "first_name")?;
+	*value.x_get_as_mut::<&mut Value>("hits")? = json!(2);
+	value.x_entry("/meta/source").or_insert("import")?;
+	let changes = value.take_changes();
+	let edited = (*value).clone();
+	let mut undos = 0;
+	while value.undo()? {
+		undos += 1;
+	}
+
+	// -- Check
+	assert_eq!(
+		edited,
+		json!({"user": {"first_name": "Jen", "age": 42}, "hits": 2, "meta": {"source": "import"}})
+	);
+	let fx_changes: Patch = serde_json::from_value(json!([
+		{"op": "add", "path": "/user/age", "value": 42},
+		{"op": "remove", "path": "/user/name"},
+		{"op": "add", "path": "/user/first_name", "value": "Jen"},
+		{"op": "replace", "path": "/hits", "value": 2},
+		{"op": "add", "path": "/meta", "value": {"source": "import"}}
+	]))?;
+	assert_eq!(changes, fx_changes);
+	assert_eq!(undos, 4);
+	assert_eq!(*value, fx_value);
+
+	Ok(())
+}
+
+#[test]
+fn test_tracked_history_depth_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = TrackedValue::new(json!({"n": 0})).with_history_depth(2);
+
+	// -- Exec
+	for n in 1..=4 {
+		value.x_insert("n", n)?;
+	}
+	let mut undos = 0;
+	while value.undo()? {
+		undos += 1;
+	}
+
+	// -- Check
+	assert_eq!(undos, 2);
+	assert_eq!(value.x_get::<i64>("n")?, 2);
+
+	Ok(())
+}
+
+#[test]
+fn test_tracked_history_depth_redo_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = TrackedValue::new(json!({"n": 0})).with_history_depth(4);
+	for n in 1..=4 {
+		value.x_insert("n", n)?;
+	}
+	while value.undo()? {}
+
+	// -- Exec
+	let mut value = value.with_history_depth(2);
+	let mut redos = 0;
+	while value.redo()? {
+		redos += 1;
+	}
+	let mut undos = 0;
+	while value.undo()? {
+		undos += 1;
+	}
+
+	// -- Check
+	assert_eq!(redos, 2);
+	assert_eq!(undos, 2);
+	assert_eq!(value.x_get::<i64>("n")?, 0);
+
+	Ok(())
+}
@@ -0,0 +1,68 @@
+use serde_json::{json, Map, Value};
+use value_ext::{JsonValueExt, JsonVisitor, WalkControl};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+/// Collects the max depth and the paths of the objects with a `type`, skipping `examples` subtrees.
+#[derive(Default)]
+struct SchemaLinter {
+	depth: usize,
+	max_depth: usize,
+	typed_paths: Vec<String>,
+	scalar_count: usize,
+}
+
+impl JsonVisitor for SchemaLinter {
+	fn enter_object(&mut self, path: &str, map: &Map<String, Value>) -> WalkControl {
+		self.depth += 1;
+		self.max_depth = self.max_depth.max(self.depth);
+		if map.contains_key("type") {
+			self.typed_paths.push(path.to_string());
+		}
+		WalkControl::Continue
+	}
+
+	fn leave_object(&mut self, _path: &str, _map: &Map<String, Value>) -> WalkControl {
+		self.depth -= 1;
+		WalkControl::Continue
+	}
+
+	fn visit_object_entry(&mut self, _path: &str, key: &str, _value: &Value) -> WalkControl {
+		if key == "examples" {
+			WalkControl::SkipChildren
+		} else {
+			WalkControl::Continue
+		}
+	}
+
+	fn visit_scalar(&mut self, _path: &str, _value: &Value) -> WalkControl {
+		self.scalar_count += 1;
+		WalkControl::Continue
+	}
+}
+
+#[test]
+fn test_visitor_accept_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let root_value = json!({
+		"type": "object",
+		"properties": {"name": {"type": "string"}, "tags": {"type": "array", "items": [{"type": "string"}]}},
+		"examples": [{"type": "ignored"}]
+	});
+	let mut linter = SchemaLinter::default();
+
+	// -- Exec
+	let completed = root_value.x_accept(&mut linter);
+
+	// -- Check
+	assert!(completed);
+	assert_eq!(linter.max_depth, 4);
+	assert_eq!(
+		linter.typed_paths,
+		vec!["", "/properties/name", "/properties/tags", "/properties/tags/items/0"]
+	);
+	assert_eq!(linter.scalar_count, 4);
+	assert_eq!(linter.depth, 0);
+
+	Ok(())
+}
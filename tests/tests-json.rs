@@ -1,5 +1,11 @@
-use serde_json::json;
-use value_ext::JsonValueExt;
+use serde_json::{json, Value};
+use std::hash::Hasher;
+use value_ext::{
+	escape_token, unescape_token, ArrayMerge, Case, CopyMode, DedupKeep, EnvOptions, EqOptions, Extractor,
+	FlattenArrays, FlattenKeys, FlattenOptions, JsonKind, JsonPath, JsonPointer, JsonValueExt, JsonValueExtError,
+	JsonValueExtErrorKind, MergeOptions, MissingVar, NullMerge, Order, PointerBuf, PrettyOptions, PruneOptions,
+	RedactRules, Redaction, TemplateOptions, WalkControl, WalkOrder,
+};
 
 type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
 
@@ -19,58 +25,1918 @@ fn test_value_insert_ok() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn test_value_builder_ok() -> Result<()> {
+	// -- Exec
+	let value = Value::x_builder()
+		.set("/user/name", "Jen")
+		.set("/user/age", 31)
+		.push("/tags", "admin")
+		.push("tags", "dev")
+		.set("/items/0/id", 1)
+		.build()?;
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"items": [{"id": 1}], "tags": ["admin", "dev"], "user": {"age": 31, "name": "Jen"}})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_builder_err() -> Result<()> {
+	// -- Exec
+	let set_res = Value::x_builder().set("/user", "Jen").set("/user/name", "Jen").build();
+	let push_res = Value::x_builder().set("/tags/a", 1).push("/tags", "dev").build();
+
+	// -- Check
+	assert!(matches!(set_res, Err(JsonValueExtError::NotAnObject { .. })));
+	assert!(matches!(push_res, Err(JsonValueExtError::NotAnArray { path, .. }) if path == "/tags"));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_rename_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"a/b": 1, "user": {"adress": "Main St", "name": "Jen", "zip": "1"}});
+	// the position is only kept with `preserve_order` (otherwise the keys are sorted)
+	let is_preserve_order = json!({"b": 0, "a": 0}).x_entries().next().map(|(key, _)| key) == Some("b");
+
+	// -- Exec
+	value.x_rename("/user/adress", "address")?;
+	value.x_rename("/a~1b", "ab")?;
+	value.x_rename("ab", "ab")?;
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"ab": 1, "user": {"address": "Main St", "name": "Jen", "zip": "1"}})
+	);
+	let mut ordered = json!({"first": 1, "second": 2});
+	ordered.x_rename("first", "third")?;
+	let keys: Vec<&str> = ordered.x_entries().map(|(key, _)| key).collect();
+	if is_preserve_order {
+		assert_eq!(keys, ["third", "second"]);
+	} else {
+		assert_eq!(keys, ["second", "third"]);
+	}
+	assert!(matches!(
+		value.x_rename("/user/name", "zip"),
+		Err(JsonValueExtError::PropertyAlreadyExists { path, .. }) if path == "/user/zip"
+	));
+	assert!(matches!(
+		value.x_rename("/user/nickname", "nick"),
+		Err(JsonValueExtError::PropertyNotFound { .. })
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_move_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": ["a", "b", "c"], "old": {"place": {"x": 1}}});
+
+	// -- Exec
+	value.x_move("/old/place", "/new/place")?;
+	value.x_move("/items/0", "/items/2")?;
+	value.x_move("/items/0", "/archive/-")?;
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"archive": ["b"], "items": ["c", "a"], "new": {"place": {"x": 1}}, "old": {}})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_move_into_array_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"a": ["x", "y", "z"], "b": "w"});
+
+	// -- Exec
+	value.x_move("/a/0", "/a/1")?;
+	value.x_move("/b", "/a/1")?;
+	value.x_copy("/a/0", "/a/-")?;
+
+	// -- Check
+	assert_eq!(value, json!({"a": ["y", "w", "x", "z", "y"]}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_move_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"items": ["a", "b"], "name": "Jen", "user": {"id": 1}});
+	let mut value = fx_value.clone();
+
+	// -- Exec & Check
+	assert!(matches!(
+		value.x_move("/user", "/name/first"),
+		Err(JsonValueExtError::NotAnObject { .. })
+	));
+	assert!(matches!(
+		value.x_move("/items/0", "/items/5"),
+		Err(JsonValueExtError::IndexOutOfBounds { .. })
+	));
+	assert!(matches!(
+		value.x_move("/user", "/user/copy"),
+		Err(JsonValueExtError::InvalidPath { .. })
+	));
+	assert!(matches!(
+		value.x_move("/nothing", "/other"),
+		Err(JsonValueExtError::PropertyNotFound { .. })
+	));
+	// unchanged on the errors
+	assert_eq!(value, fx_value);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_copy_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"defaults": {"retries": 3}, "services": {"api": {"retries": 1}}});
+
+	// -- Exec
+	value.x_copy("/defaults", "/services/web")?;
+	value.x_copy("/defaults/retries", "/services/api/retries")?;
+	let res = value.x_copy_with("/defaults", "/services/web", CopyMode::ErrorIfExists);
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"defaults": {"retries": 3}, "services": {"api": {"retries": 3}, "web": {"retries": 3}}})
+	);
+	assert!(matches!(res, Err(JsonValueExtError::PropertyAlreadyExists { path, .. }) if path == "/services/web"));
+	assert!(matches!(
+		value.x_copy("/defaults", "/defaults/retries/x"),
+		Err(JsonValueExtError::NotAnObject { .. })
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_insert_many_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"name": "Jen"});
+
+	// -- Exec
+	value.x_insert_many([
+		("/db/host", json!("localhost")),
+		("/db/port", json!(5432)),
+		("debug", json!(true)),
+	])?;
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"db": {"host": "localhost", "port": 5432}, "debug": true, "name": "Jen"})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_insert_many_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": [1], "name": "Jen"});
+
+	// -- Exec
+	let res = value.x_insert_many([("/name/first", "Jen"), ("/level", "high"), ("/items/5", "x")]);
+
+	// -- Check
+	let Err(err) = res else {
+		return Err("should have failed".into());
+	};
+	assert_eq!(err.kind(), JsonValueExtErrorKind::Multiple);
+	let JsonValueExtError::InsertManyFailed { failures } = err else {
+		return Err(format!("unexpected error: {err:?}").into());
+	};
+	let paths: Vec<&str> = failures.iter().map(|(path, _)| path.as_str()).collect();
+	assert_eq!(paths, ["/name/first", "/items/5"]);
+	assert!(matches!(failures[1].1, JsonValueExtError::IndexOutOfBounds { .. }));
+	// the valid insert is still applied
+	assert_eq!(value.x_get::<String>("/level")?, "high");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_array_ops_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": ["a", "c"]});
+
+	// -- Exec
+	value.x_push("/items", "d")?;
+	value.x_push("/user/tags", "admin")?;
+	value.x_insert_at("/items", 1, "b")?;
+	value.x_insert_at("items", 4, "e")?;
+	let removed: String = value.x_remove_at("/items", 0)?;
+
+	// -- Check
+	assert_eq!(removed, "a");
+	assert_eq!(
+		value,
+		json!({"items": ["b", "c", "d", "e"], "user": {"tags": ["admin"]}})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_array_ops_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": ["a"], "name": "Jen"});
+
+	// -- Exec & Check
+	assert!(matches!(
+		value.x_push("name", "b"),
+		Err(JsonValueExtError::NotAnArray { path, actual: "string", .. }) if path == "/name"
+	));
+	assert!(matches!(
+		value.x_insert_at("/items", 2, "b"),
+		Err(JsonValueExtError::IndexOutOfBounds { index: 2, len: 1, .. })
+	));
+	assert!(matches!(
+		value.x_remove_at::<String>("/items", 1),
+		Err(JsonValueExtError::IndexOutOfBounds { index: 1, len: 1, .. })
+	));
+	assert!(matches!(
+		value.x_remove_at::<String>("/tags", 0),
+		Err(JsonValueExtError::PropertyNotFound { .. })
+	));
+	assert_eq!(value, json!({"items": ["a"], "name": "Jen"}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_extend_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"name": "Jen", "tags": ["a"]});
+
+	// -- Exec
+	value.x_extend("/tags", ["b", "c"])?;
+	value.x_extend("/scores", (1..=3).map(|n| n * 10))?;
+	let res = value.x_extend("name", ["x"]);
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"name": "Jen", "scores": [10, 20, 30], "tags": ["a", "b", "c"]})
+	);
+	assert!(matches!(res, Err(JsonValueExtError::NotAnArray { .. })));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_group_by_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"items": [
+		{"category": "fruit", "name": "apple", "stock": 3},
+		{"category": "veggie", "name": "leek", "stock": 3},
+		{"category": "fruit", "name": "pear", "stock": 0},
+		{"name": "mystery"}
+	]});
+
+	// -- Exec
+	let by_category = value.x_group_by("/items", "/category")?;
+	let by_stock = value.x_group_by("items", "stock")?;
+
+	// -- Check
+	assert_eq!(
+		Value::Object(by_category),
+		json!({
+			"fruit": [
+				{"category": "fruit", "name": "apple", "stock": 3},
+				{"category": "fruit", "name": "pear", "stock": 0}
+			],
+			"veggie": [{"category": "veggie", "name": "leek", "stock": 3}]
+		})
+	);
+	assert_eq!(by_stock.get("3").and_then(Value::as_array).map(Vec::len), Some(2));
+	assert_eq!(by_stock.get("0").and_then(Value::as_array).map(Vec::len), Some(1));
+	assert!(matches!(
+		value.x_group_by("/items/0", "/category"),
+		Err(JsonValueExtError::NotAnArray { .. })
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_dedup_by_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"items": [
+		{"id": 1, "v": "a"},
+		{"id": 2, "v": "b"},
+		{"v": "c"},
+		{"id": 1, "v": "d"},
+		{"id": "1", "v": "e"},
+		{"id": 2, "v": "f"}
+	]});
+	let mut first = fx_value.clone();
+	let mut last = fx_value;
+
+	// -- Exec
+	let first_removed = first.x_dedup_by("/items", "/id", DedupKeep::First)?;
+	let last_removed = last.x_dedup_by("items", "id", DedupKeep::Last)?;
+
+	// -- Check
+	let values = |value: &Value| -> Result<Vec<String>> {
+		Ok(value.x_get_all("/items/*/v")?.into_iter().map(|(_, v)| v).collect())
+	};
+	assert_eq!((first_removed, last_removed), (2, 2));
+	assert_eq!(values(&first)?, ["a", "b", "c", "e"]);
+	assert_eq!(values(&last)?, ["c", "d", "e", "f"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_entry_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"tags": []});
+	let fx_incr = |hits: &mut Value| *hits = json!(hits.as_i64().unwrap_or_default() + 1);
+
+	// -- Exec
+	value.x_entry("/counters/hits").and_modify(fx_incr).or_insert(1)?;
+	value.x_entry("/counters/hits").and_modify(fx_incr).or_insert(1)?;
+	let tag = value.x_entry("/tags/-").or_insert_with(|| "new")?;
+	*tag = json!("a");
+	let is_occupied = value.x_entry("tags").is_occupied();
+	let missing = value.x_entry("/counters/misses");
+	let is_missing_occupied = missing.is_occupied();
+
+	// -- Check
+	assert_eq!(value, json!({"counters": {"hits": 2}, "tags": ["a"]}));
+	assert!(is_occupied);
+	assert!(!is_missing_occupied);
+	assert!(value.x_entry("/tags/a").or_insert(1).is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_or_insert_with_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"cache": {"users": ["a"]}});
+	let mut calls = 0;
+
+	// -- Exec
+	let users = value.x_get_or_insert_with("/cache/users", || {
+		calls += 1;
+		Vec::<String>::new()
+	})?;
+	users.as_array_mut().ok_or("should be an array")?.push(json!("b"));
+	let groups = value.x_get_or_insert_with("/cache/groups", || {
+		calls += 1;
+		vec!["admin"]
+	})?;
+	groups.as_array_mut().ok_or("should be an array")?.push(json!("dev"));
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"cache": {"groups": ["admin", "dev"], "users": ["a", "b"]}})
+	);
+	assert_eq!(calls, 1);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_insert_array_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": [{"name": "one"}]});
+
+	// -- Exec
+	value.x_insert("/items/0/name", "first")?;
+	value.x_insert("/items/-", json!({"name": "second"}))?;
+	value.x_insert("/items/2/name", "third")?;
+	value.x_insert("/matrix/0/0", 1)?;
+	let out_of_bounds = value.x_insert("/items/9", "nope");
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({
+			"items": [{"name": "first"}, {"name": "second"}, {"name": "third"}],
+			"matrix": [[1]]
+		})
+	);
+	assert!(out_of_bounds.is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_dot_path_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"user": {"address": {"city": "Paris"}}, "files": {"readme.md": 12}, "items": [{"id": 7}]});
+
+	// -- Exec
+	let city: String = value.x_get(&JsonPath::dot("user.address.city"))?;
+	let size = value.x_get_i64(&JsonPath::dot(r"files.readme\.md"))?;
+	let id = value.x_get_i64(&JsonPath::dot("items.0.id"))?;
+	value.x_insert(&JsonPath::dot("user.address.zip"), "75001")?;
+	let zip: String = value.x_take(&JsonPath::dot("user.address.zip"))?;
+
+	// -- Check
+	assert_eq!(city, "Paris");
+	assert_eq!(size, 12);
+	assert_eq!(id, 7);
+	assert_eq!(zip, "75001");
+	assert_eq!(JsonPath::dot("a/b.c~d").as_str(), "/a~1b/c~0d");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_json_pointer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut values = [
+		json!({"user": {"name": "Jen", "tags": ["a", "b"]}}),
+		json!({"user": {"name": "Mike", "tags": ["c"]}}),
+	];
+	let name_ptr = JsonPointer::parse("/user/name")?;
+	let tag_ptr = JsonPointer::parse("/user/tags/0")?;
+	let id_ptr = JsonPointer::parse("/user/meta/id")?;
+
+	// -- Exec
+	let mut names = Vec::new();
+	for (idx, value) in values.iter_mut().enumerate() {
+		names.push(value.x_get_pointer::<String>(&name_ptr)?);
+		let _tag: String = value.x_take_pointer(&tag_ptr)?;
+		value.x_insert_pointer(&id_ptr, idx)?;
+	}
+
+	// -- Check
+	assert_eq!(names, ["Jen", "Mike"]);
+	assert_eq!(
+		values[0],
+		json!({"user": {"meta": {"id": 0}, "name": "Jen", "tags": [null, "b"]}})
+	);
+	assert_eq!(values[1].x_get::<usize>(&id_ptr)?, 1);
+	assert_eq!(tag_ptr.tokens(), ["user", "tags", "0"]);
+	assert!(matches!(
+		values[0].x_get_pointer::<String>(&JsonPointer::parse("/user/tags/01")?),
+		Err(JsonValueExtError::PropertyNotFound { .. })
+	));
+	assert!(matches!(
+		JsonPointer::parse("user"),
+		Err(JsonValueExtError::InvalidPath { .. })
+	));
+	assert!(matches!(
+		JsonPointer::parse("/a~2"),
+		Err(JsonValueExtError::InvalidPath { .. })
+	));
+	assert_eq!(JsonPointer::parse("")?.resolve(&values[1]), Some(&values[1]));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_extractor_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"id": 7, "items": [{"id": "i1"}, {"id": "i2"}], "user": {"age": 31, "name": "Jen"}});
+	let extractor = Extractor::new(&[
+		"/user/name",
+		"/items/1/id",
+		"/user/age",
+		"/missing/x",
+		"/items/01/id",
+		"/id",
+	])?;
+
+	// -- Exec
+	let found = extractor.extract(&value);
+	let cloned = extractor.extract_cloned(&value);
+
+	// -- Check
+	assert_eq!(
+		found,
+		[
+			Some(&json!("Jen")),
+			Some(&json!("i2")),
+			Some(&json!(31)),
+			None,
+			None,
+			Some(&json!(7))
+		]
+	);
+	assert_eq!(cloned[3], Value::Null);
+	assert_eq!(extractor.pointers()[1].as_str(), "/items/1/id");
+	assert!(Extractor::new(&["/ok", "bad"]).is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_pointer_buf_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"files": {"docs/readme.md": [{"size": 12}]}, "a~b": 1});
+
+	// -- Exec
+	let mut pointer = PointerBuf::new();
+	pointer.push("files").push("docs/readme.md").push_index(0).push("size");
+	let size: i64 = value.x_get(&pointer)?;
+	let popped = pointer.pop();
+	pointer.push("owner");
+	value.x_insert(&pointer, "jen")?;
+	let tilde: PointerBuf = ["a~b"].into_iter().collect();
+
+	// -- Check
+	assert_eq!(size, 12);
+	assert_eq!(popped.as_deref(), Some("size"));
+	assert_eq!(pointer.as_str(), "/files/docs~1readme.md/0/owner");
+	assert_eq!(value.x_get::<String>("/files/docs~1readme.md/0/owner")?, "jen");
+	assert_eq!(tilde.to_string(), "/a~0b");
+	assert_eq!(value.x_get::<i64>(&tilde)?, 1);
+	assert_eq!(escape_token("docs/a~b"), "docs~1a~0b");
+	assert_eq!(unescape_token("docs~1a~0b"), "docs/a~b");
+	assert!(PointerBuf::new().is_root());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_all_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"items": [{"name": "a"}, {"id": 2}, {"name": "c"}],
+		"groups": {"g1": {"size": 1}, "g2": {"size": 2}}
+	});
+
+	// -- Exec
+	let names: Vec<(String, String)> = value.x_get_all("/items/*/name")?;
+	let sizes: Vec<(String, i64)> = value.x_get_all("/groups/*/size")?;
+	let none: Vec<(String, Value)> = value.x_get_all("/nope/*")?;
+
+	// -- Check
+	assert_eq!(
+		names,
+		vec![
+			("/items/0/name".to_string(), "a".to_string()),
+			("/items/2/name".to_string(), "c".to_string())
+		]
+	);
+	assert_eq!(sizes.iter().map(|(_, s)| *s).sum::<i64>(), 3);
+	assert!(sizes.iter().any(|(p, _)| p == "/groups/g2/size"));
+	assert!(none.is_empty());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_contains_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"name": "Jen", "profile": {"nickname": null}});
+
+	// -- Exec & Check
+	assert!(value.x_contains("name"));
+	assert!(value.x_contains("/profile/nickname"));
+	assert!(!value.x_contains("/profile/age"));
+	assert!(value.x_is_null("/profile/nickname"));
+	assert!(!value.x_is_null("name"));
+	assert!(!value.x_is_null("/profile/age"));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_kind_len_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"empty": [], "name": "Jen", "tags": ["a", "b"]});
+
+	// -- Exec & Check
+	assert_eq!(value.x_kind(), JsonKind::Object);
+	assert_eq!(value.x_len(), Some(3));
+	assert_eq!(value["name"].x_kind().to_string(), "string");
+	assert_eq!(value["name"].x_len(), Some(3));
+	assert_eq!(value["tags"].x_len(), Some(2));
+	assert!(value["empty"].x_is_empty());
+	assert!(!value["tags"].x_is_empty());
+	assert!(value["tags"].x_kind().is_container());
+	assert_eq!(Value::Null.x_kind(), JsonKind::Null);
+	assert_eq!(Value::Null.x_len(), None);
+	assert!(!Value::Null.x_is_empty());
+	assert_eq!(json!(1.5).x_len(), None);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_many_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"tags": ["a"], "user": {"age": 31, "name": "Jen"}});
+
+	// -- Exec
+	let values = value.x_get_many(&["/user/name", "/user/nickname", "tags"]);
+	let (name, age) = value_ext::x_get_many!(value, "/user/name" => String, "/user/age" => u32)?;
+	let missing = value_ext::x_get_many!(&value, "/user/name" => String, "/user/nickname" => String);
+
+	// -- Check
+	assert_eq!(values.len(), 3);
+	assert_eq!(values[0].as_ref().ok(), Some(&json!("Jen")));
+	assert!(matches!(values[1], Err(JsonValueExtError::PropertyNotFound { .. })));
+	assert_eq!(values[2].as_ref().ok(), Some(&json!(["a"])));
+	assert_eq!((name.as_str(), age), ("Jen", 31));
+	assert!(matches!(missing, Err(JsonValueExtError::PropertyNotFound { .. })));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_opt_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"name": "Jen", "profile": {"age": 32}});
+
+	// -- Exec
+	let name: Option<String> = value.x_get_opt("name")?;
+	let missing: Option<String> = value.x_get_opt("/profile/nickname")?;
+	let type_err = value.x_get_opt::<String>("/profile/age");
+	let age: Option<u32> = value.x_take_opt("/profile/age")?;
+	let age_missing: Option<u32> = value.x_take_opt("/profile/weight")?;
+
+	// -- Check
+	assert_eq!(name.as_deref(), Some("Jen"));
+	assert!(missing.is_none());
+	assert!(type_err.is_err(), "type mismatch should still be an error");
+	assert_eq!(age, Some(32));
+	assert!(age_missing.is_none());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_or_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"config": {"port": 8080, "host": 123}});
+
+	// -- Exec
+	let port: u32 = value.x_get_or("/config/port", 3000)?;
+	let timeout: u32 = value.x_get_or("/config/timeout", 30)?;
+	let tags: Vec<String> = value.x_get_or_default("/config/tags")?;
+	let host_res = value.x_get_or::<String>("/config/host", "localhost".to_string());
+
+	// -- Check
+	assert_eq!(port, 8080);
+	assert_eq!(timeout, 30);
+	assert!(tags.is_empty());
+	assert!(host_res.is_err(), "type mismatch should not fall back to default");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_get_lenient_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"count": "42", "ratio": " 0.5 ", "flag": 1, "on": "TRUE", "id": 3, "whole": 7.0, "name": "abc"});
+
+	// -- Exec
+	let count: i64 = value.x_get_lenient("count")?;
+	let ratio: f64 = value.x_get_lenient("ratio")?;
+	let flag: bool = value.x_get_lenient("flag")?;
+	let on: bool = value.x_get_lenient("on")?;
+	let id: String = value.x_get_lenient("id")?;
+	let whole: u32 = value.x_get_lenient("whole")?;
+	let name_res = value.x_get_lenient::<i64>("name");
+
+	// -- Check
+	assert_eq!(count, 42);
+	assert_eq!(ratio, 0.5);
+	assert!(flag);
+	assert!(on);
+	assert_eq!(id, "3");
+	assert_eq!(whole, 7);
+	assert!(name_res.is_err());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_error_context_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"user": {"name": "Jen", "tags": ["a"]}});
+
+	// -- Exec
+	let as_err = value.x_get_as::<i64>("/user/name").unwrap_err();
+	let get_err = value.x_get::<u32>("/user/name").unwrap_err();
+	let insert_err = value.x_insert("/user/name/first", "Jen").unwrap_err();
+	let index_err = value.x_insert("/user/tags/5", "b").unwrap_err();
+	let remove_err = value.x_remove_at::<u32>("/user/tags", 0).unwrap_err();
+	let take_err = value.x_take::<u32>("/user/tags").unwrap_err();
+
+	// -- Check
+	assert!(matches!(
+		as_err,
+		JsonValueExtError::PropertyValueNotOfType { name, not_of_type: "i64", actual: "string", .. } if name == "/user/name"
+	));
+	assert!(matches!(
+		get_err,
+		JsonValueExtError::PropertyValueNotDeserializable { name, not_of_type: "u32", actual: "string", .. } if name == "/user/name"
+	));
+	assert!(matches!(
+		insert_err,
+		JsonValueExtError::NotAnObject { path, actual: "string", .. } if path == "/user/name"
+	));
+	assert!(matches!(
+		index_err,
+		JsonValueExtError::IndexOutOfBounds { path, index: 5, len: 1, .. } if path == "/user/tags"
+	));
+	assert!(matches!(
+		take_err,
+		JsonValueExtError::PropertyValueNotDeserializable { name, not_of_type: "u32", actual: "array", .. } if name == "/user/tags"
+	));
+	assert!(matches!(
+		remove_err,
+		JsonValueExtError::PropertyValueNotDeserializable {
+			not_of_type: "u32",
+			actual: "string",
+			..
+		}
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_error_not_found_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"schema": {"properties": {"make": {}, "model": {}, "year": {}}}});
+
+	// -- Exec
+	let err = value.x_get::<Value>("/schema/properties/maker/type").unwrap_err();
+	let root_err = value.x_get_as::<&Value>("schemas").unwrap_err();
+
+	// -- Check
+	let JsonValueExtError::PropertyNotFound {
+		name,
+		resolved,
+		missing,
+		suggestions,
+	} = err
+	else {
+		return Err(format!("unexpected error: {err:?}").into());
+	};
+	assert_eq!(name, "/schema/properties/maker/type");
+	assert_eq!(resolved, "/schema/properties");
+	assert_eq!(missing, "maker");
+	assert_eq!(suggestions, vec!["make".to_string()]);
+	assert!(matches!(
+		root_err,
+		JsonValueExtError::PropertyNotFound { resolved, suggestions, .. } if resolved.is_empty() && suggestions == ["schema"]
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_error_snippet_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({"short": "abc", "long": "é".repeat(100)});
+
+	// -- Exec
+	let short_err = value.x_get_as::<u32>("short").unwrap_err();
+	let long_err = value.x_get::<u32>("long").unwrap_err();
+
+	// -- Check
+	assert!(matches!(short_err, JsonValueExtError::PropertyValueNotOfType { snippet, .. } if snippet == "\"abc\""));
+	let JsonValueExtError::PropertyValueNotDeserializable { snippet, .. } = long_err else {
+		return Err(format!("unexpected error: {long_err:?}").into());
+	};
+	assert!(snippet.starts_with("\"éé"));
+	assert!(snippet.ends_with('…'));
+	assert!(snippet.len() <= 120 + '…'.len_utf8());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_error_structural_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"tags": ["a"], "count": 1});
+
+	// -- Exec
+	let array_parent_err = value.x_insert("/tags/name", "x").unwrap_err();
+	let scalar_index_err = value.x_insert("/count/0", "x").unwrap_err();
+	let not_found_err = value.x_get::<String>("/nope").unwrap_err();
+
+	// -- Check
+	assert!(matches!(
+		array_parent_err,
+		JsonValueExtError::NotAnObject { actual: "array", .. }
+	));
+	assert!(matches!(
+		scalar_index_err,
+		JsonValueExtError::NotAnArray { actual: "number", .. }
+	));
+	assert_eq!(array_parent_err.kind(), JsonValueExtErrorKind::Structure);
+	assert_eq!(not_found_err.kind(), JsonValueExtErrorKind::NotFound);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_remove_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"user": {"name": "Jen", "a/b": 1}, "tags": ["a", "b", "c"]});
+
+	// -- Exec
+	let name: String = value.x_remove("/user/name")?;
+	let escaped: i64 = value.x_remove("/user/a~1b")?;
+	let tag: String = value.x_remove("/tags/1")?;
+	let missing = value.x_remove::<Value>("/user/name");
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(escaped, 1);
+	assert_eq!(tag, "b");
+	assert!(missing.is_err());
+	assert_eq!(value, json!({"user": {}, "tags": ["a", "c"]}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_remove_type_err_keeps_value_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"a": {"n": "abc", "only": {"x": "y"}}, "list": ["z"]});
+	let mut value = fx_value.clone();
+
+	// -- Exec
+	let remove_err = value.x_remove::<u32>("/a/n");
+	let take_err = value.x_take::<u32>("/a/n");
+	let prune_err = value.x_take_prune::<u32>("/a/only/x");
+	let remove_at_err = value.x_remove_at::<u32>("/list", 0);
+
+	// -- Check
+	assert!(matches!(
+		remove_err,
+		Err(JsonValueExtError::PropertyValueNotDeserializable { .. })
+	));
+	assert!(take_err.is_err());
+	assert!(prune_err.is_err());
+	assert!(remove_at_err.is_err());
+	assert_eq!(value, fx_value);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_take_prune_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"a": {"b": {"c": {"d": 1}}, "keep": true}, "list": [{"x": 1}]});
+
+	// -- Exec
+	let d: i64 = value.x_take_prune("/a/b/c/d")?;
+	let x: i64 = value.x_take_prune("/list/0/x")?;
+
+	// -- Check
+	assert_eq!(d, 1);
+	assert_eq!(x, 1);
+	assert_eq!(value, json!({"a": {"keep": true}}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_merge_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_base = json!({"name": "app", "server": {"port": 80, "host": "a"}, "tags": ["x", "y"], "debug": true});
+	let fx_other = json!({"server": {"port": 8080, "host": null}, "tags": ["y", "z"], "debug": null});
+
+	// -- Exec
+	let mut default_merged = fx_base.clone();
+	default_merged.x_merge(fx_other.clone(), MergeOptions::default());
+	let mut union_merged = fx_base.clone();
+	union_merged.x_merge(
+		fx_other,
+		MergeOptions::default()
+			.with_arrays(ArrayMerge::Union)
+			.with_nulls(NullMerge::Delete),
+	);
+
+	// -- Check
+	assert_eq!(
+		default_merged,
+		json!({"name": "app", "server": {"port": 8080, "host": null}, "tags": ["y", "z"], "debug": null})
+	);
+	assert_eq!(
+		union_merged,
+		json!({"name": "app", "server": {"port": 8080}, "tags": ["x", "y", "z"]})
+	);
+
+	Ok(())
+}
+
 #[test]
 fn test_value_walk_ok() -> Result<()> {
 	// -- Setup & Fixtures
-	let mut root_value = json!(
-	{
-			"tokens": 3,
-			"schema": {
-				"type": "object",
-				"additionalProperties": false,
-				"properties": {
-					"all_models": {
-						"type": "array",
-						"items": {
-							"type": "object",
-							"additionalProperties": false,
-							"properties": {
-								"maker": { "type": "string" },
-								"model_name": { "type": "string" }
-							},
-							"required": ["maker", "model_name"]
-						}
-					}
-				},
-				"required": ["all_models"]
-			}
+	let mut root_value = json!(
+	{
+			"tokens": 3,
+			"schema": {
+				"type": "object",
+				"additionalProperties": false,
+				"properties": {
+					"all_models": {
+						"type": "array",
+						"items": {
+							"type": "object",
+							"additionalProperties": false,
+							"properties": {
+								"maker": { "type": "string" },
+								"model_name": { "type": "string" }
+							},
+							"required": ["maker", "model_name"]
+						}
+					}
+				},
+				"required": ["all_models"]
+			}
+	});
+
+	// -- Exec
+	// Will remove "additionalProperties" (only the frist one, because return false)
+	root_value.x_walk(|parent_map, property_name| {
+		// --
+		if property_name == "type" {
+			let val = parent_map.get(property_name).and_then(|val| val.as_str());
+			if let Some("object") = val {
+				parent_map.remove("additionalProperties");
+				return false; // will stop early
+			}
+		}
+		true
+	});
+
+	// -- Check
+	// the number of "additionalProperties" left
+	let mut marker_count = 0;
+	// Will remove "additionalProperties"
+	root_value.x_walk(|_parent_map, property_name| {
+		if property_name == "additionalProperties" {
+			marker_count += 1;
+		}
+		true
+	});
+	assert_eq!(1, marker_count); // only 1 was removed, as callback returned false
+
+	Ok(())
+}
+
+#[test]
+fn test_value_walk_with_path_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut root_value = json!({
+		"schema": {
+			"properties": {
+				"maker": { "type": "string" },
+				"models": { "type": "array", "items": [{ "type": "string" }] }
+			}
+		}
+	});
+
+	// -- Exec
+	let mut type_paths = Vec::new();
+	root_value.x_walk_with_path(|path, parent_map, property_name| {
+		if property_name == "type" {
+			type_paths.push(path.to_string());
+			parent_map.insert("visited".to_string(), true.into());
+		}
+		true
+	});
+
+	// -- Check
+	assert_eq!(
+		type_paths,
+		vec![
+			"/schema/properties/maker/type",
+			"/schema/properties/models/type",
+			"/schema/properties/models/items/0/type"
+		]
+	);
+	assert!(root_value.x_get_bool("/schema/properties/maker/visited")?);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_walk_mut_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut root_value = json!({
+		"definitions": {"name": {"type": "string"}},
+		"properties": {
+			"first": {"$ref": "#/definitions/name"},
+			"list": {"type": "array", "items": {"$ref": "#/definitions/name"}}
+		}
+	});
+	let fx_definition = json!({"type": "string"});
+
+	// -- Exec
+	let mut replaced_paths = Vec::new();
+	root_value.x_walk_mut(|path, value| {
+		if value.get("$ref").is_some() {
+			*value = fx_definition.clone();
+			replaced_paths.push(path.to_string());
+		}
+		true
+	});
+
+	// -- Check
+	assert_eq!(replaced_paths, vec!["/properties/first", "/properties/list/items"]);
+	assert_eq!(root_value.x_get_str("/properties/list/items/type")?, "string");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_walk_control_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut root_value = json!({
+		"a": {"private": {"secret": 1}, "b": {"c": {}, "d": 2}},
+		"z": {"e": {}}
+	});
+
+	// -- Exec
+	// skip the "private" subtree
+	let mut visited = Vec::new();
+	root_value.x_walk(|_parent_map, property_name| {
+		visited.push(property_name.to_string());
+		if property_name == "private" {
+			WalkControl::SkipChildren
+		} else {
+			WalkControl::Continue
+		}
+	});
+	// collapse the empty objects from the bottom up
+	let completed = root_value.x_walk_mut_with(WalkOrder::PostOrder, |_path, value| {
+		if let Value::Object(map) = value {
+			map.retain(|_, child| child.as_object().map(|o| !o.is_empty()).unwrap_or(true));
+		}
+		WalkControl::Continue
+	});
+
+	// -- Check
+	assert!(!visited.contains(&"secret".to_string()));
+	assert!(visited.contains(&"d".to_string()));
+	assert!(completed);
+	assert_eq!(root_value, json!({"a": {"private": {"secret": 1}, "b": {"d": 2}}}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_walk_ref_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let root_value = json!({"a": {"b": [{"type": "string"}], "type": "object"}, "type": "object"});
+	let root_ref = &root_value;
+
+	// -- Exec
+	let mut object_paths = Vec::new();
+	let completed = root_ref.x_walk_ref(|path, value| {
+		if value.get("type").and_then(Value::as_str) == Some("object") {
+			object_paths.push(path.to_string());
+		}
+		true
+	});
+	let mut first_string = None;
+	let stopped = !root_ref.x_walk_ref(|path, value| {
+		if value.is_string() {
+			first_string = Some(path.to_string());
+			return false;
+		}
+		true
+	});
+
+	// -- Check
+	assert!(completed);
+	assert_eq!(object_paths, vec!["", "/a"]);
+	assert!(stopped);
+	assert_eq!(first_string.as_deref(), Some("/a/b/0/type"));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_leaves_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let root_value = json!({"a": {"b": 1, "c": [true, null, {}]}, "d": "x"});
+
+	// -- Exec
+	let leaves: Vec<(String, &Value)> = root_value.x_leaves().collect();
+	let numbers = root_value.x_leaves().filter(|(_, v)| v.is_number()).count();
+
+	// -- Check
+	assert_eq!(
+		leaves,
+		vec![
+			("/a/b".to_string(), &json!(1)),
+			("/a/c/0".to_string(), &json!(true)),
+			("/a/c/1".to_string(), &Value::Null),
+			("/d".to_string(), &json!("x")),
+		]
+	);
+	assert_eq!(numbers, 1);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_entries_and_paths_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut root_value = json!({"a": {"b": [1, 2]}, "c": "x"});
+
+	// -- Exec
+	let keys: Vec<String> = root_value.x_entries().map(|(key, _)| key.to_string()).collect();
+	let paths: Vec<String> = root_value.x_paths().collect();
+	for (_, value) in root_value.x_entries_mut() {
+		if value.is_string() {
+			*value = json!("y");
+		}
+	}
+	for (_, leaf) in root_value.x_leaves_mut() {
+		if let Some(num) = leaf.as_i64() {
+			*leaf = json!(num * 10);
+		}
+	}
+
+	// -- Check
+	assert_eq!(keys, vec!["a", "c"]);
+	assert_eq!(paths, vec!["/a", "/a/b", "/a/b/0", "/a/b/1", "/c"]);
+	assert_eq!(root_value, json!({"a": {"b": [10, 20]}, "c": "y"}));
+	assert_eq!(json!([1]).x_entries().count(), 0);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_find_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let root_value = json!({
+		"schema": {
+			"type": "object",
+			"properties": {"items": {"type": "array", "items": {"type": "object"}}}
+		}
+	});
+	let is_object_schema = |_: &str, v: &Value| v.get("type").and_then(Value::as_str) == Some("object");
+
+	// -- Exec
+	let first = root_value.x_find_first(is_object_schema);
+	let all = root_value.x_find_all(is_object_schema);
+	let none = root_value.x_find_first(|path, _| path.ends_with("/nope"));
+
+	// -- Check
+	assert_eq!(first.map(|(path, _)| path).as_deref(), Some("/schema"));
+	assert_eq!(
+		all.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(),
+		vec!["/schema", "/schema/properties/items/items"]
+	);
+	assert!(none.is_none());
+
+	Ok(())
+}
+
+#[test]
+#[allow(clippy::excessive_precision)] // the RFC 8785 sample numbers
+fn test_value_canonical_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// The RFC 8785 sample (section 3.2.2) and key sorting (section 3.2.3) inputs.
+	// (the numbers are Rust literals, as the serde_json default parsing is not always correctly rounded)
+	let mut fx_sample: Value = serde_json::from_str(
+		r#"{
+			"string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+			"literals": [null, true, false]
+		}"#,
+	)?;
+	fx_sample.x_insert(
+		"numbers",
+		json!([333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001]),
+	)?;
+	let fx_keys: Value = serde_json::from_str(
+		r#"{"\u20ac": 1, "\r": 2, "\ufb33": 3, "1": 4, "\ud83d\ude00": 5, "\u0080": 6, "\u00f6": 7}"#,
+	)?;
+	let fx_numbers = json!([0, -0.0, 1e21, 1e20, 123456789012.5, -1.5e-7, 9007199254740993_u64]);
+
+	// -- Exec
+	let sample = fx_sample.x_canonical();
+	let keys = fx_keys.x_canonical();
+	let numbers = fx_numbers.x_canonical();
+
+	// -- Check
+	assert_eq!(
+		sample,
+		r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+	);
+	assert_eq!(
+		keys,
+		"{\"\\r\":2,\"1\":4,\"\u{80}\":6,\"ö\":7,\"€\":1,\"😀\":5,\"\u{fb33}\":3}"
+	);
+	assert_eq!(
+		numbers,
+		"[0,0,1e+21,100000000000000000000,123456789012.5,-1.5e-7,9007199254740992]"
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_hash_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_a: Value = serde_json::from_str(r#"{"a": 1, "b": [true, null, "x"], "c": {"d": 0.0}}"#)?;
+	let fx_b: Value = serde_json::from_str(r#"{"c": {"d": -0.0}, "b": [true, null, "x"], "a": 1}"#)?;
+
+	// -- Exec
+	let hash_a = fx_a.x_hash();
+	let hash_b = fx_b.x_hash();
+	let mut hasher_a = std::collections::hash_map::DefaultHasher::new();
+	fx_a.x_hash_with(&mut hasher_a);
+	let mut hasher_b = std::collections::hash_map::DefaultHasher::new();
+	fx_b.x_hash_with(&mut hasher_b);
+
+	// -- Check
+	assert_eq!(hash_a, hash_b);
+	assert_eq!(hasher_a.finish(), hasher_b.finish());
+	assert_ne!(json!({"a": 1}).x_hash(), json!({"a": 1.0}).x_hash());
+	assert_ne!(json!(["ab", "c"]).x_hash(), json!(["a", "bc"]).x_hash());
+	assert_ne!(json!({"a": [1]}).x_hash(), json!({"a": 1}).x_hash());
+
+	Ok(())
+}
+
+#[test]
+fn test_value_equals_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_expected = json!({"id": 1, "tags": ["a", "b"], "score": 0.3, "items": [{"id": 7, "name": "x"}]});
+	let fx_actual = json!({
+		"id": 1,
+		"tags": ["b", "a"],
+		"score": 0.1 + 0.2,
+		"items": [{"id": 8, "name": "x"}],
+		"extra": null,
+		"updated_at": "2024-01-01"
+	});
+	let options = EqOptions::default()
+		.with_ignore_array_order(true)
+		.with_missing_as_null(true)
+		.with_float_tolerance(1e-9)
+		.with_ignore_path("/items/*/id")
+		.with_ignore_path("updated_at");
+
+	// -- Exec
+	let equal = fx_expected.x_equals(&fx_actual, options.clone());
+	let strict_equal = fx_expected.x_equals(&fx_actual, EqOptions::default());
+
+	// -- Check
+	assert!(equal);
+	assert!(!strict_equal);
+	assert!(!json!([1, 1, 2]).x_equals(&json!([1, 2, 2]), options.clone()));
+	assert!(!json!({"a": 1}).x_equals(&json!({"a": false}), options));
+	assert!(!json!(1).x_equals(&json!(1.0), EqOptions::default()));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_equals_unordered_tolerance_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let options = EqOptions::default()
+		.with_ignore_array_order(true)
+		.with_float_tolerance(0.15);
+
+	// -- Exec
+	// 1.0 is also within the tolerance of 1.1, so a first-match pairing would leave 1.2 without a pair
+	let equal = json!([1.0, 1.2]).x_equals(&json!([1.1, 0.95]), options.clone());
+	let not_equal = json!([1.0, 1.02]).x_equals(&json!([0.9, 1.3]), options.clone());
+	let nested_equal = json!([{"v": 1.0}, {"v": 1.2}]).x_equals(&json!([{"v": 1.1}, {"v": 0.95}]), options);
+
+	// -- Check
+	assert!(equal);
+	assert!(!not_equal);
+	assert!(nested_equal);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_contains_subset_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_response = json!({
+		"status": "ok",
+		"data": {"id": 42, "tags": ["a", "b", "c"], "items": [{"id": 1, "name": "x"}, {"id": 2, "name": "y"}]}
+	});
+
+	// -- Exec & Check
+	assert!(fx_response.x_contains_subset(&json!({"status": "ok"})));
+	assert!(fx_response.x_contains_subset(&json!({"data": {"tags": ["c", "a"], "items": [{"name": "y"}]}})));
+	assert!(fx_response.x_contains_subset(&json!({})));
+	assert!(!fx_response.x_contains_subset(&json!({"data": {"id": 43}})));
+	assert!(!fx_response.x_contains_subset(&json!({"data": {"items": [{"name": "z"}]}})));
+	assert!(!fx_response.x_contains_subset(&json!({"missing": null})));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_flatten_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({
+		"user": {"name": "Jen", "file.md": 1},
+		"items": [{"id": 1}, {"id": 2}],
+		"empty": {},
+		"tags": ["a", "b"]
 	});
 
 	// -- Exec
-	// Will remove "additionalProperties" (only the frist one, because return false)
-	root_value.x_walk(|parent_map, property_name| {
-		// --
-		if property_name == "type" {
-			let val = parent_map.get(property_name).and_then(|val| val.as_str());
-			if let Some("object") = val {
-				parent_map.remove("additionalProperties");
-				return false; // will stop early
-			}
+	let dot = fx_value.x_flatten();
+	let pointer = fx_value.x_flatten_with(FlattenOptions::default().with_keys(FlattenKeys::Pointer));
+	let brackets = fx_value.x_flatten_with(FlattenOptions::default().with_arrays(FlattenArrays::Brackets));
+	let kept = fx_value.x_flatten_with(FlattenOptions::default().with_arrays(FlattenArrays::Keep));
+
+	// -- Check
+	assert_eq!(
+		Value::Object(dot),
+		json!({
+			"user.name": "Jen", "user.file\\.md": 1, "items.0.id": 1, "items.1.id": 2,
+			"empty": {}, "tags.0": "a", "tags.1": "b"
+		})
+	);
+	assert_eq!(pointer.get("/items/1/id"), Some(&json!(2)));
+	assert_eq!(pointer.get("/user/file.md"), Some(&json!(1)));
+	assert_eq!(brackets.get("items[1].id"), Some(&json!(2)));
+	assert_eq!(kept.get("tags"), Some(&json!(["a", "b"])));
+	assert_eq!(Value::Object(json!(3).x_flatten()), json!({"": 3}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_unflatten_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({
+		"user": {"name": "Jen", "file.md": 1},
+		"items": [{"id": 1}, {"id": 2}],
+		"empty": {},
+		"tags": ["a", "b"]
+	});
+	let fx_mixed = json!({"a[0].b": 1, "a[1]": 2, "/c/x~1y": 3, "d.0": "x", "d.2": "z", "\\/e": 4, "f\\[0]": 5});
+
+	// -- Exec
+	let from_dot = Value::x_unflatten(fx_value.x_flatten())?;
+	let from_pointer =
+		Value::x_unflatten(fx_value.x_flatten_with(FlattenOptions::default().with_keys(FlattenKeys::Pointer)))?;
+	let from_brackets =
+		Value::x_unflatten(fx_value.x_flatten_with(FlattenOptions::default().with_arrays(FlattenArrays::Brackets)))?;
+	let mixed = Value::x_unflatten(serde_json::from_value(fx_mixed)?)?;
+
+	// -- Check
+	assert_eq!(from_dot, fx_value);
+	assert_eq!(from_pointer, fx_value);
+	assert_eq!(from_brackets, fx_value);
+	assert_eq!(
+		mixed,
+		json!({"a": [{"b": 1}, 2], "c": {"x/y": 3}, "d": {"0": "x", "2": "z"}, "/e": 4, "f[0]": 5})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_flatten_unflatten_escaped_keys_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"a[0]": 1, "/x": 2, "b": {"/y": 3, "c[1]": [4], "d.e\\f": 5}});
+
+	// -- Exec
+	let dot = fx_value.x_flatten();
+	let brackets = fx_value.x_flatten_with(FlattenOptions::default().with_arrays(FlattenArrays::Brackets));
+
+	// -- Check
+	assert_eq!(dot.get("a\\[0]"), Some(&json!(1)));
+	assert_eq!(dot.get("\\/x"), Some(&json!(2)));
+	assert_eq!(dot.get("b./y"), Some(&json!(3)));
+	assert_eq!(Value::x_unflatten(dot)?, fx_value);
+	assert_eq!(Value::x_unflatten(brackets)?, fx_value);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_unflatten_conflict_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_flat = json!({"a": 1, "a.b": 2});
+
+	// -- Exec
+	let err = Value::x_unflatten(serde_json::from_value(fx_flat)?).unwrap_err();
+
+	// -- Check
+	assert_eq!(err.kind(), JsonValueExtErrorKind::Structure);
+	let JsonValueExtError::UnflattenConflict { key, path } = err else {
+		panic!("Expected UnflattenConflict, got {err:?}");
+	};
+	assert_eq!(key, "a.b");
+	assert_eq!(path, "/a");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_rename_keys_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({
+		"user_id": 1,
+		"_internal_flag": true,
+		"items": [{"item_name": "a", "raw_data": {"keep_me": 1}}],
+		"metadata": {"created_at": "x"}
+	});
+
+	// -- Exec
+	value.x_rename_keys_with(Case::Camel, &["/metadata", "/items/*/raw_data"]);
+	let mut kebab = value.clone();
+	kebab.x_rename_keys(Case::Kebab);
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({
+			"userId": 1,
+			"_internalFlag": true,
+			"items": [{"itemName": "a", "raw_data": {"keep_me": 1}}],
+			"metadata": {"created_at": "x"}
+		})
+	);
+	assert_eq!(kebab.x_get::<i64>("/items/0/raw-data/keep-me")?, 1);
+	assert_eq!(Case::Snake.convert("HTTPServerURL2go"), "http_server_url2go");
+	assert_eq!(Case::Pascal.convert("user-id"), "UserId");
+	assert_eq!(Case::Camel.convert("UserID"), "userId");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_prune_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({
+		"name": "",
+		"a": null,
+		"b": {"c": null, "d": []},
+		"list": [1, null, {}, [null]],
+		"keep": 0
+	});
+
+	// -- Exec
+	let mut pruned = fx_value.clone();
+	let count = pruned.x_prune(PruneOptions::default());
+	let mut pruned_all = fx_value.clone();
+	pruned_all.x_prune(PruneOptions::default().with_empty_strings(true));
+	let mut nulls_only = fx_value.clone();
+	nulls_only.x_prune(
+		PruneOptions::default()
+			.with_empty_objects(false)
+			.with_empty_arrays(false),
+	);
+
+	// -- Check
+	assert_eq!(pruned, json!({"name": "", "list": [1], "keep": 0}));
+	assert_eq!(count, 8);
+	assert_eq!(pruned_all, json!({"list": [1], "keep": 0}));
+	assert_eq!(
+		nulls_only,
+		json!({"name": "", "b": {"d": []}, "list": [1, {}, []], "keep": 0})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_redact_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_body = json!({
+		"headers": {"Authorization": "Bearer x", "accept": "json"},
+		"access_token": "t1",
+		"users": [{"name": "a", "email": "a@x.com"}, {"name": "b", "email": "b@x.com"}],
+		"secret": {"nested": true}
+	});
+	let rules = RedactRules::default()
+		.with_key("authorization")
+		.with_key_glob("*TOKEN")
+		.with_key("secret")
+		.with_path("/users/*/email");
+
+	// -- Exec
+	let mut redacted = fx_body.clone();
+	let count = redacted.x_redact(&rules);
+	let mut hashed = fx_body.clone();
+	hashed.x_redact(&rules.clone().with_redaction(Redaction::Hash));
+
+	// -- Check
+	assert_eq!(count, 5);
+	assert_eq!(
+		redacted,
+		json!({
+			"headers": {"Authorization": "***", "accept": "json"},
+			"access_token": "***",
+			"users": [{"name": "a", "email": "***"}, {"name": "b", "email": "***"}],
+			"secret": "***"
+		})
+	);
+	let token_hash = hashed.x_get::<String>("access_token")?;
+	assert!(token_hash.starts_with("hash:"));
+	assert_eq!(token_hash, format!("hash:{:016x}", json!("t1").x_hash()));
+	assert_ne!(
+		hashed.x_get::<String>("/users/0/email")?,
+		hashed.x_get::<String>("/users/1/email")?
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_pick_omit_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let value = json!({
+		"id": 1,
+		"items": [{"id": "a", "secret": 1}, {"id": "b", "secret": 2}],
+		"password": "x",
+		"user": {"age": 31, "name": "Jen"}
+	});
+
+	// -- Exec
+	let picked = value.x_pick(&["/id", "/user/name", "/items/*/id", "/missing"]);
+	let picked_one = value.x_pick(&["/items/1/secret"]);
+	let omitted = value.x_omit(&["password", "/user/age", "/items/*/secret", "/items/0"]);
+	let projected = value.x_project(&[("/user/name", "/name"), ("/items/0/id", "/first/id"), ("/nope", "/x")])?;
+
+	// -- Check
+	assert_eq!(
+		picked,
+		json!({"id": 1, "items": [{"id": "a"}, {"id": "b"}], "user": {"name": "Jen"}})
+	);
+	assert_eq!(picked_one, json!({"items": [{"secret": 2}]}));
+	assert_eq!(
+		omitted,
+		json!({"id": 1, "items": [{"id": "b"}], "user": {"name": "Jen"}})
+	);
+	assert_eq!(projected, json!({"first": {"id": "a"}, "name": "Jen"}));
+	assert_eq!(value.x_pick(&[]), json!({}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_render_template_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let ctx = json!({"limits": {"max": 10}, "model": "gpt", "user": {"name": "Jen"}});
+	let mut value = json!({
+		"literal": "cost: $${price}",
+		"max": "${/limits/max}",
+		"prompt": "Hello ${/user/name}, use ${model} (max ${ /limits/max })",
+		"tags": ["${model}", "plain"]
+	});
+
+	// -- Exec
+	let count = value.x_render_template(&ctx)?;
+
+	// -- Check
+	assert_eq!(count, 5);
+	assert_eq!(
+		value,
+		json!({
+			"literal": "cost: ${price}",
+			"max": 10,
+			"prompt": "Hello Jen, use gpt (max 10)",
+			"tags": ["gpt", "plain"]
+		})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_render_template_missing() -> Result<()> {
+	// -- Setup & Fixtures
+	let ctx = json!({"name": "Jen"});
+	let fx_value = json!({"greeting": "Hi ${name} from ${/team}"});
+	let fx_partial = json!({"a": "${name}", "b": "${/team}", "c": "${name}"});
+
+	// -- Exec
+	let err = fx_value.clone().x_render_template(&ctx).err().ok_or("should fail")?;
+	let mut partial = fx_partial.clone();
+	let partial_res = partial.x_render_template(&ctx);
+	let mut kept = fx_value.clone();
+	kept.x_render_template_with(&ctx, TemplateOptions::default().with_missing(MissingVar::Keep))?;
+	let mut emptied = fx_value;
+	emptied.x_render_template_with(&ctx, TemplateOptions::default().with_missing(MissingVar::Empty))?;
+
+	// -- Check
+	assert!(
+		matches!(err, JsonValueExtError::TemplateVarNotFound { path, var } if path == "/greeting" && var == "/team")
+	);
+	// a failed render leaves the value unchanged
+	assert!(partial_res.is_err());
+	assert_eq!(partial, fx_partial);
+	assert_eq!(kept, json!({"greeting": "Hi Jen from ${/team}"}));
+	assert_eq!(emptied, json!({"greeting": "Hi Jen from "}));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_expand_env_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	// unique names, as the tests run in parallel
+	std::env::set_var("VALUE_EXT_TEST_HOST", "db.local");
+	std::env::set_var("VALUE_EXT_TEST_SECRET", "s3cr3t");
+	std::env::remove_var("VALUE_EXT_TEST_PORT");
+	let fx_value = json!({
+		"db": {"port": "${VALUE_EXT_TEST_PORT:-5432}", "url": "pg://${VALUE_EXT_TEST_HOST}/app"},
+		"kept": ["${VALUE_EXT_TEST_NOPE}", "$${VALUE_EXT_TEST_HOST}", "${/pointer}"],
+		"secret": "${VALUE_EXT_TEST_SECRET}"
+	});
+	let mut value = fx_value.clone();
+	let mut allowed = fx_value;
+
+	// -- Exec
+	let report = value.x_expand_env();
+	let allowed_report = allowed.x_expand_env_with(EnvOptions::default().with_allowlist(["VALUE_EXT_TEST_HOST"]));
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({
+			"db": {"port": "5432", "url": "pg://db.local/app"},
+			"kept": ["${VALUE_EXT_TEST_NOPE}", "${VALUE_EXT_TEST_HOST}", "${/pointer}"],
+			"secret": "s3cr3t"
+		})
+	);
+	assert_eq!(report.used, ["VALUE_EXT_TEST_HOST", "VALUE_EXT_TEST_SECRET"]);
+	assert_eq!(report.missing, ["VALUE_EXT_TEST_NOPE", "VALUE_EXT_TEST_PORT"]);
+	assert!(!report.is_complete());
+	assert_eq!(allowed.x_get::<String>("/secret")?, "${VALUE_EXT_TEST_SECRET}");
+	assert_eq!(allowed_report.used, ["VALUE_EXT_TEST_HOST"]);
+	assert_eq!(
+		allowed_report.denied,
+		["VALUE_EXT_TEST_NOPE", "VALUE_EXT_TEST_PORT", "VALUE_EXT_TEST_SECRET"]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_map_strings_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"count": "42", "name": "  Jen ", "tags": [" A", "b "], "zip": 75001});
+	let mut paths = Vec::new();
+
+	// -- Exec
+	value.x_map_strings(|path, text| {
+		paths.push(path.to_string());
+		let text = text.trim().to_lowercase();
+		match text.parse::<i64>() {
+			Ok(num) => num.into(),
+			Err(_) => text.into(),
 		}
-		true
 	});
 
 	// -- Check
-	// the number of "additionalProperties" left
-	let mut marker_count = 0;
-	// Will remove "additionalProperties"
-	root_value.x_walk(|_parent_map, property_name| {
-		if property_name == "additionalProperties" {
-			marker_count += 1;
+	assert_eq!(
+		value,
+		json!({"count": 42, "name": "jen", "tags": ["a", "b"], "zip": 75001})
+	);
+	assert_eq!(paths.len(), 4);
+	assert!(paths.iter().any(|path| path == "/tags/1"));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_map_numbers_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"items": [{"price": 1999}, {"price": 250}], "name": "cart", "ratio": 0.33333});
+
+	// -- Exec
+	value.x_map_numbers(|path, num| {
+		let num = num.as_f64().unwrap_or_default();
+		if path.ends_with("/price") {
+			json!(num / 100.0)
+		} else {
+			json!((num * 100.0).round() / 100.0)
 		}
-		true
 	});
-	assert_eq!(1, marker_count); // only 1 was removed, as callback returned false
+
+	// -- Check
+	assert_eq!(
+		value,
+		json!({"items": [{"price": 19.99}, {"price": 2.5}], "name": "cart", "ratio": 0.33})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_replace_where_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({
+		"contact": {"email": "jen@x.com"},
+		"users": [{"email": "mike@x.com", "name": "Mike"}, {"name": "Bob"}]
+	});
+
+	// -- Exec
+	let paths = value.x_replace_where(
+		|path, _| path.ends_with("/email"),
+		|_, email| json!(format!("hash:{}", email.as_str().map(str::len).unwrap_or_default())),
+	);
+	let bob_paths = value.x_replace_where(
+		|_, v| v.get("name") == Some(&json!("Bob")),
+		|_, _| json!({"name": "B."}),
+	);
+
+	// -- Check
+	assert_eq!(paths, ["/contact/email", "/users/0/email"]);
+	assert_eq!(bob_paths, ["/users/1"]);
+	assert_eq!(
+		value,
+		json!({
+			"contact": {"email": "hash:9"},
+			"users": [{"email": "hash:10", "name": "Mike"}, {"name": "B."}]
+		})
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_sort_array_by_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"models": [
+		{"maker": "openai", "name": "b", "year": 2023},
+		{"maker": "anthropic", "name": "c", "year": 2024},
+		{"name": "d"},
+		{"maker": "openai", "name": "a", "year": 2024},
+		{"maker": 7, "name": "e"}
+	]});
+
+	// -- Exec
+	value.x_sort_array_by_keys("/models", &[("/maker", Order::Asc), ("/year", Order::Desc)])?;
+	let names: Vec<String> = value
+		.x_get_all("/models/*/name")?
+		.into_iter()
+		.map(|(_, name)| name)
+		.collect();
+	value.x_sort_array_by("models", "name", Order::Desc)?;
+	let desc_names: Vec<String> = value
+		.x_get_all("/models/*/name")?
+		.into_iter()
+		.map(|(_, name)| name)
+		.collect();
+
+	// -- Check
+	// missing first, then the number, then the strings
+	assert_eq!(names, ["d", "e", "c", "a", "b"]);
+	assert_eq!(desc_names, ["e", "d", "c", "b", "a"]);
+	assert!(matches!(
+		value.x_sort_array_by("/models/0", "name", Order::Asc),
+		Err(JsonValueExtError::NotAnArray { .. })
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_value_sort_keys_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = json!({"b": 1, "a": {"z": [{"y": 1, "x": 2}], "c": 3}});
+
+	// -- Exec
+	value.x_sort_keys();
+	let sorted = serde_json::to_string(&value)?;
+	value.x_sort_keys_by(|a, b| b.cmp(a));
+	let reversed = serde_json::to_string(&value)?;
+
+	// -- Check
+	assert_eq!(sorted, r#"{"a":{"c":3,"z":[{"x":2,"y":1}]},"b":1}"#);
+	// the objects keep their insertion order only with the serde_json `preserve_order` feature
+	let preserve_order = json!({"b": 0, "a": 0}).x_entries().next().map(|(key, _)| key) == Some("b");
+	if preserve_order {
+		assert_eq!(reversed, r#"{"b":1,"a":{"z":[{"y":1,"x":2}],"c":3}}"#);
+	} else {
+		assert_eq!(reversed, sorted);
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_value_pretty_with_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"b": [1, 2, 3], "a": {"tags": ["x", "y"], "items": [{"id": 1}], "empty": []}});
+
+	// -- Exec
+	let default = fx_value.x_pretty_with(PrettyOptions::default());
+	let custom = fx_value.x_pretty_with(
+		PrettyOptions::default()
+			.with_indent("\t")
+			.with_sort_keys(true)
+			.with_compact_array_width(12)
+			.with_trailing_newline(true),
+	);
+
+	// -- Check
+	assert_eq!(default, fx_value.x_pretty()?);
+	assert_eq!(
+		custom,
+		"{\n\t\"a\": {\n\t\t\"empty\": [],\n\t\t\"items\": [\n\t\t\t{\n\t\t\t\t\"id\": 1\n\t\t\t}\n\t\t],\n\t\t\"tags\": [\"x\", \"y\"]\n\t},\n\t\"b\": [1, 2, 3]\n}\n"
+	);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_to_writer_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "Jen", "items": [1, 2]});
+	let mut compact = Vec::new();
+	let mut pretty = Vec::new();
+
+	// -- Exec
+	fx_value.x_to_writer(&mut compact)?;
+	fx_value.x_pretty_to_writer(&mut pretty)?;
+
+	// -- Check
+	assert_eq!(String::from_utf8(compact)?, serde_json::to_string(&fx_value)?);
+	assert_eq!(String::from_utf8(pretty)?, fx_value.x_pretty()?);
+
+	Ok(())
+}
+
+#[test]
+fn test_value_preview_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({
+		"id": 7,
+		"items": (0..100).collect::<Vec<_>>(),
+		"text": "a".repeat(100),
+	});
+
+	// -- Exec
+	let preview = fx_value.x_preview(80);
+	let small = fx_value.x_preview(10);
+	let accents = json!("é".repeat(50)).x_preview(12);
+
+	// -- Check
+	assert_eq!(
+		preview,
+		format!(
+			r#"{{"id":7,"items":[0,1,2,3,4,5,6,7,8,9,…+90],"text":"{}…"}}"#,
+			"a".repeat(20)
+		)
+	);
+	assert!(preview.len() <= 80);
+	assert_eq!(small, "{\"id\":7…");
+	assert!(accents.len() <= 12);
+	assert!(accents.ends_with('…'));
+	assert_eq!(json!([1, 2]).x_preview(80), "[1,2]");
+
+	Ok(())
+}
+
+#[test]
+fn test_value_stats_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "Jen", "tags": ["a", "bc", null], "meta": {"ok": true, "n": [[1, 2.5]]}});
+
+	// -- Exec
+	let stats = fx_value.x_stats();
+
+	// -- Check
+	assert_eq!(
+		(
+			stats.nulls,
+			stats.bools,
+			stats.numbers,
+			stats.strings,
+			stats.arrays,
+			stats.objects
+		),
+		(1, 1, 2, 3, 3, 2)
+	);
+	assert_eq!(stats.nodes(), 12);
+	assert_eq!(stats.max_depth, 4);
+	assert_eq!(stats.string_bytes, 6);
+	assert_eq!(stats.key_bytes, 15);
+	assert_eq!(stats.max_array_len, 3);
+	assert!(stats.estimated_heap_bytes > stats.string_bytes + stats.key_bytes);
+	assert_eq!(json!(1).x_stats().max_depth, 0);
 
 	Ok(())
 }
@@ -0,0 +1,72 @@
+#![cfg(feature = "msgpack")]
+
+use rmpv::Value;
+use value_ext::{MsgpackValueExt, MsgpackValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_msgpack_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = Value::Map(vec![
+		(
+			Value::from("user"),
+			Value::Map(vec![(Value::from("name"), Value::from("Jen"))]),
+		),
+		(Value::from(404), Value::from("Not Found")),
+		(Value::Binary(b"raw".to_vec()), Value::from(true)),
+		(Value::from("tags"), Value::Array(vec![Value::from("a")])),
+	]);
+
+	// -- Exec
+	let name: String = value.x_get("/user/name")?;
+	let not_found: String = value.x_get("/404")?;
+	let raw: bool = value.x_get("raw")?;
+	let tag: String = value.x_get("/tags/0")?;
+	let taken: String = value.x_take("/404")?;
+	value.x_insert("/tags/-", "b")?;
+	value.x_insert("/user/age", 42)?;
+	value.x_insert("/meta/scores/0", 1.5)?;
+	let missing_err = value.x_get::<String>("/user/nam").unwrap_err();
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(not_found, "Not Found");
+	assert!(raw);
+	assert_eq!(tag, "a");
+	assert_eq!(taken, "Not Found");
+	assert!(value.x_get::<Option<String>>("/404")?.is_none());
+	assert_eq!(value.x_get::<Vec<String>>("tags")?, vec!["a", "b"]);
+	assert_eq!(value.x_get::<u32>("/user/age")?, 42);
+	assert_eq!(value.x_get::<f64>("/meta/scores/0")?, 1.5);
+	assert!(matches!(
+		missing_err,
+		MsgpackValueExtError::PropertyNotFound { resolved, suggestions, .. } if resolved == "/user" && suggestions == ["name"]
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_msgpack_walk_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value = Value::Map(vec![
+		(Value::from("a"), Value::from(1)),
+		(
+			Value::from("b"),
+			Value::Map(vec![(Value::from(7), Value::from("seven"))]),
+		),
+	]);
+
+	// -- Exec
+	let mut keys = Vec::new();
+	value.x_walk(|_map, key| {
+		keys.push(key.to_string());
+		true
+	});
+
+	// -- Check
+	assert_eq!(keys, ["\"a\"", "\"b\"", "7"]);
+
+	Ok(())
+}
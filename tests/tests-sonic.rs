@@ -0,0 +1,100 @@
+#![cfg(feature = "sonic")]
+
+use sonic_rs::{json, JsonValueTrait, LazyValue, Value};
+use value_ext::{SonicLazyValueExt, SonicValueExt, SonicValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_sonic_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: Value = json!({"user": {"name": "Jen", "a/b": 1}, "tags": ["a"], "token": "x"});
+
+	// -- Exec
+	let name: String = value.x_get("/user/name")?;
+	let escaped: i64 = value.x_get("/user/a~1b")?;
+	let tag: String = value.x_get("/tags/0")?;
+	let taken: String = value.x_take("token")?;
+	let take_err = value.x_take::<u32>("/user/name").unwrap_err();
+	value.x_insert("/tags/-", "b")?;
+	value.x_insert("/user/age", 42)?;
+	value.x_insert("/meta/scores/0", 1.5)?;
+	let missing_err = value.x_get::<String>("/user/nam").unwrap_err();
+	let scalar_err = value.x_insert("/user/name/first", "Jen").unwrap_err();
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(escaped, 1);
+	assert_eq!(tag, "a");
+	assert_eq!(taken, "x");
+	assert!(value.x_get::<Option<String>>("token")?.is_none());
+	assert!(matches!(
+		take_err,
+		SonicValueExtError::PropertyValueNotDeserializable { actual: "string", .. }
+	));
+	assert_eq!(value.x_get::<String>("/user/name")?, "Jen");
+	assert_eq!(value.x_get::<Vec<String>>("tags")?, vec!["a", "b"]);
+	assert_eq!(value.x_get::<u32>("/user/age")?, 42);
+	assert_eq!(value.x_get::<f64>("/meta/scores/0")?, 1.5);
+	assert!(matches!(
+		missing_err,
+		SonicValueExtError::PropertyNotFound { resolved, suggestions, .. } if resolved == "/user" && suggestions == ["name"]
+	));
+	assert!(matches!(
+		scalar_err,
+		SonicValueExtError::NotAnObject { path, actual: "string", .. } if path == "/user/name"
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_sonic_walk_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: Value = sonic_rs::from_str(r#"{"a": 1, "b": {"c": [{"d": true}]}}"#)?;
+
+	// -- Exec
+	let mut keys = Vec::new();
+	value.x_walk(|_object, key| {
+		keys.push(key.to_string());
+		true
+	});
+
+	// -- Check
+	assert_eq!(keys, ["a", "b", "c", "d"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_sonic_lazy_get_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_json = r#"{"user": {"name": "Jen", "tags": ["a", "b"]}, "logs": [{"level": "info"}, {"level": "warn"}]}"#;
+	let doc: LazyValue = sonic_rs::from_str(fx_json)?;
+
+	// -- Exec
+	let name: String = doc.x_get("/user/name")?;
+	let tags: Vec<String> = doc.x_get("/user/tags")?;
+	let level: String = doc.x_get("/logs/1/level")?;
+	let user = doc.x_get_lazy("user")?;
+	let missing_err = doc.x_get::<String>("/user/nam").unwrap_err();
+	let type_err = doc.x_get::<u32>("/user/name").unwrap_err();
+
+	// -- Check
+	assert_eq!(name, "Jen");
+	assert_eq!(tags, vec!["a", "b"]);
+	assert_eq!(level, "warn");
+	assert_eq!(user.as_raw_str(), r#"{"name": "Jen", "tags": ["a", "b"]}"#);
+	assert!(user.is_object());
+	assert!(matches!(
+		missing_err,
+		SonicValueExtError::PropertyNotFound { resolved, missing, suggestions, .. }
+			if resolved == "/user" && missing == "nam" && suggestions == ["name"]
+	));
+	assert!(matches!(
+		type_err,
+		SonicValueExtError::PropertyValueNotDeserializable { actual: "string", .. }
+	));
+
+	Ok(())
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "toml")]
+
+use serde_json::{json, Value};
+use value_ext::{JsonValueExt, TomlConversionIssue, TomlValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_toml_convert_roundtrip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({
+		"name": "app",
+		"port": 8080,
+		"ratio": 1.0,
+		"released": "1979-05-27T07:32:00Z",
+		"servers": [{"host": "a"}, {"host": "b"}],
+	});
+
+	// -- Exec
+	let toml_value = fx_value.x_to_toml()?;
+	let back = Value::x_from_toml(&toml_value)?;
+
+	// -- Check
+	assert!(toml_value["port"].is_integer());
+	assert!(toml_value["ratio"].is_float());
+	assert!(toml_value["released"].is_datetime());
+	assert_eq!(back, fx_value);
+	assert!(back["ratio"].is_f64());
+
+	Ok(())
+}
+
+#[test]
+fn test_toml_convert_not_representable_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"a": null, "big": u64::MAX, "list": [1, null]});
+	let fx_toml: toml::Value = toml::from_str("x = nan\ny = [1.0, inf]")?;
+
+	// -- Exec
+	let to_err = fx_value.x_to_toml().unwrap_err();
+	let from_err = Value::x_from_toml(&fx_toml).unwrap_err();
+
+	// -- Check
+	let TomlValueExtError::NotRepresentable { issues } = to_err else {
+		panic!("Expected NotRepresentable, got {to_err:?}");
+	};
+	assert_eq!(
+		issues,
+		[
+			TomlConversionIssue::Null { path: "/a".to_string() },
+			TomlConversionIssue::IntegerOutOfRange {
+				path: "/big".to_string(),
+				value: u64::MAX
+			},
+			TomlConversionIssue::Null {
+				path: "/list/1".to_string()
+			},
+		]
+	);
+	let TomlValueExtError::NotRepresentable { issues } = from_err else {
+		panic!("Expected NotRepresentable, got {from_err:?}");
+	};
+	let paths: Vec<_> = issues
+		.iter()
+		.filter_map(|issue| match issue {
+			TomlConversionIssue::NonFiniteFloat { path, .. } => Some(path.as_str()),
+			_ => None,
+		})
+		.collect();
+	assert_eq!(paths, ["/x", "/y/1"]);
+
+	Ok(())
+}
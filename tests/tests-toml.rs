@@ -0,0 +1,80 @@
+#![cfg(feature = "toml")]
+
+use value_ext::{JsonPath, TomlValueExt, TomlValueExtError, WalkControl};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_toml_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: toml::Value = toml::from_str(
+		r#"
+[package]
+name = "demo"
+version = "0.1.0"
+
+[[bin]]
+name = "cli"
+"#,
+	)?;
+
+	// -- Exec
+	let name: String = value.x_get(&JsonPath::dot("package.name"))?;
+	let bin_name: String = value.x_get("/bin/0/name")?;
+	let version: String = value.x_take("/package/version")?;
+	value.x_insert("/dependencies/serde/version", "1")?;
+	value.x_insert("/bin/-", toml::toml! { name = "server" })?;
+	let missing_err = value.x_get::<String>("/package/nam").unwrap_err();
+	let scalar_err = value.x_insert("/package/name/first", "x").unwrap_err();
+
+	// -- Check
+	assert_eq!(name, "demo");
+	assert_eq!(bin_name, "cli");
+	assert_eq!(version, "0.1.0");
+	assert!(value.x_get::<String>("/package/version").is_err());
+	assert_eq!(value.x_get::<String>("/dependencies/serde/version")?, "1");
+	assert_eq!(value.x_get::<String>("/bin/1/name")?, "server");
+	assert!(matches!(
+		missing_err,
+		TomlValueExtError::PropertyNotFound { resolved, suggestions, .. } if resolved == "/package" && suggestions == ["name"]
+	));
+	assert!(matches!(
+		scalar_err,
+		TomlValueExtError::NotATable { actual: "string", .. }
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_toml_walk_and_pretty_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: toml::Value = toml::from_str(
+		r#"
+title = "demo"
+[server]
+host = "localhost"
+[server.tls]
+cert = "a.pem"
+"#,
+	)?;
+
+	// -- Exec
+	let mut keys = Vec::new();
+	value.x_walk(|_table, key| {
+		keys.push(key.to_string());
+		if key == "tls" {
+			WalkControl::SkipChildren
+		} else {
+			WalkControl::Continue
+		}
+	});
+	let pretty = value.x_pretty()?;
+
+	// -- Check
+	assert_eq!(keys, ["server", "title", "host", "tls"]);
+	assert!(pretty.contains("[server.tls]"));
+	assert!(pretty.contains("cert = \"a.pem\""));
+
+	Ok(())
+}
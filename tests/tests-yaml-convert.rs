@@ -0,0 +1,69 @@
+#![cfg(feature = "yaml")]
+
+use serde_json::{json, Value};
+use value_ext::{JsonValueExt, YamlConversionIssue, YamlValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_yaml_convert_roundtrip_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_value = json!({"name": "app", "port": 8080, "ratio": 0.5, "big": u64::MAX, "tags": ["a", null]});
+
+	// -- Exec
+	let yaml_value = fx_value.x_to_yaml();
+	let back = Value::x_from_yaml(&yaml_value)?;
+
+	// -- Check
+	assert_eq!(yaml_value["port"].as_i64(), Some(8080));
+	assert_eq!(back, fx_value);
+
+	Ok(())
+}
+
+#[test]
+fn test_yaml_convert_not_representable_err() -> Result<()> {
+	// -- Setup & Fixtures
+	let fx_yaml: serde_yaml::Value = serde_yaml::from_str(
+		r#"
+base: &base
+  a: 1
+codes:
+  404: not found
+derived:
+  <<: *base
+point: !Point {x: 1}
+ratio: .nan
+"#,
+	)?;
+
+	// -- Exec
+	let err = Value::x_from_yaml(&fx_yaml).unwrap_err();
+
+	// -- Check
+	let YamlValueExtError::NotRepresentable { issues } = err else {
+		panic!("Expected NotRepresentable, got {err:?}");
+	};
+	assert_eq!(
+		issues[..3],
+		[
+			YamlConversionIssue::NonStringKey {
+				path: "/codes".to_string(),
+				actual: "number"
+			},
+			YamlConversionIssue::MergeKey {
+				path: "/derived/<<".to_string()
+			},
+			YamlConversionIssue::Tagged {
+				path: "/point".to_string(),
+				tag: "!Point".to_string()
+			},
+		]
+	);
+	assert!(matches!(
+		&issues[3],
+		YamlConversionIssue::NonFiniteFloat { path, value } if path == "/ratio" && value.is_nan()
+	));
+
+	Ok(())
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "yaml")]
+
+use value_ext::{YamlValueExt, YamlValueExtError};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+#[test]
+fn test_yaml_get_take_insert_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: serde_yaml::Value = serde_yaml::from_str(
+		r#"
+server:
+  host: localhost
+  ports: [80, 443]
+codes:
+  404: Not Found
+  true: yes-key
+"#,
+	)?;
+
+	// -- Exec
+	let host: String = value.x_get("/server/host")?;
+	let port: u16 = value.x_get("/server/ports/1")?;
+	let not_found: String = value.x_get("/codes/404")?;
+	let bool_key: String = value.x_get("/codes/true")?;
+	let taken: String = value.x_take("/codes/404")?;
+	value.x_insert("/server/ports/-", 8080)?;
+	value.x_insert("/codes/500", "Server Error")?;
+	value.x_insert("/db/replicas/0/host", "db1")?;
+	let missing_err = value.x_get::<String>("/server/hst").unwrap_err();
+
+	// -- Check
+	assert_eq!(host, "localhost");
+	assert_eq!(port, 443);
+	assert_eq!(not_found, "Not Found");
+	assert_eq!(bool_key, "yes-key");
+	assert_eq!(taken, "Not Found");
+	assert!(value.x_get::<Option<String>>("/codes/404")?.is_none());
+	assert_eq!(value.x_get::<Vec<u16>>("/server/ports")?, vec![80, 443, 8080]);
+	assert_eq!(value.x_get::<String>("/codes/500")?, "Server Error");
+	assert_eq!(value.x_get::<String>("/db/replicas/0/host")?, "db1");
+	assert!(matches!(
+		missing_err,
+		YamlValueExtError::PropertyNotFound { resolved, suggestions, .. } if resolved == "/server" && suggestions == ["host"]
+	));
+
+	Ok(())
+}
+
+#[test]
+fn test_yaml_walk_and_pretty_ok() -> Result<()> {
+	// -- Setup & Fixtures
+	let mut value: serde_yaml::Value = serde_yaml::from_str("a: 1\nb:\n  c: 2\n  3: three\n")?;
+
+	// -- Exec
+	let mut keys = Vec::new();
+	value.x_walk(|_mapping, key| {
+		keys.push(serde_yaml::to_string(key).unwrap_or_default().trim().to_string());
+		true
+	});
+	let pretty = value.x_pretty()?;
+
+	// -- Check
+	assert_eq!(keys, ["a", "b", "c", "3"]);
+	assert_eq!(pretty, "a: 1\nb:\n  c: 2\n  3: three\n");
+
+	Ok(())
+}
@@ -0,0 +1,107 @@
+//! Derive macros for `value-ext`.
+//!
+//! Use through `value-ext` with the `derive` feature (`value_ext::AsType`).
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam, Lifetime, LitStr};
+
+/// Derives `value_ext::AsType` for a struct with named fields, where each field type implements `AsType`.
+///
+/// - Each field is extracted from the JSON object property of the same name (borrowed when the field type borrows).
+/// - `#[as_type(rename = "otherName")]` extracts a field from another property name.
+/// - A missing property is extracted as `Null` (so `Option<_>` fields are `None`), or fails with `PropertyNotFound`.
+///
+/// ```rust
+/// #[derive(AsType)]
+/// struct UserView<'a> {
+///     name: &'a str,
+///     age: i64,
+///     #[as_type(rename = "nickName")]
+///     nick_name: Option<&'a str>,
+/// }
+/// ```
+#[proc_macro_derive(AsType, attributes(as_type))]
+pub fn derive_as_type(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand_as_type(input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+fn expand_as_type(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+	let ident = &input.ident;
+
+	// -- Only lifetime generics are supported (a single one is used as the `AsType` lifetime)
+	let mut lifetimes = Vec::new();
+	for param in &input.generics.params {
+		match param {
+			GenericParam::Lifetime(lifetime) => lifetimes.push(lifetime.lifetime.clone()),
+			other => {
+				return Err(syn::Error::new_spanned(
+					other,
+					"AsType derive only supports lifetime generics",
+				))
+			}
+		}
+	}
+	let (impl_lifetime, type_generics) = match lifetimes.as_slice() {
+		[] => (Lifetime::new("'__a", Span::call_site()), quote! {}),
+		[lifetime] => (lifetime.clone(), quote! { <#lifetime> }),
+		_ => {
+			return Err(syn::Error::new_spanned(
+				&input.generics,
+				"AsType derive supports at most one lifetime",
+			))
+		}
+	};
+
+	// -- Build the field extractions
+	let Data::Struct(data) = &input.data else {
+		return Err(syn::Error::new_spanned(ident, "AsType derive only supports structs"));
+	};
+	let Fields::Named(fields) = &data.fields else {
+		return Err(syn::Error::new_spanned(
+			ident,
+			"AsType derive only supports structs with named fields",
+		));
+	};
+
+	let mut field_inits = Vec::new();
+	for field in &fields.named {
+		let field_ident = field.ident.as_ref().expect("named field");
+		// the raw identifiers (e.g., `r#type`) are extracted from the property without the `r#`
+		let mut name = field_ident.unraw().to_string();
+		for attr in &field.attrs {
+			if !attr.path().is_ident("as_type") {
+				continue;
+			}
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("rename") {
+					name = meta.value()?.parse::<LitStr>()?.value();
+					Ok(())
+				} else {
+					Err(meta.error("unsupported as_type attribute (expected `rename`)"))
+				}
+			})?;
+		}
+		field_inits.push(quote! {
+			#field_ident: ::value_ext::__private::from_object_property(map, #name)?
+		});
+	}
+
+	Ok(quote! {
+		impl<#impl_lifetime> ::value_ext::AsType<#impl_lifetime> for #ident #type_generics {
+			fn from_value(
+				value: &#impl_lifetime ::value_ext::__private::Value,
+			) -> ::core::result::Result<Self, ::value_ext::JsonValueExtError> {
+				let map = ::value_ext::__private::as_object(value)?;
+				Ok(Self {
+					#(#field_inits,)*
+				})
+			}
+		}
+	})
+}